@@ -1,11 +1,13 @@
-use num_traits::Num;
+use std::fmt::{ Display, Formatter };
 
 use crate::ast::operator::Operator;
+use crate::ast::pattern::Pattern;
 use crate::ast::statement::Statement;
-use crate::ast::structs::{ CallExpression, ClassDeclaration, FunctionDeclaration };
+use crate::ast::structs::{ CallExpression, ClassDeclaration, FunctionDeclaration, MatchArm };
 use crate::errors::ResultWithError;
-use crate::tokenizer::Token;
+use crate::tokenizer::{ is_identifier, Token };
 use crate::types::number::NumberT;
+use crate::types::span::Span;
 use crate::types::string::StringT;
 
 pub type BoxExpression = Box<Expression>;
@@ -25,12 +27,50 @@ pub enum MemberIndexer {
 	// MethodNameArrow(IdentifierT),
 }
 
+#[derive(Debug, Clone)]
+pub struct Expression {
+	pub kind: ExpressionKind,
+	pub span: Span,
+}
+
+/// Expressions are compared structurally, the span they were parsed from is ignored.
+impl PartialEq for Expression {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		return self.kind == other.kind;
+	}
+}
+
+impl From<ExpressionKind> for Expression {
+	#[inline(always)]
+	fn from(kind: ExpressionKind) -> Self {
+		return Expression { kind, span: Span::default() };
+	}
+}
+
+impl From<ExpressionKind> for BoxExpression {
+	#[inline(always)]
+	fn from(kind: ExpressionKind) -> Self {
+		return Box::new(kind.into());
+	}
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub enum Expression {
+pub enum ExpressionKind {
 	NullLiteral,
 	BooleanLiteral(bool),
 	NumericLiteral(NumberT),
 	StringLiteral(StringT),
+	/// A template string, `strings` holds the text around each of the embedded `expressions`, so
+	/// there is always one more string than expressions.
+	TemplateLiteral {
+		strings: Vec<StringT>,
+		expressions: Vec<Expression>,
+	},
+	ArrayLiteral(Vec<Expression>),
+	/// Object literal properties, named either by an identifier or string, or by a computed
+	/// subscript expression.
+	ObjectLiteral(Vec<(MemberIndexer, Expression)>),
 	ParenthesizedExpression(BoxExpression),
 	UnaryExpression {
 		operator: Operator,
@@ -41,34 +81,81 @@ pub enum Expression {
 		left: BoxExpression,
 		right: BoxExpression,
 	},
+	/// `++x`, `x++`, `--x` or `x--`, where `prefix` tells whether the expression evaluates to the
+	/// updated value rather than the original one.
+	UpdateExpression {
+		operator: Operator,
+		prefix: bool,
+		argument: BoxExpression,
+	},
 	AssignmentExpression {
 		operator: Operator,
 		left: BoxExpression,
 		right: BoxExpression,
 	},
+	ConditionalExpression {
+		condition: BoxExpression,
+		consequent: BoxExpression,
+		alternate: BoxExpression,
+	},
 	DottedIdentifiers(DottedIdentifiers),
 	Identifier(IdentifierT),
 	MemberAccess {
 		object: BoxExpression,
 		member: MemberIndexer,
 	},
+	/// `object?.member`, only found inside an [`ExpressionKind::OptionalChain`].
+	OptionalMemberAccess {
+		object: BoxExpression,
+		member: MemberIndexer,
+	},
+	/// A chain of member accesses and calls containing at least one `?.`, the whole of which
+	/// evaluates to `null` as soon as an object accessed with `?.` is `null`.
+	OptionalChain(BoxExpression),
+	/// `...vector`, only found among the arguments of a call, whose items are passed as separate
+	/// arguments.
+	SpreadExpression(BoxExpression),
+	/// `[a, b]` or `{ a, b }`, only found on the left of a simple assignment, which destructures
+	/// the assigned value.
+	DestructuringPattern(Pattern),
 	FunctionCall(CallExpression),
 	NewObjectExpression(CallExpression),
 	FunctionExpression(FunctionDeclaration),
+	/// An arrow function or anonymous `fn` expression, which unlike a [`ExpressionKind::FunctionExpression`]
+	/// doesn't declare its name in the enclosing scope.
+	LambdaExpression(FunctionDeclaration),
 	ClassDeclarationExpression(Box<ClassDeclaration>),
+	/// Evaluates to the body of the first arm that matches the value, it is an error for no arm
+	/// to match.
+	MatchExpression {
+		value: BoxExpression,
+		arms: Vec<MatchArm<Expression>>,
+	},
+}
+
+impl ExpressionKind {
+	#[inline(always)]
+	pub fn consume_as_statement(self) -> Statement {
+		return Expression::from(self).consume_as_statement();
+	}
+
+	#[inline(always)]
+	pub fn consume_as_parenthesized(self) -> Expression {
+		return Expression::from(self).consume_as_parenthesized();
+	}
 }
 
 impl Expression {
 	pub fn numeric_literal(v: &str) -> ResultWithError<Expression> {
-		return Ok(Expression::NumericLiteral(NumberT::from_str_radix(v, 10)?));
+		return Ok(ExpressionKind::NumericLiteral(NumberT::from_literal(v)?).into());
 	}
 
 	pub fn integer_literal(v: i64) -> Expression {
-		return Expression::NumericLiteral(NumberT::Integer(v as i128));
+		return ExpressionKind::NumericLiteral(NumberT::Integer(v as i128)).into();
 	}
 
 	pub fn float_literal(v: f64) -> Expression {
-		return Expression::NumericLiteral(NumberT::Float(v));
+		return ExpressionKind::NumericLiteral(NumberT::Float(v)).into();
 	}
 
 	#[inline(always)]
@@ -77,12 +164,17 @@ impl Expression {
 		left: BoxExpression,
 		right: BoxExpression
 	) -> Expression {
-		return Expression::BinaryExpression { operator, left, right };
+		return ExpressionKind::BinaryExpression { operator, left, right }.into();
 	}
 
 	#[inline(always)]
 	pub fn unary_expression(operator: Operator, argument: BoxExpression) -> Expression {
-		return Expression::UnaryExpression { operator, argument };
+		return ExpressionKind::UnaryExpression { operator, argument }.into();
+	}
+
+	#[inline(always)]
+	pub fn update_expression(operator: Operator, prefix: bool, argument: BoxExpression) -> Expression {
+		return ExpressionKind::UpdateExpression { operator, prefix, argument }.into();
 	}
 
 	#[inline(always)]
@@ -91,7 +183,7 @@ impl Expression {
 		left: BoxExpression,
 		right: BoxExpression
 	) -> Expression {
-		return Expression::AssignmentExpression { operator, left, right };
+		return ExpressionKind::AssignmentExpression { operator, left, right }.into();
 	}
 
 	// #[inline(always)]
@@ -101,17 +193,42 @@ impl Expression {
 
 	#[inline(always)]
 	pub fn member_property_access(object: BoxExpression, property_name: IdentifierT) -> Expression {
-		return Expression::MemberAccess { object, member: MemberIndexer::PropertyName(property_name) };
+		return (ExpressionKind::MemberAccess {
+			object,
+			member: MemberIndexer::PropertyName(property_name),
+		}).into();
 	}
 
 	#[inline(always)]
 	pub fn member_subscript(object: BoxExpression, expr: BoxExpression) -> Expression {
-		return Expression::MemberAccess { object, member: MemberIndexer::SubscriptExpression(expr) };
+		return (ExpressionKind::MemberAccess {
+			object,
+			member: MemberIndexer::SubscriptExpression(expr),
+		}).into();
+	}
+
+	#[inline(always)]
+	pub fn optional_member_access(object: BoxExpression, member: MemberIndexer) -> Expression {
+		return (ExpressionKind::OptionalMemberAccess { object, member }).into();
+	}
+
+	#[inline(always)]
+	pub fn optional_chain(chain: BoxExpression) -> Expression {
+		return ExpressionKind::OptionalChain(chain).into();
+	}
+
+	#[inline(always)]
+	pub fn conditional_expression(
+		condition: BoxExpression,
+		consequent: BoxExpression,
+		alternate: BoxExpression
+	) -> Expression {
+		return ExpressionKind::ConditionalExpression { condition, consequent, alternate }.into();
 	}
 
 	#[inline(always)]
 	pub fn function_call(function: BoxExpression, arguments: Vec<Expression>) -> Expression {
-		return Expression::FunctionCall(CallExpression::new(function, arguments));
+		return ExpressionKind::FunctionCall(CallExpression::new(function, arguments)).into();
 	}
 
 	#[inline(always)]
@@ -119,14 +236,22 @@ impl Expression {
 		class_expr: BoxExpression,
 		arguments: Vec<Expression>
 	) -> Expression {
-		return Expression::NewObjectExpression(CallExpression::new(class_expr, arguments));
+		return ExpressionKind::NewObjectExpression(CallExpression::new(class_expr, arguments)).into();
+	}
+
+	#[inline(always)]
+	pub fn match_expression(value: BoxExpression, arms: Vec<MatchArm<Expression>>) -> Expression {
+		return ExpressionKind::MatchExpression { value, arms }.into();
 	}
 
 	#[inline(always)]
 	pub fn is_lhs(&self) -> bool {
 		return matches!(
-			self,
-			Expression::Identifier(_) | Expression::MemberAccess { .. } | Expression::DottedIdentifiers(_)
+			self.kind,
+			| ExpressionKind::Identifier(_)
+			| ExpressionKind::MemberAccess { .. }
+			| ExpressionKind::DottedIdentifiers(_)
+			| ExpressionKind::DestructuringPattern(_)
 		);
 	}
 
@@ -137,6 +262,124 @@ impl Expression {
 
 	#[inline(always)]
 	pub fn consume_as_parenthesized(self) -> Expression {
-		return Expression::ParenthesizedExpression(self.into());
+		let span = self.span;
+		return Expression { kind: ExpressionKind::ParenthesizedExpression(self.into()), span };
+	}
+
+	#[inline(always)]
+	pub fn with_span(self, span: Span) -> Expression {
+		return Expression { span, ..self };
+	}
+}
+
+/// Writes the expression back out in a compact, source-like form, eliding the bodies of function
+/// and class expressions. Used to describe expressions in error messages.
+impl Display for Expression {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match &self.kind {
+			ExpressionKind::NullLiteral => f.write_str("null"),
+			ExpressionKind::BooleanLiteral(v) => Display::fmt(v, f),
+			ExpressionKind::NumericLiteral(v) => Display::fmt(v, f),
+			ExpressionKind::StringLiteral(v) => write!(f, "{:?}", v),
+			ExpressionKind::TemplateLiteral { strings, expressions } => {
+				f.write_str("`")?;
+				for (i, string) in strings.iter().enumerate() {
+					for ch in string.chars() {
+						match ch {
+							'`' | '$' => write!(f, "\\{}", ch)?,
+							ch => write!(f, "{}", ch.escape_debug())?,
+						}
+					}
+					if let Some(expr) = expressions.get(i) {
+						write!(f, "${{{}}}", expr)?;
+					}
+				}
+				f.write_str("`")
+			}
+			ExpressionKind::ArrayLiteral(items) => {
+				write!(f, "[{}]", items.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", "))
+			}
+			ExpressionKind::ObjectLiteral(properties) => {
+				if properties.is_empty() {
+					return f.write_str("{}");
+				}
+				let properties = properties
+					.iter()
+					.map(|(key, value)| {
+						match key {
+							MemberIndexer::PropertyName(name) if is_identifier(name) => {
+								format!("{}: {}", name, value)
+							}
+							MemberIndexer::PropertyName(name) => format!("{:?}: {}", name, value),
+							MemberIndexer::SubscriptExpression(expr) => format!("[{}]: {}", expr, value),
+						}
+					})
+					.collect::<Vec<_>>();
+				write!(f, "{{ {} }}", properties.join(", "))
+			}
+			ExpressionKind::ParenthesizedExpression(expr) => write!(f, "({})", expr),
+			ExpressionKind::UnaryExpression { operator, argument } => {
+				write!(f, "{}{}", operator, argument)
+			}
+			ExpressionKind::UpdateExpression { operator, prefix: true, argument } => {
+				write!(f, "{}{}", operator, argument)
+			}
+			ExpressionKind::UpdateExpression { operator, prefix: false, argument } => {
+				write!(f, "{}{}", argument, operator)
+			}
+			| ExpressionKind::BinaryExpression { operator, left, right }
+			| ExpressionKind::AssignmentExpression { operator, left, right } => {
+				write!(f, "{} {} {}", left, operator, right)
+			}
+			ExpressionKind::ConditionalExpression { condition, consequent, alternate } => {
+				write!(f, "{} ? {} : {}", condition, consequent, alternate)
+			}
+			ExpressionKind::DottedIdentifiers(idens) => {
+				let mut delimiters = idens.delimiters.iter();
+				for (i, iden) in idens.identifiers.iter().enumerate() {
+					if i != 0 {
+						f.write_str(delimiters.next().map_or(".", |v| v.data.as_str()))?;
+					}
+					f.write_str(iden)?;
+				}
+				Ok(())
+			}
+			ExpressionKind::Identifier(iden) => f.write_str(iden),
+			ExpressionKind::MemberAccess { object, member } => {
+				match member {
+					MemberIndexer::PropertyName(name) => write!(f, "{}.{}", object, name),
+					MemberIndexer::SubscriptExpression(expr) => write!(f, "{}[{}]", object, expr),
+				}
+			}
+			ExpressionKind::OptionalMemberAccess { object, member } => {
+				match member {
+					MemberIndexer::PropertyName(name) => write!(f, "{}?.{}", object, name),
+					MemberIndexer::SubscriptExpression(expr) => write!(f, "{}?.[{}]", object, expr),
+				}
+			}
+			ExpressionKind::OptionalChain(chain) => Display::fmt(chain, f),
+			ExpressionKind::SpreadExpression(expr) => write!(f, "...{}", expr),
+			ExpressionKind::DestructuringPattern(pattern) => Display::fmt(pattern, f),
+			ExpressionKind::FunctionCall(call) => write!(f, "{}", call),
+			ExpressionKind::NewObjectExpression(call) => write!(f, "new {}", call),
+			ExpressionKind::FunctionExpression(decl) => {
+				let params = decl.parameters
+					.iter()
+					.map(|v| v.to_string())
+					.collect::<Vec<_>>();
+				write!(f, "fn {}({}) {{ ... }}", decl.name, params.join(", "))
+			}
+			ExpressionKind::LambdaExpression(decl) => {
+				let params = decl.parameters
+					.iter()
+					.map(|v| v.to_string())
+					.collect::<Vec<_>>();
+				write!(f, "({}) => {{ ... }}", params.join(", "))
+			}
+			ExpressionKind::ClassDeclarationExpression(decl) => {
+				write!(f, "class {} {{ ... }}", decl.name)
+			}
+			ExpressionKind::MatchExpression { value, .. } => write!(f, "match ({}) {{ ... }}", value),
+		}
 	}
 }
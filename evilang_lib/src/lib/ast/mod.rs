@@ -2,4 +2,5 @@ pub mod statement;
 pub mod expression;
 pub mod operator;
 pub mod structs;
+pub mod pattern;
 
@@ -1,3 +1,5 @@
+use std::fmt::{ Display, Formatter };
+
 use crate::errors::{ ErrorT, EvilangError, ResultWithError };
 use crate::types::string::StringT;
 
@@ -8,12 +10,32 @@ pub enum Operator {
 	Multiplication,
 	Division,
 	Modulus,
+	FloorDivision,
+	Exponentiation,
+	BitwiseAnd,
+	BitwiseOr,
+	BitwiseXor,
+	BitwiseNot,
+	Increment,
+	Decrement,
+	LeftShift,
+	RightShift,
 	Assignment,
 	PlusAssignment,
 	MinusAssignment,
 	MultiplicationAssignment,
 	DivisionAssignment,
 	ModulusAssignment,
+	FloorDivisionAssignment,
+	ExponentiationAssignment,
+	BitwiseAndAssignment,
+	BitwiseOrAssignment,
+	BitwiseXorAssignment,
+	LeftShiftAssignment,
+	RightShiftAssignment,
+	NullishCoalescingAssignment,
+	LogicalOrAssignment,
+	LogicalAndAssignment,
 	LessThan,
 	GreaterThan,
 	LessThanOrEqualTo,
@@ -23,6 +45,7 @@ pub enum Operator {
 	LogicalAnd,
 	LogicalOr,
 	LogicalNot,
+	NullishCoalescing,
 }
 
 impl Operator {
@@ -34,7 +57,17 @@ impl Operator {
 				Operator::MinusAssignment |
 				Operator::MultiplicationAssignment |
 				Operator::DivisionAssignment |
-				Operator::ModulusAssignment
+				Operator::ModulusAssignment |
+				Operator::FloorDivisionAssignment |
+				Operator::ExponentiationAssignment |
+				Operator::BitwiseAndAssignment |
+				Operator::BitwiseOrAssignment |
+				Operator::BitwiseXorAssignment |
+				Operator::LeftShiftAssignment |
+				Operator::RightShiftAssignment |
+				Operator::NullishCoalescingAssignment |
+				Operator::LogicalOrAssignment |
+				Operator::LogicalAndAssignment
 		);
 	}
 
@@ -46,9 +79,109 @@ impl Operator {
 			Operator::MultiplicationAssignment => Ok(Operator::Multiplication),
 			Operator::DivisionAssignment => Ok(Operator::Division),
 			Operator::ModulusAssignment => Ok(Operator::Modulus),
+			Operator::FloorDivisionAssignment => Ok(Operator::FloorDivision),
+			Operator::ExponentiationAssignment => Ok(Operator::Exponentiation),
+			Operator::BitwiseAndAssignment => Ok(Operator::BitwiseAnd),
+			Operator::BitwiseOrAssignment => Ok(Operator::BitwiseOr),
+			Operator::BitwiseXorAssignment => Ok(Operator::BitwiseXor),
+			Operator::LeftShiftAssignment => Ok(Operator::LeftShift),
+			Operator::RightShiftAssignment => Ok(Operator::RightShift),
+			Operator::NullishCoalescingAssignment => Ok(Operator::NullishCoalescing),
+			Operator::LogicalOrAssignment => Ok(Operator::LogicalOr),
+			Operator::LogicalAndAssignment => Ok(Operator::LogicalAnd),
 			v => Err(ErrorT::CantStripAssignment(*v).into()),
 		};
 	}
+
+	pub fn symbol(&self) -> &'static str {
+		return match self {
+			Operator::Plus => "+",
+			Operator::Minus => "-",
+			Operator::Multiplication => "*",
+			Operator::Division => "/",
+			Operator::Modulus => "%",
+			Operator::FloorDivision => "~/",
+			Operator::Exponentiation => "**",
+			Operator::BitwiseAnd => "&",
+			Operator::BitwiseOr => "|",
+			Operator::BitwiseXor => "^",
+			Operator::BitwiseNot => "~",
+			Operator::Increment => "++",
+			Operator::Decrement => "--",
+			Operator::LeftShift => "<<",
+			Operator::RightShift => ">>",
+			Operator::Assignment => "=",
+			Operator::PlusAssignment => "+=",
+			Operator::MinusAssignment => "-=",
+			Operator::MultiplicationAssignment => "*=",
+			Operator::DivisionAssignment => "/=",
+			Operator::ModulusAssignment => "%=",
+			Operator::FloorDivisionAssignment => "~/=",
+			Operator::ExponentiationAssignment => "**=",
+			Operator::BitwiseAndAssignment => "&=",
+			Operator::BitwiseOrAssignment => "|=",
+			Operator::BitwiseXorAssignment => "^=",
+			Operator::LeftShiftAssignment => "<<=",
+			Operator::RightShiftAssignment => ">>=",
+			Operator::NullishCoalescingAssignment => "??=",
+			Operator::LogicalOrAssignment => "||=",
+			Operator::LogicalAndAssignment => "&&=",
+			Operator::LessThan => "<",
+			Operator::GreaterThan => ">",
+			Operator::LessThanOrEqualTo => "<=",
+			Operator::GreaterThanOrEqualTo => ">=",
+			Operator::Equals => "==",
+			Operator::NotEquals => "!=",
+			Operator::LogicalAnd => "&&",
+			Operator::LogicalOr => "||",
+			Operator::LogicalNot => "!",
+			Operator::NullishCoalescing => "??",
+		};
+	}
+
+	/// The name of the method an object can define to overload this operator when it is the left
+	/// operand of a binary expression, so that `a + b` calls `a.__add__(b)`.
+	pub fn binary_method_name(&self) -> Option<&'static str> {
+		return Some(match self {
+			Operator::Plus => "__add__",
+			Operator::Minus => "__sub__",
+			Operator::Multiplication => "__mul__",
+			Operator::Division => "__div__",
+			Operator::Modulus => "__mod__",
+			Operator::FloorDivision => "__floordiv__",
+			Operator::Exponentiation => "__pow__",
+			Operator::BitwiseAnd => "__and__",
+			Operator::BitwiseOr => "__or__",
+			Operator::BitwiseXor => "__xor__",
+			Operator::LeftShift => "__lshift__",
+			Operator::RightShift => "__rshift__",
+			Operator::LessThan => "__lt__",
+			Operator::GreaterThan => "__gt__",
+			Operator::LessThanOrEqualTo => "__le__",
+			Operator::GreaterThanOrEqualTo => "__ge__",
+			Operator::Equals => "__eq__",
+			Operator::NotEquals => "__ne__",
+			_ => return None,
+		});
+	}
+
+	/// The name of the method an object can define to overload this operator as a unary one, so
+	/// that `-a` calls `a.__neg__()`.
+	pub fn unary_method_name(&self) -> Option<&'static str> {
+		return Some(match self {
+			Operator::Minus => "__neg__",
+			Operator::Plus => "__pos__",
+			Operator::BitwiseNot => "__invert__",
+			_ => return None,
+		});
+	}
+}
+
+impl Display for Operator {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.symbol())
+	}
 }
 
 impl TryFrom<&StringT> for Operator {
@@ -61,12 +194,32 @@ impl TryFrom<&StringT> for Operator {
 			"*" => Ok(Operator::Multiplication),
 			"/" => Ok(Operator::Division),
 			"%" => Ok(Operator::Modulus),
+			"~/" => Ok(Operator::FloorDivision),
+			"**" => Ok(Operator::Exponentiation),
+			"&" => Ok(Operator::BitwiseAnd),
+			"|" => Ok(Operator::BitwiseOr),
+			"^" => Ok(Operator::BitwiseXor),
+			"~" => Ok(Operator::BitwiseNot),
+			"++" => Ok(Operator::Increment),
+			"--" => Ok(Operator::Decrement),
+			"<<" => Ok(Operator::LeftShift),
+			">>" => Ok(Operator::RightShift),
 			"=" => Ok(Operator::Assignment),
 			"+=" => Ok(Operator::PlusAssignment),
 			"-=" => Ok(Operator::MinusAssignment),
 			"*=" => Ok(Operator::MultiplicationAssignment),
 			"/=" => Ok(Operator::DivisionAssignment),
 			"%=" => Ok(Operator::ModulusAssignment),
+			"~/=" => Ok(Operator::FloorDivisionAssignment),
+			"**=" => Ok(Operator::ExponentiationAssignment),
+			"&=" => Ok(Operator::BitwiseAndAssignment),
+			"|=" => Ok(Operator::BitwiseOrAssignment),
+			"^=" => Ok(Operator::BitwiseXorAssignment),
+			"<<=" => Ok(Operator::LeftShiftAssignment),
+			">>=" => Ok(Operator::RightShiftAssignment),
+			"??=" => Ok(Operator::NullishCoalescingAssignment),
+			"||=" => Ok(Operator::LogicalOrAssignment),
+			"&&=" => Ok(Operator::LogicalAndAssignment),
 			"<" => Ok(Operator::LessThan),
 			">" => Ok(Operator::GreaterThan),
 			"<=" => Ok(Operator::LessThanOrEqualTo),
@@ -76,6 +229,7 @@ impl TryFrom<&StringT> for Operator {
 			"&&" => Ok(Operator::LogicalAnd),
 			"||" => Ok(Operator::LogicalOr),
 			"!" => Ok(Operator::LogicalNot),
+			"??" => Ok(Operator::NullishCoalescing),
 			_ => Err(ErrorT::UnknownOperator.into()),
 		};
 	}
@@ -0,0 +1,154 @@
+use std::fmt::{ Display, Formatter };
+
+use crate::ast::expression::{ BoxExpression, Expression, IdentifierT, MemberIndexer };
+use crate::tokenizer::is_identifier;
+
+pub type BoxPattern = Box<Pattern>;
+
+/// The left hand side of a destructuring declaration or assignment, or a pattern of a `match` arm,
+/// which takes apart a value and binds its pieces.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+	/// `name`, binds the whole value.
+	Identifier(IdentifierT),
+	/// Any other assignable expression, such as `object.property`, only found in destructuring
+	/// assignments.
+	Target(BoxExpression),
+	/// `[first, second = default, ...rest]`, destructures the items of a vector, with the items
+	/// after the `elements` collected into a new vector bound to `rest`.
+	Vector {
+		elements: Vec<PatternElement>,
+		rest: Option<BoxPattern>,
+	},
+	/// `{ name, key: pattern = default }`, destructures the properties of an object.
+	Object(Vec<PropertyPattern>),
+	/// `_`, matches any value without binding it, only found in `match` arms.
+	Wildcard,
+	/// A literal like `1`, `-2.5`, `"str"` or `null`, matches values equal to it, only found in
+	/// `match` arms.
+	Literal(BoxExpression),
+	/// `first | second`, matches a value that any of the alternatives matches, only found in
+	/// `match` arms.
+	Alternatives(Vec<Pattern>),
+}
+
+impl Pattern {
+	/// All the identifiers a declaration with this pattern declares, in order.
+	pub fn identifiers(&self) -> Vec<&IdentifierT> {
+		let mut res = vec![];
+		self.push_identifiers(&mut res);
+		return res;
+	}
+
+	fn push_identifiers<'a>(&'a self, res: &mut Vec<&'a IdentifierT>) {
+		match self {
+			Pattern::Identifier(identifier) => res.push(identifier),
+			Pattern::Target(_) | Pattern::Wildcard | Pattern::Literal(_) => {}
+			Pattern::Vector { elements, rest } => {
+				for element in elements.iter() {
+					element.pattern.push_identifiers(res);
+				}
+				if let Some(rest) = rest {
+					rest.push_identifiers(res);
+				}
+			}
+			Pattern::Object(properties) => {
+				for property in properties.iter() {
+					property.element.pattern.push_identifiers(res);
+				}
+			}
+			Pattern::Alternatives(alternatives) => {
+				for alternative in alternatives.iter() {
+					alternative.push_identifiers(res);
+				}
+			}
+		}
+	}
+}
+
+/// A pattern along with the value used in its place when the destructured value is `null`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternElement {
+	pub pattern: Pattern,
+	pub default: Option<Expression>,
+}
+
+impl PatternElement {
+	#[inline(always)]
+	pub fn new(pattern: Pattern, default: Option<Expression>) -> Self {
+		Self { pattern, default }
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyPattern {
+	pub key: MemberIndexer,
+	pub element: PatternElement,
+}
+
+impl PropertyPattern {
+	#[inline(always)]
+	pub fn new(key: MemberIndexer, element: PatternElement) -> Self {
+		Self { key, element }
+	}
+
+	/// `{ name }`, short for `{ name: name }`.
+	#[inline(always)]
+	pub fn new_shorthand(name: IdentifierT, default: Option<Expression>) -> Self {
+		Self {
+			key: MemberIndexer::PropertyName(name.clone()),
+			element: PatternElement::new(Pattern::Identifier(name), default),
+		}
+	}
+}
+
+impl Display for Pattern {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Pattern::Identifier(identifier) => f.write_str(identifier),
+			Pattern::Target(expr) => Display::fmt(expr, f),
+			Pattern::Vector { elements, rest } => {
+				let mut items = elements.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+				if let Some(rest) = rest {
+					items.push(format!("...{}", rest));
+				}
+				write!(f, "[{}]", items.join(", "))
+			}
+			Pattern::Object(properties) => {
+				if properties.is_empty() {
+					return f.write_str("{}");
+				}
+				let properties = properties.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+				write!(f, "{{ {} }}", properties.join(", "))
+			}
+			Pattern::Wildcard => f.write_str("_"),
+			Pattern::Literal(expr) => Display::fmt(expr, f),
+			Pattern::Alternatives(alternatives) => {
+				let alternatives = alternatives.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+				f.write_str(&alternatives.join(" | "))
+			}
+		}
+	}
+}
+
+impl Display for PatternElement {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.pattern, f)?;
+		if let Some(default) = &self.default {
+			write!(f, " = {}", default)?;
+		}
+		Ok(())
+	}
+}
+
+impl Display for PropertyPattern {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match (&self.key, &self.element.pattern) {
+			(MemberIndexer::PropertyName(name), Pattern::Identifier(identifier)) if name == identifier => {}
+			(MemberIndexer::PropertyName(name), _) if is_identifier(name) => write!(f, "{}: ", name)?,
+			(MemberIndexer::PropertyName(name), _) => write!(f, "{:?}: ", name)?,
+			(MemberIndexer::SubscriptExpression(expr), _) => write!(f, "[{}]: ", expr)?,
+		}
+		Display::fmt(&self.element, f)
+	}
+}
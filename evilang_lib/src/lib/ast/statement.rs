@@ -1,22 +1,56 @@
 use crate::ast::expression::{ DottedIdentifiers, Expression, IdentifierT };
+use crate::ast::pattern::Pattern;
 use crate::ast::structs::{
+	CatchClause,
 	ClassDeclaration,
 	FunctionDeclaration,
 	FunctionParameterDeclaration,
+	MatchArm,
 	VariableDeclaration,
 };
+use crate::types::span::Span;
 
 pub type BoxStatement = Box<Statement>;
 
 pub type StatementList = Vec<Statement>;
 
+#[derive(Debug, Clone)]
+pub struct Statement {
+	pub kind: StatementKind,
+	pub span: Span,
+}
+
+/// Statements are compared structurally, the span they were parsed from is ignored.
+impl PartialEq for Statement {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		return self.kind == other.kind;
+	}
+}
+
+impl From<StatementKind> for Statement {
+	#[inline(always)]
+	fn from(kind: StatementKind) -> Self {
+		return Statement { kind, span: Span::default() };
+	}
+}
+
+impl From<StatementKind> for BoxStatement {
+	#[inline(always)]
+	fn from(kind: StatementKind) -> Self {
+		return Box::new(kind.into());
+	}
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub enum Statement {
+pub enum StatementKind {
 	BlockStatement(StatementList),
 	EmptyStatement,
 	ExpressionStatement(Expression),
 	ReturnStatement(Option<Expression>),
 	VariableDeclarations(Vec<VariableDeclaration>),
+	/// `const a = 1;`, declarations that can't be assigned to once initialized.
+	ConstantDeclarations(Vec<VariableDeclaration>),
 	IfStatement {
 		condition: Expression,
 		if_branch: BoxStatement,
@@ -36,8 +70,34 @@ pub enum Statement {
 		increment: BoxStatement,
 		body: BoxStatement,
 	},
+	/// `for (let pattern of iterable) body`, runs the body with each item of a vector, or each
+	/// value produced by the iterator returned by the `iter()` method of any other object.
+	ForOfLoop {
+		pattern: Pattern,
+		iterable: Expression,
+		body: BoxStatement,
+	},
+	/// `for (let pattern in object) body`, runs the body with the name of each property of an
+	/// object.
+	ForInLoop {
+		pattern: Pattern,
+		object: Expression,
+		body: BoxStatement,
+	},
 	BreakStatement(i64),
 	ContinueStatement(i64),
+	ThrowStatement(Expression),
+	/// A `try` block followed by a `catch` clause, a `finally` block, or both.
+	TryStatement {
+		body: BoxStatement,
+		catch_clause: Option<CatchClause>,
+		finally_block: Option<BoxStatement>,
+	},
+	/// Runs the body of the first arm that matches the value, if any.
+	MatchStatement {
+		value: Expression,
+		arms: Vec<MatchArm<BoxStatement>>,
+	},
 	FunctionDeclarationStatement(FunctionDeclaration),
 	ClassDeclarationStatement(ClassDeclaration),
 	NamespaceStatement {
@@ -48,6 +108,9 @@ pub enum Statement {
 		file_name: Expression,
 		as_object: DottedIdentifiers,
 	},
+	/// Placeholder for a statement that could not be parsed, the parser skipped over it to report
+	/// the errors in the rest of the program.
+	ErrorStatement,
 }
 
 impl Statement {
@@ -57,17 +120,17 @@ impl Statement {
 		if_branch: BoxStatement,
 		else_branch: Option<BoxStatement>
 	) -> Statement {
-		return Statement::IfStatement { condition, if_branch, else_branch };
+		return StatementKind::IfStatement { condition, if_branch, else_branch }.into();
 	}
 
 	#[inline(always)]
 	pub fn while_loop(condition: Expression, body: BoxStatement) -> Statement {
-		return Statement::WhileLoop { condition, body };
+		return StatementKind::WhileLoop { condition, body }.into();
 	}
 
 	#[inline(always)]
 	pub fn do_while_loop(condition: Expression, body: BoxStatement) -> Statement {
-		return Statement::DoWhileLoop { condition, body };
+		return StatementKind::DoWhileLoop { condition, body }.into();
 	}
 
 	#[inline(always)]
@@ -77,12 +140,36 @@ impl Statement {
 		increment: BoxStatement,
 		body: BoxStatement
 	) -> Statement {
-		return Statement::ForLoop {
+		return (StatementKind::ForLoop {
 			initialization,
 			condition,
 			increment,
 			body,
-		};
+		}).into();
+	}
+
+	#[inline(always)]
+	pub fn for_of_loop(pattern: Pattern, iterable: Expression, body: BoxStatement) -> Statement {
+		return StatementKind::ForOfLoop { pattern, iterable, body }.into();
+	}
+
+	#[inline(always)]
+	pub fn for_in_loop(pattern: Pattern, object: Expression, body: BoxStatement) -> Statement {
+		return StatementKind::ForInLoop { pattern, object, body }.into();
+	}
+
+	#[inline(always)]
+	pub fn try_statement(
+		body: BoxStatement,
+		catch_clause: Option<CatchClause>,
+		finally_block: Option<BoxStatement>
+	) -> Statement {
+		return StatementKind::TryStatement { body, catch_clause, finally_block }.into();
+	}
+
+	#[inline(always)]
+	pub fn match_statement(value: Expression, arms: Vec<MatchArm<BoxStatement>>) -> Statement {
+		return StatementKind::MatchStatement { value, arms }.into();
 	}
 
 	#[inline(always)]
@@ -91,9 +178,9 @@ impl Statement {
 		parameters: Vec<FunctionParameterDeclaration>,
 		body: BoxStatement
 	) -> Statement {
-		return Statement::FunctionDeclarationStatement(
+		return StatementKind::FunctionDeclarationStatement(
 			FunctionDeclaration::new(name, parameters, body)
-		);
+		).into();
 	}
 
 	#[inline(always)]
@@ -102,24 +189,32 @@ impl Statement {
 		super_class: Option<Expression>,
 		methods: Vec<FunctionDeclaration>
 	) -> Statement {
-		return Statement::ClassDeclarationStatement(ClassDeclaration::new(name, super_class, methods));
+		return StatementKind::ClassDeclarationStatement(
+			ClassDeclaration::new(name, super_class, methods)
+		).into();
 	}
 
 	#[inline(always)]
 	pub fn namespace_statement(namespace: DottedIdentifiers, body: StatementList) -> Statement {
-		return Statement::NamespaceStatement { namespace, body };
+		return StatementKind::NamespaceStatement { namespace, body }.into();
 	}
 
 	#[inline(always)]
 	pub fn import_statement(file_name: Expression, as_object: DottedIdentifiers) -> Statement {
-		return Statement::ImportStatement { file_name, as_object };
+		return StatementKind::ImportStatement { file_name, as_object }.into();
+	}
+
+	#[inline(always)]
+	pub fn with_span(self, span: Span) -> Statement {
+		return Statement { span, ..self };
 	}
 }
 
 impl From<Expression> for Statement {
 	#[inline(always)]
 	fn from(value: Expression) -> Self {
-		return Statement::ExpressionStatement(value);
+		let span = value.span;
+		return Statement { kind: StatementKind::ExpressionStatement(value), span };
 	}
 }
 
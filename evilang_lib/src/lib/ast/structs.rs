@@ -1,36 +1,154 @@
+use std::fmt::{ Display, Formatter };
+
 use crate::ast::expression::{ BoxExpression, Expression, IdentifierT };
+use crate::ast::pattern::Pattern;
 use crate::ast::statement::BoxStatement;
+use crate::types::span::Span;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableDeclaration {
-	pub identifier: IdentifierT,
+	pub pattern: Pattern,
 	pub initializer: Option<Expression>,
 }
 
 impl VariableDeclaration {
 	#[inline(always)]
 	pub fn new(identifier: IdentifierT, initializer: Option<Expression>) -> Self {
-		Self { identifier, initializer }
+		Self { pattern: Pattern::Identifier(identifier), initializer }
+	}
+
+	#[inline(always)]
+	pub fn new_destructuring(pattern: Pattern, initializer: Expression) -> Self {
+		Self { pattern, initializer: Some(initializer) }
 	}
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionParameterDeclaration {
-	pub identifier: IdentifierT,
+	pub pattern: Pattern,
+	/// Evaluated in the scope of the function when no argument is passed for this parameter.
+	pub default: Option<Expression>,
+	/// A `...rest` parameter, which collects all the remaining arguments into a vector.
+	pub is_rest: bool,
 }
 
 impl FunctionParameterDeclaration {
 	#[inline(always)]
 	pub fn new(identifier: IdentifierT) -> Self {
-		Self { identifier }
+		Self::new_destructuring(Pattern::Identifier(identifier), None)
+	}
+
+	#[inline(always)]
+	pub fn new_with_default(identifier: IdentifierT, default: Expression) -> Self {
+		Self::new_destructuring(Pattern::Identifier(identifier), Some(default))
+	}
+
+	#[inline(always)]
+	pub fn new_destructuring(pattern: Pattern, default: Option<Expression>) -> Self {
+		Self { pattern, default, is_rest: false }
+	}
+
+	#[inline(always)]
+	pub fn new_rest(identifier: IdentifierT) -> Self {
+		Self { pattern: Pattern::Identifier(identifier), default: None, is_rest: true }
+	}
+
+	/// Whether an argument has to be passed for this parameter.
+	#[inline(always)]
+	pub fn is_required(&self) -> bool {
+		return self.default.is_none() && !self.is_rest;
 	}
 }
 
+impl Display for FunctionParameterDeclaration {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		if self.is_rest {
+			f.write_str("...")?;
+		}
+		Display::fmt(&self.pattern, f)?;
+		if let Some(default) = &self.default {
+			write!(f, " = {}", default)?;
+		}
+		Ok(())
+	}
+}
+
+/// A binding listed after the `captures` keyword of a function.
 #[derive(Debug, Clone, PartialEq)]
+pub enum CaptureDeclaration {
+	/// `name`, a snapshot of the value of the variable when the closure is created.
+	ByValue(IdentifierT),
+	/// `&name`, the variable itself, shared with the enclosing scope.
+	ByReference(IdentifierT),
+	/// `name = expression`, the value of the expression when the closure is created.
+	Initialized(IdentifierT, Expression),
+}
+
+impl CaptureDeclaration {
+	#[inline(always)]
+	pub fn identifier(&self) -> &IdentifierT {
+		return match self {
+			| CaptureDeclaration::ByValue(identifier)
+			| CaptureDeclaration::ByReference(identifier)
+			| CaptureDeclaration::Initialized(identifier, _) => identifier,
+		};
+	}
+}
+
+/// `catch (parameter) { ... }`, the parameter is bound to the thrown value, and can be left out
+/// along with its parentheses when the value isn't needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatchClause {
+	pub parameter: Option<Pattern>,
+	pub body: BoxStatement,
+}
+
+impl CatchClause {
+	#[inline(always)]
+	pub fn new(parameter: Option<Pattern>, body: BoxStatement) -> Self {
+		Self { parameter, body }
+	}
+}
+
+/// `pattern if guard => body`, an arm of a `match` statement or expression, whose `body` is run
+/// when the pattern matches the value and the guard, evaluated with the bindings of the pattern,
+/// is truthy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm<T> {
+	pub pattern: Pattern,
+	pub guard: Option<Expression>,
+	pub body: T,
+}
+
+impl<T> MatchArm<T> {
+	#[inline(always)]
+	pub fn new(pattern: Pattern, guard: Option<Expression>, body: T) -> Self {
+		Self { pattern, guard, body }
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct FunctionDeclaration {
 	pub name: IdentifierT,
 	pub parameters: Vec<FunctionParameterDeclaration>,
+	/// The bindings the function is restricted to, if it has a `captures` list, otherwise it can
+	/// access every variable in the scope it was declared in.
+	pub captures: Option<Vec<CaptureDeclaration>>,
 	pub body: BoxStatement,
+	/// Declared with `const fn`, so that the name it is declared as can't be assigned to.
+	pub is_constant: bool,
+	pub span: Span,
+}
+
+/// Declarations are compared structurally, the span they were parsed from is ignored.
+impl PartialEq for FunctionDeclaration {
+	fn eq(&self, other: &Self) -> bool {
+		return self.name == other.name &&
+			self.parameters == other.parameters &&
+			self.captures == other.captures &&
+			self.body == other.body &&
+			self.is_constant == other.is_constant;
+	}
 }
 
 impl FunctionDeclaration {
@@ -40,15 +158,49 @@ impl FunctionDeclaration {
 		parameters: Vec<FunctionParameterDeclaration>,
 		body: BoxStatement
 	) -> Self {
-		Self { name, parameters, body }
+		Self { name, parameters, captures: None, body, is_constant: false, span: Span::default() }
+	}
+
+	#[inline(always)]
+	pub fn with_captures(self, captures: Option<Vec<CaptureDeclaration>>) -> Self {
+		Self { captures, ..self }
+	}
+
+	#[inline(always)]
+	pub fn as_constant(self) -> Self {
+		Self { is_constant: true, ..self }
+	}
+
+	#[inline(always)]
+	pub fn with_span(self, span: Span) -> Self {
+		Self { span, ..self }
 	}
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct ClassDeclaration {
 	pub name: IdentifierT,
 	pub super_class: Option<Expression>,
 	pub methods: Vec<FunctionDeclaration>,
+	/// Declared with `get name() { ... }`, called when `name` is read off of an instance.
+	pub getters: Vec<FunctionDeclaration>,
+	/// Declared with `set name(value) { ... }`, called when `name` of an instance is assigned to.
+	pub setters: Vec<FunctionDeclaration>,
+	/// Declared with `const class`, so that the name it is declared as can't be assigned to.
+	pub is_constant: bool,
+	pub span: Span,
+}
+
+/// Declarations are compared structurally, the span they were parsed from is ignored.
+impl PartialEq for ClassDeclaration {
+	fn eq(&self, other: &Self) -> bool {
+		return self.name == other.name &&
+			self.super_class == other.super_class &&
+			self.methods == other.methods &&
+			self.getters == other.getters &&
+			self.setters == other.setters &&
+			self.is_constant == other.is_constant;
+	}
 }
 
 impl ClassDeclaration {
@@ -58,7 +210,34 @@ impl ClassDeclaration {
 		super_class: Option<Expression>,
 		methods: Vec<FunctionDeclaration>
 	) -> Self {
-		Self { name, super_class, methods }
+		Self {
+			name,
+			super_class,
+			methods,
+			getters: vec![],
+			setters: vec![],
+			is_constant: false,
+			span: Span::default(),
+		}
+	}
+
+	#[inline(always)]
+	pub fn with_accessors(
+		self,
+		getters: Vec<FunctionDeclaration>,
+		setters: Vec<FunctionDeclaration>
+	) -> Self {
+		Self { getters, setters, ..self }
+	}
+
+	#[inline(always)]
+	pub fn as_constant(self) -> Self {
+		Self { is_constant: true, ..self }
+	}
+
+	#[inline(always)]
+	pub fn with_span(self, span: Span) -> Self {
+		Self { span, ..self }
 	}
 }
 
@@ -75,3 +254,16 @@ impl CallExpression {
 	}
 }
 
+
+impl Display for CallExpression {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}(", self.callee)?;
+		for (i, arg) in self.arguments.iter().enumerate() {
+			if i != 0 {
+				f.write_str(", ")?;
+			}
+			Display::fmt(arg, f)?;
+		}
+		f.write_str(")")
+	}
+}
@@ -0,0 +1,129 @@
+use std::fmt::{ Display, Formatter, Write };
+
+use crate::errors::EvilangError;
+use crate::types::source_map::{ get_source_file, SourceFile };
+use crate::types::span::Span;
+use crate::types::string::StringT;
+
+const ANONYMOUS_FILE_NAME: &str = "<input>";
+
+/// A user facing description of an error: its stable code, a short message, and the line of
+/// source code that it was raised from, when that is known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+	pub code: &'static str,
+	pub message: StringT,
+	pub span: Option<Span>,
+	pub file_name: Option<StringT>,
+	pub source_line: Option<StringT>,
+	/// The range of characters of `source_line` that the error points to.
+	pub highlight: Option<(usize, usize)>,
+}
+
+impl Diagnostic {
+	/// Creates the diagnostic for an error, looking up the source code it refers to in the source
+	/// map.
+	pub fn new(error: &EvilangError) -> Diagnostic {
+		let file = error.span.and_then(|span| get_source_file(span.file_id));
+		return Self::new_with_source(error, file.as_deref());
+	}
+
+	/// Creates the diagnostic for an error raised from source code that is not in the source map,
+	/// such as a program evaluated directly from a string.
+	pub fn new_with_source(error: &EvilangError, file: Option<&SourceFile>) -> Diagnostic {
+		let mut res = Diagnostic {
+			code: error.code(),
+			message: error.typ.to_string(),
+			span: error.span,
+			file_name: file.map(|v| v.name.clone()),
+			source_line: None,
+			highlight: None,
+		};
+		let (Some(span), Some(file)) = (error.span, file) else {
+			return res;
+		};
+		let (line, line_start) = file.line_at(span.start);
+		let from = span.start.clamp(line_start, line_start + line.len()) - line_start;
+		let to = span.end.clamp(line_start + from, line_start + line.len()) - line_start;
+		let start = line[..from].chars().count();
+		let len = line[from..to].chars().count();
+		res.source_line = Some(line.to_string());
+		res.highlight = Some((start, start + len.max(1)));
+		return res;
+	}
+
+	/// Renders the diagnostic for a terminal: the code and message followed by the offending line
+	/// of source code, with the erroneous part underlined.
+	pub fn render(&self) -> StringT {
+		let mut res = format!("error[{}]: {}", self.code, self.message);
+		let Some(span) = self.span else {
+			return res;
+		};
+		let file_name = self.file_name.as_deref().unwrap_or(ANONYMOUS_FILE_NAME);
+		let gutter = " ".repeat(span.line.to_string().len());
+		write!(res, "\n{} --> {}:{}:{}", gutter, file_name, span.line, span.column).unwrap();
+		let (Some(line), Some((start, end))) = (&self.source_line, self.highlight) else {
+			return res;
+		};
+		// Keep the tabs before the highlighted part so that the underline lines up with it.
+		let padding = line
+			.chars()
+			.take(start)
+			.map(|c| if c == '\t' { '\t' } else { ' ' })
+			.collect::<StringT>();
+		write!(res, "\n{} |", gutter).unwrap();
+		write!(res, "\n{} | {}", span.line, line).unwrap();
+		write!(res, "\n{} | {}{}", gutter, padding, "^".repeat(end - start)).unwrap();
+		return res;
+	}
+
+	/// Renders the diagnostic as a single line JSON object, for editors and other tools.
+	pub fn to_json(&self) -> StringT {
+		let mut res = StringT::from("{");
+		write!(res, "\"code\":{}", json_string(self.code)).unwrap();
+		write!(res, ",\"message\":{}", json_string(&self.message)).unwrap();
+		let file_name = self.file_name.as_deref().map(json_string);
+		write!(res, ",\"file\":{}", file_name.as_deref().unwrap_or("null")).unwrap();
+		if let Some(span) = self.span {
+			write!(
+				res,
+				",\"line\":{},\"column\":{},\"start\":{},\"end\":{}",
+				span.line,
+				span.column,
+				span.start,
+				span.end
+			).unwrap();
+		} else {
+			res.push_str(",\"line\":null,\"column\":null,\"start\":null,\"end\":null");
+		}
+		let source_line = self.source_line.as_deref().map(json_string);
+		write!(res, ",\"source_line\":{}", source_line.as_deref().unwrap_or("null")).unwrap();
+		res.push('}');
+		return res;
+	}
+}
+
+impl Display for Diagnostic {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.render())
+	}
+}
+
+fn json_string(str: &str) -> StringT {
+	let mut res = StringT::with_capacity(str.len() + 2);
+	res.push('"');
+	for c in str.chars() {
+		match c {
+			'"' => res.push_str("\\\""),
+			'\\' => res.push_str("\\\\"),
+			'\n' => res.push_str("\\n"),
+			'\r' => res.push_str("\\r"),
+			'\t' => res.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(res, "\\u{:04x}", c as u32).unwrap(),
+			c => res.push(c),
+		}
+	}
+	res.push('"');
+	return res;
+}
@@ -1,6 +1,7 @@
 use std::error::Error;
 use std::fmt::{ Display, Formatter };
 use std::io;
+use std::ops::{ Deref, DerefMut };
 
 use backtrace::Backtrace;
 use maybe_owned::MaybeOwned;
@@ -11,6 +12,8 @@ use crate::ast::operator::Operator;
 use crate::ast::statement::Statement;
 use crate::interpreter::runtime_values::PrimitiveValue;
 use crate::tokenizer::Token;
+use crate::types::source_map::get_source_file;
+use crate::types::span::Span;
 use crate::types::string::StringT;
 
 pub type ResultWithError<T, E = EvilangError> = anyhow::Result<T, E>;
@@ -87,6 +90,39 @@ impl Clone for Descriptor {
 	}
 }
 
+/// Describes the value or expression concisely, for use in error messages.
+impl Display for Descriptor {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Descriptor::None => f.write_str("value"),
+			Descriptor::Name(name) => write!(f, "`{}`", name),
+			Descriptor::Value(value) => Descriptor::fmt_value(value, f),
+			Descriptor::Expression(expression) => write!(f, "`{}`", expression),
+			Descriptor::ExpressionAndValue { expression, value } => {
+				write!(f, "`{}` (which is ", expression)?;
+				Descriptor::fmt_value(value, f)?;
+				f.write_str(")")
+			}
+			Descriptor::NameAndValue { name, value } => {
+				write!(f, "`{}` (which is ", name)?;
+				Descriptor::fmt_value(value, f)?;
+				f.write_str(")")
+			}
+		}
+	}
+}
+
+impl Descriptor {
+	fn fmt_value(value: &PrimitiveValue, f: &mut Formatter<'_>) -> std::fmt::Result {
+		return match value {
+			PrimitiveValue::String(str) => write!(f, "{:?}", str),
+			PrimitiveValue::Function(_) => write!(f, "the function `{}`", value),
+			PrimitiveValue::Object(_) => write!(f, "the object `{}`", value),
+			value => write!(f, "`{}`", value),
+		};
+	}
+}
+
 impl From<&str> for Descriptor {
 	#[inline(always)]
 	fn from(value: &str) -> Self {
@@ -118,27 +154,68 @@ impl From<Expression> for Descriptor {
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum RuntimeError {
 	#[error("{0}")] GenericError(StringT),
-	#[error("Expected {0:#?} to not be null")] UnexpectedNullValue(Descriptor),
-	#[error("Expected {0:#?} to be a boolean")] ExpectedBoolean(Descriptor),
-	#[error("Expected {0:#?} to be a number")] ExpectedNumber(Descriptor),
-	#[error("Expected {0:#?} to be a string")] ExpectedString(Descriptor),
-	#[error("Expected {0:#?} to be a function")] ExpectedFunction(Descriptor),
-	#[error("Expected {0:#?} to be a class object")] ExpectedClassObject(Descriptor),
-	#[error("Expected {0:#?} to be a namespace object")] ExpectedNamespaceObject(Descriptor),
-	#[error("Expected {0:#?} to be an object")] ExpectedObject(Descriptor),
-	#[error("Expected {0:#?} to be a native struct object")] ExpectedNativeObject(Descriptor),
-	#[error("Invalid arguments {0:#?}: {1:#?}")] InvalidArgumentsToFunction(String, Descriptor),
+	#[error("Expected {0} to not be null")] UnexpectedNullValue(Descriptor),
+	#[error("Expected {0} to be a boolean")] ExpectedBoolean(Descriptor),
+	#[error("Expected {0} to be a number")] ExpectedNumber(Descriptor),
+	#[error("Expected {0} to be a string")] ExpectedString(Descriptor),
+	#[error("Expected {0} to be a function")] ExpectedFunction(Descriptor),
+	#[error("Expected {0} to be a class object")] ExpectedClassObject(Descriptor),
+	#[error("Expected {0} to be a namespace object")] ExpectedNamespaceObject(Descriptor),
+	#[error("Expected {0} to be an object")] ExpectedObject(Descriptor),
+	#[error("Expected {0} to be a native struct object")] ExpectedNativeObject(Descriptor),
+	#[error("Invalid arguments, {0}: {1}")] InvalidArgumentsToFunction(String, Descriptor),
 	#[error(
-		"Invalid number of arguments {got:?} expected {expected:?} to be function {func:#?}"
+		"Invalid number of arguments to {func}: got {got}{}",
+		expected.as_ref().map(|v| format!(", expected {}", v)).unwrap_or_default()
 	)] InvalidNumberArgumentsToFunction {
 		got: usize,
 		expected: Option<StringT>,
 		func: Descriptor,
 	},
-	#[error("Expected {0:#?} to be a valid subscript expression")] ExpectedValidSubscript(Descriptor),
-	#[error("Expected {0:#?} to be a valid file name expression")] ExpectedValidFileName(Descriptor),
+	#[error("Expected {0} to be a valid subscript expression")] ExpectedValidSubscript(Descriptor),
+	#[error("Expected {0} to be a valid file name expression")] ExpectedValidFileName(Descriptor),
 	#[error("{0}")] IOError(StringT),
-	#[error("Expression can not be cloned: {0:#?}")] CantCloneSafely(Descriptor),
+	#[error("Expression can not be cloned: {0}")] CantCloneSafely(Descriptor),
+	/// A value thrown by a `throw` statement that has unrolled out of a function or the program.
+	#[error("Uncaught exception: {0}")] UncaughtException(Descriptor),
+	/// A `match` expression whose arms all failed to match the value.
+	#[error("No arm of the match expression matches {0}")] NoMatchingArm(Descriptor),
+	#[error("Expected {0} to be an integer")] ExpectedInteger(Descriptor),
+	/// An operation on numbers whose result can't be represented, like dividing an integer by zero.
+	#[error("{1} in {0}")] InvalidArithmetic(Descriptor, StringT),
+	/// An assignment to a property that has a getter but no setter.
+	#[error("Can't set the property `{0}`, it only has a getter")] PropertyWithoutSetter(IdentifierT),
+}
+
+impl RuntimeError {
+	/// The stable diagnostic code of this error, runtime errors are numbered from `E1000`.
+	///
+	/// Codes are never reused or renumbered, new variants get the next free code.
+	pub fn code(&self) -> &'static str {
+		return match self {
+			RuntimeError::GenericError(..) => "E1000",
+			RuntimeError::UnexpectedNullValue(..) => "E1001",
+			RuntimeError::ExpectedBoolean(..) => "E1002",
+			RuntimeError::ExpectedNumber(..) => "E1003",
+			RuntimeError::ExpectedString(..) => "E1004",
+			RuntimeError::ExpectedFunction(..) => "E1005",
+			RuntimeError::ExpectedClassObject(..) => "E1006",
+			RuntimeError::ExpectedNamespaceObject(..) => "E1007",
+			RuntimeError::ExpectedObject(..) => "E1008",
+			RuntimeError::ExpectedNativeObject(..) => "E1009",
+			RuntimeError::InvalidArgumentsToFunction(..) => "E1010",
+			RuntimeError::InvalidNumberArgumentsToFunction { .. } => "E1011",
+			RuntimeError::ExpectedValidSubscript(..) => "E1012",
+			RuntimeError::ExpectedValidFileName(..) => "E1013",
+			RuntimeError::IOError(..) => "E1014",
+			RuntimeError::CantCloneSafely(..) => "E1015",
+			RuntimeError::UncaughtException(..) => "E1016",
+			RuntimeError::NoMatchingArm(..) => "E1017",
+			RuntimeError::ExpectedInteger(..) => "E1018",
+			RuntimeError::InvalidArithmetic(..) => "E1019",
+			RuntimeError::PropertyWithoutSetter(..) => "E1020",
+		};
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -147,7 +224,7 @@ pub enum ErrorT {
 	#[error(transparent)] UnexpectedRuntimeError(#[from] RuntimeError),
 	#[error("End of Token Stream")]
 	EndOfTokenStream,
-	#[error("Invalid Token Type: {0:#?}")] InvalidTokenType(Token),
+	#[error("Unexpected token: {0}")] InvalidTokenType(Token),
 	#[error("Token Cannot be Parsed")]
 	TokenCannotBeParsed,
 	#[error("Invalid numeric literal: {0}")] InvalidNumericLiteral(StringT),
@@ -160,41 +237,153 @@ pub enum ErrorT {
 	#[error("Expected at least one variable in declaration")]
 	ExpectedVariableDeclaration,
 	#[error(
-		"The interpreter does not support this statement type: {0:#?}"
+		"The interpreter does not support this statement type"
 	)] UnimplementedStatementTypeForInterpreter(Statement),
 	#[error(
-		"The interpreter does not support this expression type: {0:#?}"
+		"The interpreter does not support this expression type: `{0}`"
 	)] UnimplementedExpressionTypeForInterpreter(Expression),
 	#[error(
-		"The interpreter does not support the operator: {0:?} for the values {1:#?} and {2:#?}"
+		"The interpreter does not support the operator `{0}` for the values of `{1}` and `{2}`"
 	)] UnimplementedBinaryOperatorForValues(Operator, Expression, Expression),
 	#[error(
-		"The interpreter does not support the unary operator: {0:?} for the value {1:#?}"
+		"The interpreter does not support the unary operator `{0}` for the value of `{1}`"
 	)] UnimplementedUnaryOperatorForValues(Operator, Expression),
 	#[error("A mutable borrow already exists")]
 	InvalidBorrow,
-	#[error("The cannot strip assignment from operator: {0:?}")] CantStripAssignment(Operator),
+	#[error("The cannot strip assignment from operator: `{0}`")] CantStripAssignment(Operator),
 	#[error(
-		"Can't access variable '{0:?}' before the point in time at which it has been declared"
+		"Can't access variable `{0}` before the point in time at which it has been declared"
 	)] CantAccessHoistedVariable(IdentifierT),
 	#[error(
-		"Can't declare variable '{0:?}' since it already exists in this scope"
+		"Can't declare variable `{0}` since it already exists in this scope"
 	)] CantRedeclareVariable(IdentifierT),
 	#[error("Can't set a variable to be hoisted")]
 	CantSetToHoistedValue,
-	#[error("Invalid unrolling from function {0:?}: {1:#?}")] InvalidUnrollingOfFunction(
+	#[error("Invalid unrolling from function `{0}`: {1}")] InvalidUnrollingOfFunction(
 		IdentifierT,
 		StringT,
 	),
 	#[error(
-		"Member functions accessed by the arrow notation mus be immediately called: {0:#?}"
+		"Member functions accessed by the arrow notation mus be immediately called: `{0}`"
 	)] InvalidMethodArrowAccess(Expression),
+	#[error("Can't execute a statement that failed to parse")]
+	ExecutingErrorStatement,
+	#[error("Invalid escape sequence in string literal: `{0}`")]
+	InvalidEscapeSequence(StringT),
+	#[error(
+		"Can't access variable `{0}` since it is not in the capture list of the enclosing function"
+	)] UncapturedVariable(IdentifierT),
+	#[error("The rest parameter `{0}` must be the last parameter of the function")]
+	RestParameterNotLast(IdentifierT),
+	#[error("Expected a `catch` clause or a `finally` block after the `try` block")]
+	ExpectedCatchOrFinally,
+	#[error("There is no enclosing loop labeled `{0}`")] UnknownLoopLabel(IdentifierT),
+	#[error("Can't assign to `{0}`, it is a constant")] AssignmentToConstant(IdentifierT),
+	#[error("The number of loops to break out of or continue must be a positive integer, got `{0}`")]
+	InvalidLoopDepth(StringT),
+	#[error("Can't capture `{0}`, there is no variable with that name")]
+	UnknownCapturedVariable(IdentifierT),
+}
+
+impl ErrorT {
+	/// The stable diagnostic code of this error, errors raised while parsing or by the
+	/// interpreter itself are numbered from `E0001`, runtime errors use their own codes.
+	///
+	/// Codes are never reused or renumbered, new variants get the next free code.
+	pub fn code(&self) -> &'static str {
+		return match self {
+			ErrorT::NeverError(..) => "E0001",
+			ErrorT::UnexpectedRuntimeError(err) => err.code(),
+			ErrorT::EndOfTokenStream => "E0002",
+			ErrorT::InvalidTokenType(..) => "E0003",
+			ErrorT::TokenCannotBeParsed => "E0004",
+			ErrorT::InvalidNumericLiteral(..) => "E0005",
+			ErrorT::UnknownOperator => "E0006",
+			ErrorT::ExpectedLhsExpression => "E0007",
+			ErrorT::ExpectedSimpleAssignmentOperator => "E0008",
+			ErrorT::ExpectedVariableDeclaration => "E0009",
+			ErrorT::UnimplementedStatementTypeForInterpreter(..) => "E0010",
+			ErrorT::UnimplementedExpressionTypeForInterpreter(..) => "E0011",
+			ErrorT::UnimplementedBinaryOperatorForValues(..) => "E0012",
+			ErrorT::UnimplementedUnaryOperatorForValues(..) => "E0013",
+			ErrorT::InvalidBorrow => "E0014",
+			ErrorT::CantStripAssignment(..) => "E0015",
+			ErrorT::CantAccessHoistedVariable(..) => "E0016",
+			ErrorT::CantRedeclareVariable(..) => "E0017",
+			ErrorT::CantSetToHoistedValue => "E0018",
+			ErrorT::InvalidUnrollingOfFunction(..) => "E0019",
+			ErrorT::InvalidMethodArrowAccess(..) => "E0020",
+			ErrorT::ExecutingErrorStatement => "E0021",
+			ErrorT::InvalidEscapeSequence(..) => "E0022",
+			ErrorT::UncapturedVariable(..) => "E0023",
+			ErrorT::RestParameterNotLast(..) => "E0024",
+			ErrorT::ExpectedCatchOrFinally => "E0025",
+			ErrorT::UnknownLoopLabel(..) => "E0026",
+			ErrorT::AssignmentToConstant(..) => "E0027",
+			ErrorT::InvalidLoopDepth(..) => "E0028",
+			ErrorT::UnknownCapturedVariable(..) => "E0029",
+		};
+	}
+}
+
+impl ErrorT {
+	/// Whether a `catch` clause can handle the error, which is the case for all the errors except
+	/// those caused by a bug in the interpreter itself.
+	pub fn is_catchable(&self) -> bool {
+		return !matches!(self, ErrorT::NeverError(..) | ErrorT::InvalidBorrow);
+	}
+}
+
+/// A function that an error unrolled out of, along with where the error passed through it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackFrame {
+	pub function: IdentifierT,
+	pub span: Option<Span>,
+}
+
+impl Display for StackFrame {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.function)?;
+		let Some(span) = self.span else {
+			return Ok(());
+		};
+		match get_source_file(span.file_id) {
+			Some(file) => write!(f, " at {}:{}", file.name, span),
+			None => write!(f, " at {}", span),
+		}
+	}
 }
 
 #[derive(Debug, Clone)]
-pub struct EvilangError {
+pub struct EvilangErrorData {
 	pub typ: ErrorT,
+	pub span: Option<Span>,
 	pub backtrace: Option<Backtrace>,
+	/// The functions the error unrolled out of, innermost first.
+	pub stack: Vec<StackFrame>,
+	/// The innermost location the error passed through in the function that it is currently
+	/// unrolling out of, which becomes the location of its stack frame.
+	pub span_in_frame: Option<Span>,
+}
+
+/// The error type used throughout the interpreter, boxed so that `ResultWithError` stays small.
+#[derive(Debug, Clone)]
+pub struct EvilangError(Box<EvilangErrorData>);
+
+impl Deref for EvilangError {
+	type Target = EvilangErrorData;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		return &self.0;
+	}
+}
+
+impl DerefMut for EvilangError {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		return &mut self.0;
+	}
 }
 
 impl From<io::Error> for EvilangError {
@@ -204,13 +393,17 @@ impl From<io::Error> for EvilangError {
 	}
 }
 
+/// Prints the location and message of the error, the alternate form (`{:#}`) also prints the
+/// backtrace of the interpreter at the point where the error was raised.
 impl Display for EvilangError {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		if let Some(trace) = &self.backtrace {
-			write!(f, "{}: {:?}", self.typ, trace)
-		} else {
-			write!(f, "{}", self.typ)
+		if let Some(span) = &self.span {
+			write!(f, "[{}] ", span)?;
+		}
+		match &self.backtrace {
+			Some(trace) if f.alternate() => write!(f, "{}: {:?}", self.typ, trace),
+			_ => write!(f, "{}", self.typ),
 		}
 	}
 }
@@ -239,7 +432,49 @@ impl From<RuntimeError> for EvilangError {
 impl EvilangError {
 	#[inline(always)]
 	pub fn new(typ: ErrorT) -> EvilangError {
-		return EvilangError { typ, backtrace: Some(Backtrace::new()) };
+		let span = match &typ {
+			ErrorT::InvalidTokenType(token) => Some(token.span),
+			_ => None,
+		};
+		return EvilangError(
+			Box::new(EvilangErrorData {
+				typ,
+				span,
+				backtrace: Some(Backtrace::new()),
+				stack: vec![],
+				span_in_frame: span,
+			})
+		);
+	}
+
+	/// The stable diagnostic code of the error.
+	#[inline(always)]
+	pub fn code(&self) -> &'static str {
+		return self.typ.code();
+	}
+
+	/// Sets the location of the error, unless it already has one: errors are located at the
+	/// innermost construct that they were raised from.
+	#[inline(always)]
+	pub fn with_span(mut self, span: Span) -> EvilangError {
+		if span.is_dummy() {
+			return self;
+		}
+		if self.span.is_none() {
+			self.span = Some(span);
+		}
+		if self.span_in_frame.is_none() {
+			self.span_in_frame = Some(span);
+		}
+		return self;
+	}
+
+	/// Records that the error unrolled out of the function `function`, the locations it passes
+	/// through from now on are in the caller of the function.
+	pub fn with_stack_frame(mut self, function: IdentifierT) -> EvilangError {
+		let span = self.span_in_frame.take();
+		self.stack.push(StackFrame { function, span });
+		return self;
 	}
 }
 
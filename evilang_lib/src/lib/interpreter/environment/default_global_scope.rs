@@ -15,11 +15,12 @@ use crate::interpreter::variables_containers::{
 	VariablesMap,
 };
 use crate::interpreter::variables_containers::map::IVariablesMapDelegator;
-use crate::types::cell_ref::gc_ptr_cell_from;
+use crate::types::cell_ref::{ gc_clone, gc_ptr_cell_from };
 use crate::types::consts::OBJECT;
 
+/// The native functions and classes are constants, so that scripts can't replace them.
 fn make_default_global_variables() -> VariablesMap {
-	return VariablesMap::new_direct(
+	return VariablesMap::new_direct_constants(
 		make_native_functions_list()
 			.into_iter()
 			.map(|(name, f)| { (name, PrimitiveValue::new_native_function(f)) })
@@ -35,6 +36,8 @@ pub fn get_default_global_scope(resolver: BoxIResolver) -> GcPtrMutCellToGlobalS
 
 pub fn setup_environment(env: &mut Environment) -> ResultWithError<()> {
 	let vec_obj = Vector::get_class_cached(env)?;
-	env.global_scope.borrow().assign_locally(Vector::NAME.into(), PrimitiveValue::Object(vec_obj));
+	let global_scope = env.global_scope.borrow();
+	global_scope.declare_constant(Vector::NAME.into(), PrimitiveValue::Object(gc_clone(&vec_obj)))?;
+	global_scope.builtins.declare_constant(Vector::NAME.into(), PrimitiveValue::Object(vec_obj))?;
 	return Ok(());
 }
@@ -0,0 +1,124 @@
+use crate::ast::expression::Expression;
+use crate::ast::pattern::{ Pattern, PatternElement };
+use crate::errors::{ Descriptor, ErrorT, ResultWithError };
+use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::runtime_values::ref_to_value::RefToValue;
+use crate::interpreter::utils::expect_object_fn;
+use crate::interpreter::variables_containers::map::IVariablesMapDelegator;
+use crate::types::cell_ref::gc_clone;
+use crate::types::traits::ConsumeOrCloneOf;
+
+/// What to do with the pieces of a destructured value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BindingMode {
+	/// Declare the identifiers of the pattern in the current scope, as in `let [a, b] = v;`
+	Declare,
+	/// Declare the identifiers of the pattern as constants, as in `const [a, b] = v;`
+	DeclareConstant,
+	/// Assign to the targets of the pattern, as in `[a, b] = v;`
+	Assign,
+}
+
+impl Environment {
+	/// Declares the identifiers in `pattern` with the corresponding pieces of `value`.
+	#[inline(always)]
+	pub fn declare_pattern(&mut self, pattern: &Pattern, value: PrimitiveValue) -> ResultWithError<()> {
+		return self.bind_pattern(pattern, value, BindingMode::Declare);
+	}
+
+	/// Declares the identifiers in `pattern` as constants with the corresponding pieces of `value`.
+	#[inline(always)]
+	pub fn declare_constant_pattern(
+		&mut self,
+		pattern: &Pattern,
+		value: PrimitiveValue
+	) -> ResultWithError<()> {
+		return self.bind_pattern(pattern, value, BindingMode::DeclareConstant);
+	}
+
+	/// Assigns the corresponding pieces of `value` to the targets in `pattern`.
+	#[inline(always)]
+	pub fn assign_pattern(&mut self, pattern: &Pattern, value: PrimitiveValue) -> ResultWithError<()> {
+		return self.bind_pattern(pattern, value, BindingMode::Assign);
+	}
+
+	pub fn eval_destructuring_assignment(
+		&mut self,
+		pattern: &Pattern,
+		right: &Expression
+	) -> ResultWithError<RefToValue> {
+		let value = self.eval(right)?;
+		if value.is_hoisted() {
+			return Err(ErrorT::CantSetToHoistedValue.into());
+		}
+		let value = value.consume_or_clone()?;
+		self.assign_pattern(pattern, value.try_clone_err()?)?;
+		return Ok(value.into());
+	}
+
+	fn bind_pattern(
+		&mut self,
+		pattern: &Pattern,
+		value: PrimitiveValue,
+		mode: BindingMode
+	) -> ResultWithError<()> {
+		match pattern {
+			Pattern::Identifier(identifier) if mode == BindingMode::Declare => {
+				self.declare(identifier.into(), value)?;
+			}
+			Pattern::Identifier(identifier) if mode == BindingMode::DeclareConstant => {
+				self.declare_constant(identifier.into(), value)?;
+			}
+			Pattern::Identifier(identifier) => {
+				self.get_identifier(identifier.into())?.set(self, value)?;
+			}
+			Pattern::Target(_) if mode != BindingMode::Assign => {
+				return Err(ErrorT::ExpectedLhsExpression.into());
+			}
+			// Literals and alternatives can only be matched against, not bound to
+			Pattern::Literal(_) | Pattern::Alternatives(_) => {
+				return Err(ErrorT::ExpectedLhsExpression.into());
+			}
+			Pattern::Wildcard => {}
+			Pattern::Target(target) => {
+				self.eval_assignment_target(target)?.set(self, value)?;
+			}
+			Pattern::Vector { elements, rest } => {
+				let mut items = Vector::items_of(&value, pattern.to_string())?.into_iter();
+				for element in elements.iter() {
+					let item = items.next().unwrap_or(PrimitiveValue::Null);
+					self.bind_pattern_element(element, item, mode)?;
+				}
+				if let Some(rest) = rest {
+					let rest_value = Vector::from(self, items.collect())?;
+					self.bind_pattern(rest, rest_value, mode)?;
+				}
+			}
+			Pattern::Object(properties) => {
+				let object = gc_clone(expect_object_fn(&value, || Descriptor::Name(pattern.to_string()))?);
+				for property in properties.iter() {
+					let name = self.name_from_member_indexer(&property.key)?.into_owned();
+					let property_value = RefToValue::get_object_property(self, gc_clone(&object), name)?
+						.consume_or_clone()?;
+					self.bind_pattern_element(&property.element, property_value, mode)?;
+				}
+			}
+		}
+		return Ok(());
+	}
+
+	fn bind_pattern_element(
+		&mut self,
+		element: &PatternElement,
+		value: PrimitiveValue,
+		mode: BindingMode
+	) -> ResultWithError<()> {
+		let value = match &element.default {
+			Some(default) if value.is_null() => self.eval(default)?.consume_or_clone()?,
+			_ => value,
+		};
+		return self.bind_pattern(&element.pattern, value, mode);
+	}
+}
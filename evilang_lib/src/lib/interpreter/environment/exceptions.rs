@@ -0,0 +1,73 @@
+use crate::ast::statement::BoxStatement;
+use crate::ast::structs::CatchClause;
+use crate::errors::{ Descriptor, ErrorT, EvilangError, ResultWithError, RuntimeError };
+use crate::handle_unrolling;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::object::ObjectSuperclass;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::environment::statement_result::{ StatementExecution, UnrollingReason };
+use crate::interpreter::runtime_values::i_native_struct::INativeClass_GetClassCached;
+use crate::interpreter::runtime_values::objects::runtime_object::RuntimeObject;
+use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::variables_containers::map::IVariablesMapDelegator;
+use crate::types::consts::{ ERROR_KIND, ERROR_MESSAGE, ERROR_STACK };
+
+impl Environment {
+	pub(crate) fn eval_try_statement(
+		&mut self,
+		body: &BoxStatement,
+		catch_clause: &Option<CatchClause>,
+		finally_block: &Option<BoxStatement>
+	) -> ResultWithError<StatementExecution> {
+		let res = self.eval_statement__creates_scope(body);
+		let res = match (catch_clause, res) {
+			(
+				Some(clause),
+				Ok(StatementExecution::Unrolling(UnrollingReason::Throwing(value, _)))
+			) => self.eval_catch_clause(clause, value),
+			(Some(clause), Err(err)) if err.typ.is_catchable() => {
+				let value = self.exception_value(err)?;
+				self.eval_catch_clause(clause, value)
+			}
+			(_, res) => res,
+		};
+		let Some(finally_block) = finally_block else {
+			return res;
+		};
+		// Anything unrolling out of the finally block takes over from the try and catch blocks
+		handle_unrolling!(self.eval_statement__creates_scope(finally_block)?);
+		return res;
+	}
+
+	fn eval_catch_clause(
+		&mut self,
+		clause: &CatchClause,
+		value: PrimitiveValue
+	) -> ResultWithError<StatementExecution> {
+		let mut env = Environment::new_with_parent(self)?;
+		if let Some(parameter) = &clause.parameter {
+			env.declare_pattern(parameter, value)?;
+		}
+		return env.eval_statement__creates_scope(&clause.body);
+	}
+
+	/// The value that a `catch` clause receives for an error: the value itself for values thrown
+	/// by a `throw` statement, otherwise an object describing the error.
+	pub fn exception_value(&mut self, error: EvilangError) -> ResultWithError<PrimitiveValue> {
+		if let ErrorT::UnexpectedRuntimeError(
+			RuntimeError::UncaughtException(Descriptor::Value(value))
+		) = &error.typ {
+			return value.try_clone_err();
+		}
+		let object = RuntimeObject::allocate_instance(ObjectSuperclass::get_class_cached(self)?, None);
+		let stack = error.stack
+			.iter()
+			.map(|frame| PrimitiveValue::String(frame.to_string()))
+			.collect();
+		let stack = Vector::from(self, stack)?;
+		object.assign_locally(ERROR_MESSAGE.into(), PrimitiveValue::String(error.typ.to_string()))?;
+		object.assign_locally(ERROR_KIND.into(), PrimitiveValue::String(error.code().into()))?;
+		object.assign_locally(ERROR_STACK.into(), stack)?;
+		return Ok(object.into());
+	}
+}
@@ -2,11 +2,13 @@ use std::ops::{ Add, Div, Mul, Rem, Sub };
 use std::ops::Deref;
 
 use itertools::{ Either::Left, Either::Right };
+use num_traits::{ One, Zero };
 
 use crate::ast::expression::{
 	BoxExpression,
 	DottedIdentifiers,
 	Expression,
+	ExpressionKind,
 	IdentifierT,
 	MemberIndexer,
 };
@@ -14,7 +16,11 @@ use crate::ast::operator::Operator;
 use crate::ast::structs::CallExpression;
 use crate::errors::{ Descriptor, ErrorT, EvilangError, ResultWithError, RuntimeError };
 use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::object::ObjectSuperclass;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::environment::native_items::stringify;
 use crate::interpreter::runtime_values::{
+	GcPtrVariable,
 	GcPtrVariableExt,
 	PrimitiveValue,
 	ref_to_value::RefToValue,
@@ -22,16 +28,18 @@ use crate::interpreter::runtime_values::{
 use crate::interpreter::runtime_values::functions::Function;
 use crate::interpreter::runtime_values::functions::ifunction::IFunction;
 use crate::interpreter::runtime_values::functions::types::FunctionParameters;
+use crate::interpreter::runtime_values::i_native_struct::INativeClass_GetClassCached;
 use crate::interpreter::runtime_values::objects::runtime_object::{ GcPtrToObject, RuntimeObject };
 use crate::interpreter::utils::{ expect_object, expect_object_or_set_object_if_null };
 use crate::interpreter::variables_containers::map::{
 	IVariablesMapConstMembers,
 	IVariablesMapDelegator,
 };
-use crate::tokenizer::TokenType;
+use crate::tokenizer::{ Token, TokenType };
 use crate::types::cell_ref::gc_clone;
-use crate::types::consts::CONSTRUCTOR;
-use crate::types::string::CowStringT;
+use crate::types::consts::{ CONSTRUCTOR, IMPLICIT_THIS, SUPER };
+use crate::types::number::NumberT;
+use crate::types::string::{ CowStringT, StringT };
 use crate::types::traits::ConsumeOrCloneOf;
 
 macro_rules! by_ref {
@@ -66,38 +74,91 @@ macro_rules! auto_implement_binary_operators {
 
 impl Environment {
 	pub fn eval(&mut self, expression: &Expression) -> ResultWithError<RefToValue> {
-		return Ok(match expression {
-			Expression::NullLiteral => PrimitiveValue::Null.into(),
-			Expression::BooleanLiteral(a) => PrimitiveValue::Boolean(*a).into(),
-			Expression::NumericLiteral(a) => PrimitiveValue::Number(*a).into(),
-			Expression::StringLiteral(a) => PrimitiveValue::String(a.clone()).into(),
-			Expression::UnaryExpression { operator, argument } =>
+		return self.eval_expression_kind(expression).map_err(|e| e.with_span(expression.span));
+	}
+
+	fn eval_expression_kind(&mut self, expression: &Expression) -> ResultWithError<RefToValue> {
+		return Ok(match &expression.kind {
+			ExpressionKind::NullLiteral => PrimitiveValue::Null.into(),
+			ExpressionKind::BooleanLiteral(a) => PrimitiveValue::Boolean(*a).into(),
+			ExpressionKind::NumericLiteral(a) => PrimitiveValue::Number(*a).into(),
+			ExpressionKind::StringLiteral(a) => PrimitiveValue::String(a.clone()).into(),
+			ExpressionKind::TemplateLiteral { strings, expressions } => {
+				let mut res = StringT::new();
+				for (i, string) in strings.iter().enumerate() {
+					res.push_str(string);
+					if let Some(expr) = expressions.get(i) {
+						let value = self.eval(expr)?.consume_or_clone()?;
+						res.push_str(&stringify(self, &value).map_err(|e| e.with_span(expr.span))?);
+					}
+				}
+				PrimitiveValue::String(res).into()
+			}
+			ExpressionKind::ArrayLiteral(items) => {
+				let values = items
+					.iter()
+					.map(|v| self.eval(v)?.consume_or_clone())
+					.collect::<ResultWithError<Vec<_>>>()?;
+				Vector::from(self, values)?.into()
+			}
+			ExpressionKind::ObjectLiteral(properties) => {
+				let object = RuntimeObject::allocate_instance(ObjectSuperclass::get_class_cached(self)?, None);
+				for (key, value) in properties.iter() {
+					let name = self.name_from_member_indexer(key)?;
+					let value = self.eval(value)?.consume_or_clone()?;
+					object.assign_locally(name, value)?;
+				}
+				PrimitiveValue::Object(object).into()
+			}
+			ExpressionKind::UpdateExpression { operator, prefix, argument } =>
+				self.eval_update_expression(operator, *prefix, argument)?,
+			ExpressionKind::UnaryExpression { operator, argument } =>
 				self.execute_unary_operator_expression(operator, argument)?,
-			Expression::BinaryExpression { operator, left, right } =>
+			ExpressionKind::BinaryExpression { operator, left, right } =>
 				self.eval_binary_operator_expression(operator, left, right)?,
-			Expression::AssignmentExpression { operator, left, right } =>
-				self.eval_binary_operator_expression(operator, left, right)?,
-			Expression::Identifier(name) => self.get_identifier(name.into())?,
-			Expression::FunctionCall(call_expr) => self.eval_function_call(call_expr)?,
-			Expression::FunctionExpression(fdecl) => {
-				let function = Function::new_closure(self, fdecl.clone());
-				self.assign_locally((&fdecl.name).into(), gc_clone(&function).into());
+			ExpressionKind::AssignmentExpression { operator, left, right } => match &left.kind {
+				ExpressionKind::DestructuringPattern(pattern) =>
+					self.eval_destructuring_assignment(pattern, right)?,
+				_ => self.eval_binary_operator_expression(operator, left, right)?,
+			},
+			ExpressionKind::Identifier(name) => self.get_identifier(name.into())?,
+			ExpressionKind::FunctionCall(call_expr) => self.eval_function_call(call_expr)?,
+			ExpressionKind::SpreadExpression(..) | ExpressionKind::DestructuringPattern(..) => {
+				return Err(ErrorT::UnimplementedExpressionTypeForInterpreter(expression.clone()).into());
+			}
+			ExpressionKind::FunctionExpression(fdecl) => {
+				let function = Function::new_closure(self, fdecl.clone())?;
+				self.assign_locally((&fdecl.name).into(), gc_clone(&function).into())?;
 				RefToValue::Value(function.into())
 			}
-			Expression::ClassDeclarationExpression(cdecl) => {
+			ExpressionKind::LambdaExpression(fdecl) =>
+				RefToValue::Value(Function::new_closure(self, fdecl.clone())?.into()),
+			ExpressionKind::ClassDeclarationExpression(cdecl) => {
 				let class = RuntimeObject::new_class_decl(self, cdecl)?;
-				self.assign_locally((&cdecl.name).into(), gc_clone(&class).into());
+				self.assign_locally((&cdecl.name).into(), gc_clone(&class).into())?;
 				RefToValue::Value(class.into())
 			}
-			Expression::ParenthesizedExpression(expr) => self.eval(expr)?,
-			Expression::MemberAccess { object, member } => {
+			ExpressionKind::ParenthesizedExpression(expr) => self.eval(expr)?,
+			ExpressionKind::MemberAccess { object, member } => {
 				let name = self.name_from_member_indexer(member)?;
 				let object_val = self.eval_expr_expect_object(object)?;
-				RefToValue::new_object_property_ref(object_val, name.into_owned())
+				RefToValue::get_object_property(self, object_val, name.into_owned())?
+			}
+			ExpressionKind::ConditionalExpression { condition, consequent, alternate } => {
+				if self.eval(condition)?.is_truthy() {
+					self.eval(consequent)?
+				} else {
+					self.eval(alternate)?
+				}
 			}
-			Expression::DottedIdentifiers(idens) =>
-				self.get_dotted_identifiers(expression, idens.identifiers.iter())?,
-			Expression::NewObjectExpression(call_expr) => self.eval_new_object_expression(call_expr)?,
+			ExpressionKind::OptionalChain(chain) =>
+				self.eval_optional_chain_link(chain)?.unwrap_or_else(|| PrimitiveValue::Null.into()),
+			ExpressionKind::OptionalMemberAccess { .. } =>
+				self.eval_optional_chain_link(expression)?.unwrap_or_else(|| PrimitiveValue::Null.into()),
+			ExpressionKind::DottedIdentifiers(idens) =>
+				self.get_dotted_identifiers(expression, &idens.identifiers, &idens.delimiters)?,
+			ExpressionKind::NewObjectExpression(call_expr) => self.eval_new_object_expression(call_expr)?,
+			ExpressionKind::MatchExpression { value, arms } => self.eval_match_expression(value, arms)?,
 			/*
 			expr => {
 				return Err(ErrorT::UnimplementedExpressionTypeForInterpreter(expr.clone()).into());
@@ -106,7 +167,7 @@ impl Environment {
 		});
 	}
 
-	fn name_from_member_indexer<'a>(
+	pub(crate) fn name_from_member_indexer<'a>(
 		&mut self,
 		member: &'a MemberIndexer
 	) -> ResultWithError<CowStringT<'a>> {
@@ -138,11 +199,11 @@ impl Environment {
 		let Some(obj_expr) = iter.next() else {
 			return Err(
 				RuntimeError::ExpectedNamespaceObject(
-					Descriptor::Expression(Expression::DottedIdentifiers(idens.clone()))
+					Descriptor::Expression(ExpressionKind::DottedIdentifiers(idens.clone()).into())
 				).into()
 			);
 		};
-		let f = || Descriptor::Expression(Expression::DottedIdentifiers(idens.clone()));
+		let f = || Descriptor::Expression(ExpressionKind::DottedIdentifiers(idens.clone()).into());
 		let mut res_ref = self.get_identifier(obj_expr.into())?;
 		let mut res_ref_name = obj_expr;
 		for next_name in iter {
@@ -154,30 +215,53 @@ impl Environment {
 		Ok(ret_obj)
 	}
 
-	pub fn get_dotted_identifiers<'a, TIter: Iterator<Item = &'a IdentifierT>>(
+	/// Reads `identifiers`, each separated from the next by the delimiter at the same index in
+	/// `delimiters`.
+	pub fn get_dotted_identifiers(
 		&mut self,
 		expression: &Expression,
-		mut iter: TIter
+		identifiers: &[IdentifierT],
+		delimiters: &[Token]
 	) -> ResultWithError<RefToValue> {
-		let Some(obj_expr) = iter.next() else {
+		let Some((obj_expr, rest)) = identifiers.split_first() else {
 			return Ok(PrimitiveValue::Null.into());
 		};
 		let mut res = self.get_identifier(obj_expr.into())?;
-		for next_name in iter {
+		for (next_name, delimiter) in rest.iter().zip(delimiters) {
 			let obj = expect_object(res, Some(expression))?;
-			res = RefToValue::new_object_property_ref(obj, next_name.clone());
+			res = if delimiter.typ == TokenType::DoubleColon {
+				RefToValue::get_object_member(self, obj, next_name.clone())?
+			} else {
+				RefToValue::get_object_property(self, obj, next_name.clone())?
+			};
 		}
 		Ok(res)
 	}
 
 	pub fn get_identifier(&mut self, name: CowStringT) -> ResultWithError<RefToValue> {
+		let var = self.get_variable(name.deref().into())?;
+		if var.is_hoisted() {
+			return Err(ErrorT::CantAccessHoistedVariable(name.into()).into());
+		}
+		if self.is_constant(name.deref().into()) {
+			return Ok(RefToValue::Constant { name: name.into(), value: var });
+		}
+		Ok(RefToValue::Variable(var))
+	}
+
+	/// Gets the variable named `name`, even if it has only been hoisted so far. Variables that
+	/// don't exist are declared as null, unless the lookup is inside a closure with a capture list.
+	pub fn get_variable(&mut self, name: CowStringT) -> ResultWithError<GcPtrVariable> {
 		let name_ref = name.deref();
+		if let Some(var) = self.get_actual(name_ref.into()) {
+			return Ok(var.into_owned());
+		}
+		if self.scope.is_inside_capture_list() {
+			return Err(ErrorT::UncapturedVariable(name.into()).into());
+		}
+		self.assign_locally(name_ref.into(), PrimitiveValue::Null)?;
 		let var = self
 			.get_actual(name_ref.into())
-			.or_else(|| {
-				self.assign_locally(name_ref.into(), PrimitiveValue::Null);
-				self.get_actual(name_ref.into())
-			})
 			.ok_or_else(||
 				EvilangError::new(
 					ErrorT::NeverError(
@@ -185,10 +269,33 @@ impl Environment {
 					)
 				)
 			)?;
-		if var.is_hoisted() {
-			return Err(ErrorT::CantAccessHoistedVariable(name.into()).into());
-		}
-		Ok(RefToValue::Variable(var.into_owned()))
+		Ok(var.into_owned())
+	}
+
+	/// Evaluates what is assigned to by `=`, without calling the getter of the property that is
+	/// assigned to, since its value is about to be replaced.
+	pub(crate) fn eval_assignment_target(&mut self, expression: &Expression) -> ResultWithError<RefToValue> {
+		return self
+			.eval_assignment_target_kind(expression)
+			.map_err(|e| e.with_span(expression.span));
+	}
+
+	fn eval_assignment_target_kind(&mut self, expression: &Expression) -> ResultWithError<RefToValue> {
+		return match &expression.kind {
+			ExpressionKind::MemberAccess { object, member } => {
+				let name = self.name_from_member_indexer(member)?;
+				let object_val = self.eval_expr_expect_object(object)?;
+				Ok(RefToValue::new_object_property_ref(object_val, name.into_owned()))
+			}
+			ExpressionKind::DottedIdentifiers(idens) => match idens.identifiers.split_last() {
+				Some((last, rest)) if !rest.is_empty() => {
+					let object = self.get_dotted_identifiers(expression, rest, &idens.delimiters)?;
+					Ok(RefToValue::new_object_property_ref(expect_object(object, Some(expression))?, last.clone()))
+				}
+				_ => self.eval(expression),
+			},
+			_ => self.eval(expression),
+		};
 	}
 
 	fn eval_expr_expect_object(&mut self, expr: &Expression) -> ResultWithError<GcPtrToObject> {
@@ -201,10 +308,17 @@ impl Environment {
 		argument: &Expression
 	) -> ResultWithError<RefToValue> {
 		let arg_eval = self.eval(argument)?;
+		if let (Some(method_name), Some(object)) = (operator.unary_method_name(), object_operand(&arg_eval)) {
+			if RuntimeObject::has_method(&object, method_name.into()) {
+				return Ok(RuntimeObject::call_method_on_object(object, self, method_name.into(), vec![])?.into());
+			}
+		}
 		let prim_borrow = arg_eval.borrow();
 		let prim_ref = prim_borrow.deref();
 		return Ok(match (operator, prim_ref) {
 			(Operator::LogicalNot, PrimitiveValue::Boolean(v)) => PrimitiveValue::Boolean(!*v).into(),
+			(Operator::BitwiseNot, PrimitiveValue::Number(v)) =>
+				PrimitiveValue::Number(NumberT::Integer(!expect_integer(v, argument)?)).into(),
 			(Operator::Plus, PrimitiveValue::Number(v)) => PrimitiveValue::Number(*v).into(),
 			(Operator::Minus, PrimitiveValue::Number(v)) => PrimitiveValue::Number(-*v).into(),
 			(op, _) => {
@@ -213,14 +327,72 @@ impl Environment {
 		});
 	}
 
+	fn eval_update_expression(
+		&mut self,
+		operator: &Operator,
+		prefix: bool,
+		argument: &Expression
+	) -> ResultWithError<RefToValue> {
+		let mut target = self.eval(argument)?;
+		let original = match target.borrow().deref() {
+			PrimitiveValue::Number(v) => *v,
+			v => {
+				return Err(
+					RuntimeError::ExpectedNumber(
+						Descriptor::Expression(argument.clone()).with_value(v.into())
+					).into()
+				);
+			}
+		};
+		let updated = if *operator == Operator::Increment {
+			original + NumberT::one()
+		} else {
+			original - NumberT::one()
+		};
+		target.set(self, PrimitiveValue::Number(updated))?;
+		return Ok(PrimitiveValue::Number(if prefix { updated } else { original }).into());
+	}
+
 	pub fn eval_binary_operator_expression(
 		&mut self,
 		operator: &Operator,
 		left: &BoxExpression,
 		right: &BoxExpression
 	) -> ResultWithError<RefToValue> {
-		let left_eval = self.eval(left.deref())?;
+		let left_eval = if *operator == Operator::Assignment {
+			self.eval_assignment_target(left)?
+		} else {
+			self.eval(left.deref())?
+		};
 		match operator {
+			Operator::NullishCoalescing => {
+				return if !left_eval.is_null() {
+					Ok(left_eval)
+				} else {
+					let right_eval = self.eval(right.deref())?;
+					Ok(right_eval)
+				};
+			}
+			| Operator::NullishCoalescingAssignment
+			| Operator::LogicalOrAssignment
+			| Operator::LogicalAndAssignment => {
+				let should_assign = match operator {
+					Operator::NullishCoalescingAssignment => left_eval.is_null(),
+					Operator::LogicalOrAssignment => !left_eval.is_truthy(),
+					_ => left_eval.is_truthy(),
+				};
+				if !should_assign {
+					return Ok(left_eval);
+				}
+				let right_eval = self.eval(right.deref())?;
+				return self.execute_binary_expression(
+					&Operator::Assignment,
+					left_eval,
+					right_eval,
+					left,
+					right
+				);
+			}
 			Operator::LogicalOr => {
 				return if left_eval.is_truthy() {
 					Ok(left_eval)
@@ -257,23 +429,31 @@ impl Environment {
 				if right.is_hoisted() {
 					return Err(ErrorT::CantSetToHoistedValue.into());
 				}
-				left.set(right.consume_or_clone()?)?;
+				left.set(self, right.consume_or_clone()?)?;
 			} else {
-				let val_to_assign = self.execute_operation_on_primitive(
-					&operator.strip_assignment()?,
-					left.borrow().deref(),
-					right.borrow().deref(),
-					left_expr,
-					right_expr
-				)?;
+				let operator = operator.strip_assignment()?;
+				let val_to_assign = match self.call_binary_operator_method(&operator, &left, &right)? {
+					Some(value) => value,
+					None =>
+						self.execute_operation_on_primitive(
+							&operator,
+							left.borrow().deref(),
+							right.borrow().deref(),
+							left_expr,
+							right_expr
+						)?,
+				};
 				if val_to_assign.is_hoisted() {
 					return Err(ErrorT::CantSetToHoistedValue.into());
 				}
-				left.set(val_to_assign)?;
+				left.set(self, val_to_assign)?;
 			}
 			return Ok(left);
 		}
 
+		if let Some(value) = self.call_binary_operator_method(operator, &left, &right)? {
+			return Ok(value.into());
+		}
 		let lder = left.borrow();
 		let rder = right.borrow();
 		return Ok(
@@ -289,6 +469,29 @@ impl Environment {
 		);
 	}
 
+	/// Calls the method overloading `operator` when the left operand is an object that has one, see
+	/// [`Operator::binary_method_name`]. Without a `__ne__` method, `a != b` is the negation of
+	/// `a.__eq__(b)`, and without either, objects are compared by identity.
+	fn call_binary_operator_method(
+		&mut self,
+		operator: &Operator,
+		left: &RefToValue,
+		right: &RefToValue
+	) -> ResultWithError<Option<PrimitiveValue>> {
+		let (Some(method_name), Some(object)) = (operator.binary_method_name(), object_operand(left)) else {
+			return Ok(None);
+		};
+		let negate_equals = *operator == Operator::NotEquals &&
+			!RuntimeObject::has_method(&object, method_name.into());
+		let method_name = if negate_equals { Operator::Equals.binary_method_name().unwrap() } else { method_name };
+		if !RuntimeObject::has_method(&object, method_name.into()) {
+			return Ok(None);
+		}
+		let argument = right.borrow().try_clone_err()?;
+		let result = RuntimeObject::call_method_on_object(object, self, method_name.into(), vec![argument])?;
+		return Ok(Some(if negate_equals { PrimitiveValue::Boolean(!result.is_truthy()) } else { result }));
+	}
+
 	pub fn execute_operation_on_primitive(
 		&mut self,
 		operator: &Operator,
@@ -314,9 +517,62 @@ impl Environment {
 		if let Some(int_r) = int_result {
 			return Ok(int_r);
 		}
+		let invalid_arithmetic = |reason: &str| -> EvilangError {
+			let expression = Expression::binary_expression(
+				*operator,
+				left_expr.clone().into(),
+				right_expr.clone().into()
+			);
+			return RuntimeError::InvalidArithmetic(Descriptor::Expression(expression), reason.into()).into();
+		};
 		return match (operator, left, right) {
 			(Operator::Plus, PrimitiveValue::String(a), PrimitiveValue::String(b)) =>
 				Ok(PrimitiveValue::String(a.clone() + b)),
+			(Operator::Plus, PrimitiveValue::String(a), b @ PrimitiveValue::Object(_)) =>
+				Ok(PrimitiveValue::String(a.clone() + &stringify(self, b)?)),
+			(Operator::Plus, a @ PrimitiveValue::Object(_), PrimitiveValue::String(b)) =>
+				Ok(PrimitiveValue::String(stringify(self, a)? + b)),
+			(Operator::Exponentiation, PrimitiveValue::Number(a), PrimitiveValue::Number(b)) =>
+				a
+					.checked_pow(b)
+					.map(PrimitiveValue::Number)
+					.ok_or_else(|| invalid_arithmetic("Integer overflow")),
+			(Operator::FloorDivision, PrimitiveValue::Number(a), PrimitiveValue::Number(b)) =>
+				a
+					.checked_floor_div(b)
+					.map(PrimitiveValue::Number)
+					.ok_or_else(|| {
+						invalid_arithmetic(if b.is_zero() { "Division by zero" } else { "Integer overflow" })
+					}),
+			(
+				Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor,
+				PrimitiveValue::Number(a),
+				PrimitiveValue::Number(b),
+			) => {
+				let (a, b) = (expect_integer(a, left_expr)?, expect_integer(b, right_expr)?);
+				Ok(
+					PrimitiveValue::Number(
+						NumberT::Integer(match operator {
+							Operator::BitwiseAnd => a & b,
+							Operator::BitwiseOr => a | b,
+							_ => a ^ b,
+						})
+					)
+				)
+			}
+			(
+				Operator::LeftShift | Operator::RightShift,
+				PrimitiveValue::Number(a),
+				PrimitiveValue::Number(b),
+			) => {
+				let (a, b) = (expect_integer(a, left_expr)?, expect_integer(b, right_expr)?);
+				let shifted = u32::try_from(b)
+					.ok()
+					.and_then(|b| if *operator == Operator::LeftShift { a.checked_shl(b) } else { a.checked_shr(b) });
+				shifted
+					.map(|v| PrimitiveValue::Number(NumberT::Integer(v)))
+					.ok_or_else(|| invalid_arithmetic("Shift amount out of range"))
+			}
 			(Operator::Equals, a, b) => Ok(PrimitiveValue::Boolean(a == b)),
 			(Operator::NotEquals, a, b) => Ok(PrimitiveValue::Boolean(a != b)),
 			(op, _l, _r) => {
@@ -356,9 +612,16 @@ impl Environment {
 	//noinspection RsLift
 	fn eval_function_call(&mut self, call_expr: &CallExpression) -> ResultWithError<RefToValue> {
 		// dbg!(&call_expr.callee);
-		match call_expr.callee.deref() {
-			Expression::MemberAccess { object, member } => {
+		match &call_expr.callee.kind {
+			ExpressionKind::MemberAccess { object, member } => {
 				let method_name = self.name_from_member_indexer(member)?;
+				if matches!(&object.kind, ExpressionKind::Identifier(name) if name == SUPER) {
+					let this = self.implicit_this_for_super_call()?;
+					let class = self.eval_expr_expect_object(object)?;
+					return Ok(
+						RuntimeObject::call_method_of_class_with_args(class, this, self, method_name, call_expr)?.into()
+					);
+				}
 				return Ok(
 					RuntimeObject::call_method_on_object_with_args(
 						self.eval_expr_expect_object(object)?,
@@ -368,7 +631,7 @@ impl Environment {
 					)?.into()
 				);
 			}
-			Expression::DottedIdentifiers(idens) if
+			ExpressionKind::DottedIdentifiers(idens) if
 				idens.identifiers.len() > 1 &&
 				idens.delimiters
 					.last()
@@ -376,8 +639,21 @@ impl Environment {
 					.unwrap_or(false)
 			=> {
 				let left_iter = &idens.identifiers[0..idens.identifiers.len() - 1];
-				let object = self.get_dotted_identifiers(call_expr.callee.deref(), left_iter.iter())?;
+				let object = self.get_dotted_identifiers(call_expr.callee.deref(), left_iter, &idens.delimiters)?;
 				let method_name = idens.identifiers.last().unwrap();
+				if left_iter == [SUPER] {
+					let this = self.implicit_this_for_super_call()?;
+					let class = expect_object(object, Some(call_expr.callee.deref()))?;
+					return Ok(
+						RuntimeObject::call_method_of_class_with_args(
+							class,
+							this,
+							self,
+							method_name.into(),
+							call_expr
+						)?.into()
+					);
+				}
 				return Ok(
 					RuntimeObject::call_method_on_object_with_args(
 						expect_object(object, Some(call_expr.callee.deref()))?,
@@ -387,21 +663,151 @@ impl Environment {
 					)?.into()
 				);
 			}
-			expr => {
-				let function = self.eval(expr)?.consume_or_clone()?;
-				let PrimitiveValue::Function(ref gc_fn) = function else {
-					return Err(
-						RuntimeError::ExpectedFunction(
-							Descriptor::new_both(function.into(), expr.into())
-						).into()
-					);
+			_ => {
+				let function = self.eval(call_expr.callee.deref())?.consume_or_clone()?;
+				return self.call_function_value(function, call_expr);
+			}
+		};
+	}
+
+	/// `super.method(args)` never passes the superclass as `this`. Inside methods that have `this`
+	/// bound implicitly it passes the current `this` along, while methods taking `this` explicitly
+	/// pass it among the arguments themselves.
+	fn implicit_this_for_super_call(&mut self) -> ResultWithError<Option<PrimitiveValue>> {
+		let Some(this) = self.get_actual(IMPLICIT_THIS.into()).map(|v| v.into_owned()) else {
+			return Ok(None);
+		};
+		let this = this.borrow().try_clone_err()?;
+		return Ok(if this.is_null() { None } else { Some(this) });
+	}
+
+	fn call_function_value(
+		&mut self,
+		function: PrimitiveValue,
+		call_expr: &CallExpression
+	) -> ResultWithError<RefToValue> {
+		let PrimitiveValue::Function(ref gc_fn) = function else {
+			return Err(
+				RuntimeError::ExpectedFunction(
+					Descriptor::new_both(function.into(), call_expr.callee.deref().into())
+				).into()
+			);
+		};
+		let args = self.eval_arguments(&call_expr.arguments)?;
+		return Ok(gc_fn.execute(self, args)?.into());
+	}
+
+	/// Evaluates the arguments of a call, in order, passing the items of spread vectors as separate
+	/// arguments.
+	pub fn eval_arguments(&mut self, arguments: &[Expression]) -> ResultWithError<FunctionParameters> {
+		let mut res = FunctionParameters::with_capacity(arguments.len());
+		for arg in arguments.iter() {
+			if let ExpressionKind::SpreadExpression(expr) = &arg.kind {
+				let value = self.eval(expr)?.consume_or_clone()?;
+				res.extend(Vector::items_of(&value, expr.to_string()).map_err(|e| e.with_span(arg.span))?);
+			} else {
+				res.push(self.eval(arg)?.consume_or_clone()?);
+			}
+		}
+		return Ok(res);
+	}
+
+	/// Evaluates a link of an optional chain, returning [`None`] when the chain short-circuits
+	/// because an object accessed with `?.` is null.
+	fn eval_optional_chain_link(
+		&mut self,
+		expression: &Expression
+	) -> ResultWithError<Option<RefToValue>> {
+		return self
+			.eval_optional_chain_link_kind(expression)
+			.map_err(|e| e.with_span(expression.span));
+	}
+
+	fn eval_optional_chain_link_kind(
+		&mut self,
+		expression: &Expression
+	) -> ResultWithError<Option<RefToValue>> {
+		return match &expression.kind {
+			| ExpressionKind::MemberAccess { object, member }
+			| ExpressionKind::OptionalMemberAccess { object, member } => {
+				let Some(object_val) = self.eval_optional_chain_object(expression, object)? else {
+					return Ok(None);
 				};
-				let args = call_expr.arguments
-					.iter()
-					.map(|v| self.eval(v).and_then(RefToValue::consume_or_clone))
-					.collect::<ResultWithError<FunctionParameters>>()?;
-				return Ok(gc_fn.execute(self, args)?.into());
+				let name = self.name_from_member_indexer(member)?;
+				Ok(Some(RefToValue::get_object_property(self, object_val, name.into_owned())?))
+			}
+			ExpressionKind::FunctionCall(call_expr) => {
+				match &call_expr.callee.kind {
+					| ExpressionKind::MemberAccess { object, member }
+					| ExpressionKind::OptionalMemberAccess { object, member } => {
+						let callee = call_expr.callee.deref();
+						let Some(object_val) = self.eval_optional_chain_object(callee, object)? else {
+							return Ok(None);
+						};
+						let method_name = self.name_from_member_indexer(member)?;
+						let res = RuntimeObject::call_method_on_object_with_args(
+							object_val,
+							self,
+							method_name,
+							call_expr
+						)?;
+						Ok(Some(res.into()))
+					}
+					_ => {
+						let Some(function) = self.eval_optional_chain_link(&call_expr.callee)? else {
+							return Ok(None);
+						};
+						let function = function.consume_or_clone()?;
+						Ok(Some(self.call_function_value(function, call_expr)?))
+					}
+				}
 			}
+			_ => Ok(Some(self.eval(expression)?)),
+		};
+	}
+
+	/// Evaluates the object of the member access `access` in an optional chain, returning [`None`]
+	/// when the chain short-circuits.
+	fn eval_optional_chain_object(
+		&mut self,
+		access: &Expression,
+		object: &Expression
+	) -> ResultWithError<Option<GcPtrToObject>> {
+		let Some(object_val) = self.eval_optional_chain_link(object)? else {
+			return Ok(None);
 		};
+		if
+			matches!(access.kind, ExpressionKind::OptionalMemberAccess { .. }) &&
+			object_val.is_null()
+		{
+			return Ok(None);
+		}
+		return Ok(Some(expect_object(object_val, Some(object))?));
 	}
 }
+
+/// The object an operand evaluated to, the borrow of the operand being released before any of its
+/// methods get to run.
+fn object_operand(value: &RefToValue) -> Option<GcPtrToObject> {
+	return match value.borrow().deref() {
+		PrimitiveValue::Object(object) => Some(gc_clone(object)),
+		_ => None,
+	};
+}
+
+/// Bitwise operators only work on integers, floats are not truncated implicitly.
+fn expect_integer(number: &NumberT, expression: &Expression) -> ResultWithError<i128> {
+	return match number {
+		NumberT::Integer(v) => Ok(*v),
+		NumberT::Float(_) => {
+			Err(
+				RuntimeError::ExpectedInteger(
+					Descriptor::ExpressionAndValue {
+						value: PrimitiveValue::Number(*number),
+						expression: expression.clone(),
+					}
+				).into()
+			)
+		}
+	};
+}
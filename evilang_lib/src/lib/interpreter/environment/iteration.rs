@@ -0,0 +1,75 @@
+use crate::ast::expression::Expression;
+use crate::ast::pattern::Pattern;
+use crate::ast::statement::BoxStatement;
+use crate::errors::{ Descriptor, ResultWithError };
+use crate::handle_unrolling_in_loop;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::environment::statement_result::{ StatementExecution, UnrollingReason };
+use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::runtime_values::objects::runtime_object::RuntimeObject;
+use crate::interpreter::runtime_values::ref_to_value::RefToValue;
+use crate::interpreter::utils::expect_object_fn;
+use crate::types::cell_ref::gc_clone;
+use crate::types::consts::{ ITER, ITERATOR_DONE, ITERATOR_VALUE, NEXT };
+use crate::types::traits::ConsumeOrCloneOf;
+
+impl Environment {
+	/// Vectors are iterated over the items they have when the loop starts, any other object has to
+	/// have an `iter()` method returning an iterator, whose `next()` method returns objects like
+	/// `{ value: 1, done: false }`, until one with a truthy `done`.
+	pub(crate) fn eval_for_of_loop(
+		&mut self,
+		pattern: &Pattern,
+		iterable: &Expression,
+		body: &BoxStatement
+	) -> ResultWithError<StatementExecution> {
+		let value = self.eval(iterable)?.consume_or_clone()?;
+		if let Some(items) = Vector::try_items_of(&value)? {
+			for item in items.into_iter() {
+				handle_unrolling_in_loop!(self.eval_loop_iteration(pattern, item, body)?);
+			}
+			return Ok(StatementExecution::NormalFlow);
+		}
+		let object = gc_clone(expect_object_fn(&value, || Descriptor::Expression(iterable.clone()))?);
+		let iterator = RuntimeObject::call_method_on_object(object, self, ITER.into(), vec![])?;
+		let iterator = gc_clone(expect_object_fn(&iterator, || Descriptor::Name(ITER.into()))?);
+		loop {
+			let step = RuntimeObject::call_method_on_object(gc_clone(&iterator), self, NEXT.into(), vec![])?;
+			let step = gc_clone(expect_object_fn(&step, || Descriptor::Name(NEXT.into()))?);
+			if RefToValue::get_object_property(self, gc_clone(&step), ITERATOR_DONE.into())?.is_truthy() {
+				break;
+			}
+			let item = RefToValue::get_object_property(self, step, ITERATOR_VALUE.into())?.consume_or_clone()?;
+			handle_unrolling_in_loop!(self.eval_loop_iteration(pattern, item, body)?);
+		}
+		return Ok(StatementExecution::NormalFlow);
+	}
+
+	/// Iterates over the names of the properties of the object itself in sorted order, leaving out
+	/// those of its class and the internal ones.
+	pub(crate) fn eval_for_in_loop(
+		&mut self,
+		pattern: &Pattern,
+		object: &Expression,
+		body: &BoxStatement
+	) -> ResultWithError<StatementExecution> {
+		let value = self.eval(object)?.consume_or_clone()?;
+		let object = expect_object_fn(&value, || Descriptor::Expression(object.clone()))?;
+		for key in object.own_property_names().into_iter() {
+			handle_unrolling_in_loop!(self.eval_loop_iteration(pattern, PrimitiveValue::String(key), body)?);
+		}
+		return Ok(StatementExecution::NormalFlow);
+	}
+
+	fn eval_loop_iteration(
+		&mut self,
+		pattern: &Pattern,
+		value: PrimitiveValue,
+		body: &BoxStatement
+	) -> ResultWithError<StatementExecution> {
+		let mut env = Environment::new_with_parent(self)?;
+		env.declare_pattern(pattern, value)?;
+		return env.eval_statement__creates_scope(body);
+	}
+}
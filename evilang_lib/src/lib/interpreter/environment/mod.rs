@@ -5,8 +5,8 @@ use gc::{ Finalize, Trace };
 use itertools::{ Either::Left, Either::Right };
 
 use crate::ast::expression::{ Expression, IdentifierT };
-use crate::ast::statement::{ BoxStatement, Statement, StatementList };
-use crate::errors::{ Descriptor, ResultWithError, RuntimeError };
+use crate::ast::statement::{ BoxStatement, Statement, StatementKind, StatementList };
+use crate::errors::{ Descriptor, ErrorT, ResultWithError, RuntimeError };
 use crate::interpreter::environment::default_global_scope::{
 	get_default_global_scope,
 	setup_environment,
@@ -42,6 +42,10 @@ use crate::types::traits::ConsumeOrCloneOf;
 
 pub mod statement_result;
 pub mod expression_evaluation;
+pub mod destructuring;
+pub mod exceptions;
+pub mod iteration;
+pub mod pattern_matching;
 pub mod native_items;
 pub mod default_global_scope;
 pub mod resolver;
@@ -88,7 +92,7 @@ impl Environment {
 		{
 			let mut scope_vars_borr = scope.variables.borrow_mut();
 			for (name, value) in variables.into_iter() {
-				scope_vars_borr.deref_mut().assign(name.into(), value);
+				scope_vars_borr.deref_mut().assign(name.into(), value)?;
 			}
 		}
 		return Ok(Self { scope, global_scope });
@@ -119,8 +123,8 @@ impl Environment {
 		env.scope.assign_locally(
 			CURRENT_FILE.into(),
 			PrimitiveValue::String(resolved_res.absolute_file_path)
-		);
-		env.setup_and_eval_statements(&resolved_res.statements)?;
+		)?;
+		env.setup_and_eval_statements(&resolved_res.statements)?.raise_uncaught_exception()?;
 		return Ok(env);
 	}
 
@@ -134,30 +138,38 @@ impl Environment {
 		env.scope.assign_locally(
 			CURRENT_FILE.into(),
 			PrimitiveValue::String(resolved_res.absolute_file_path)
-		);
+		)?;
 		env.setup_and_eval_statements(&resolved_res.statements)
 	}
 
 	pub fn eval_program_string(&mut self, input: StringT) -> ResultWithError<StatementExecution> {
-		self.setup_and_eval_statements(&parse(input)?)
+		self.setup_and_eval_statements(&parse(input)?)?.raise_uncaught_exception()
 	}
 
 	pub fn setup_scope_for_statement(
 		&mut self,
 		statement: &Statement
 	) -> ResultWithError<StatementMetaGeneration> {
-		match statement {
-			Statement::VariableDeclarations(decls) => {
+		match &statement.kind {
+			StatementKind::VariableDeclarations(decls) | StatementKind::ConstantDeclarations(decls) => {
 				for decl in decls.iter() {
-					self.hoist_identifier((&decl.identifier).into())?;
+					for identifier in decl.pattern.identifiers() {
+						self.hoist_identifier(identifier.into())?;
+					}
 				}
 			}
-			Statement::FunctionDeclarationStatement(fdecl) => {
-				self.declare((&fdecl.name).into(), Function::new_closure(self, fdecl.clone()).into())?;
+			// Closures with capture lists are only created once their declaration is reached, so
+			// that they capture the values the variables have at that point.
+			StatementKind::FunctionDeclarationStatement(fdecl) if fdecl.captures.is_some() => {
+				self.hoist_identifier((&fdecl.name).into())?;
+			}
+			StatementKind::FunctionDeclarationStatement(fdecl) => {
+				let function = Function::new_closure(self, fdecl.clone())?;
+				self.declare_binding((&fdecl.name).into(), function.into(), fdecl.is_constant)?;
 			}
-			Statement::ClassDeclarationStatement(cdecl) => {
+			StatementKind::ClassDeclarationStatement(cdecl) => {
 				let class = RuntimeObject::new_class_decl(self, cdecl)?;
-				self.declare((&cdecl.name).into(), class.into())?;
+				self.declare_binding((&cdecl.name).into(), class.into(), cdecl.is_constant)?;
 			}
 			_ => {}
 		}
@@ -198,34 +210,38 @@ impl Environment {
 		&mut self,
 		statement: &Statement
 	) -> ResultWithError<StatementExecution> {
-		return match statement {
-			Statement::EmptyStatement => { Ok(StatementExecution::NormalFlow) }
-			Statement::BlockStatement(statements) => { self.eval_block__creates_scope(statements) }
-			Statement::ForLoop { initialization, condition, increment, body } => {
+		return match &statement.kind {
+			StatementKind::EmptyStatement => { Ok(StatementExecution::NormalFlow) }
+			StatementKind::BlockStatement(statements) => { self.eval_block__creates_scope(statements) }
+			StatementKind::ForLoop { initialization, condition, increment, body } => {
 				self.eval_for_loop__creates_scope(initialization, condition, increment, body)
 			}
-			v => {
+			_ => {
 				let mut env = Environment::new_with_parent(self)?;
-				env.setup_and_eval_statement(v)
+				env.setup_and_eval_statement(statement)
 			}
 		};
 	}
 
 	pub fn eval_statement(&mut self, statement: &Statement) -> ResultWithError<StatementExecution> {
-		return match statement {
-			Statement::EmptyStatement => { Ok(StatementExecution::NormalFlow) }
-			Statement::BlockStatement(statements) => { self.eval_block__creates_scope(statements) }
-			Statement::IfStatement { condition, if_branch, else_branch } => {
+		return self.eval_statement_kind(statement).map_err(|e| e.with_span(statement.span));
+	}
+
+	fn eval_statement_kind(&mut self, statement: &Statement) -> ResultWithError<StatementExecution> {
+		return match &statement.kind {
+			StatementKind::EmptyStatement => { Ok(StatementExecution::NormalFlow) }
+			StatementKind::BlockStatement(statements) => { self.eval_block__creates_scope(statements) }
+			StatementKind::IfStatement { condition, if_branch, else_branch } => {
 				self.eval_if_statement(condition, if_branch, else_branch)
 			}
-			Statement::WhileLoop { condition, body } => {
+			StatementKind::WhileLoop { condition, body } => {
 				while self.eval(condition)?.is_truthy() {
 					let v = self.eval_statement__creates_scope(body)?;
 					handle_unrolling_in_loop!(v);
 				}
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::DoWhileLoop { condition, body } => {
+			StatementKind::DoWhileLoop { condition, body } => {
 				loop {
 					let v = self.eval_statement__creates_scope(body)?;
 					handle_unrolling_in_loop!(v);
@@ -235,35 +251,64 @@ impl Environment {
 				}
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::ForLoop { initialization, condition, increment, body } => {
+			StatementKind::ForLoop { initialization, condition, increment, body } => {
 				self.eval_for_loop__creates_scope(initialization, condition, increment, body)
 			}
-			Statement::ExpressionStatement(expr) => {
+			StatementKind::ForOfLoop { pattern, iterable, body } => {
+				self.eval_for_of_loop(pattern, iterable, body)
+			}
+			StatementKind::ForInLoop { pattern, object, body } => {
+				self.eval_for_in_loop(pattern, object, body)
+			}
+			StatementKind::ExpressionStatement(expr) => {
 				self.eval(expr)?;
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::VariableDeclarations(decls) => {
+			StatementKind::VariableDeclarations(decls) => {
 				for decl in decls.iter() {
 					let value = if let Some(expr) = &decl.initializer {
 						self.eval(expr)?.consume_or_clone()?
 					} else {
 						PrimitiveValue::Null
 					};
-					self.declare((&decl.identifier).into(), value)?;
+					self.declare_pattern(&decl.pattern, value)?;
 				}
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::BreakStatement(v) => {
+			StatementKind::ConstantDeclarations(decls) => {
+				for decl in decls.iter() {
+					let value = match &decl.initializer {
+						Some(expr) => self.eval(expr)?.consume_or_clone()?,
+						None => PrimitiveValue::Null,
+					};
+					self.declare_constant_pattern(&decl.pattern, value)?;
+				}
+				Ok(StatementExecution::NormalFlow)
+			}
+			StatementKind::BreakStatement(v) => {
 				Ok(StatementExecution::Unrolling(UnrollingReason::EncounteredBreak(*v)))
 			}
-			Statement::ContinueStatement(v) => {
+			StatementKind::ContinueStatement(v) => {
 				Ok(StatementExecution::Unrolling(UnrollingReason::EncounteredContinue(*v)))
 			}
-			Statement::FunctionDeclarationStatement(..) => {
+			StatementKind::ThrowStatement(expr) => {
+				let value = self.eval(expr)?.consume_or_clone()?;
+				Ok(StatementExecution::Unrolling(UnrollingReason::Throwing(value, statement.span)))
+			}
+			StatementKind::TryStatement { body, catch_clause, finally_block } => {
+				self.eval_try_statement(body, catch_clause, finally_block)
+			}
+			StatementKind::MatchStatement { value, arms } => self.eval_match_statement(value, arms),
+			StatementKind::FunctionDeclarationStatement(fdecl) if fdecl.captures.is_some() => {
+				let function = Function::new_closure(self, fdecl.clone())?;
+				self.declare_binding((&fdecl.name).into(), function.into(), fdecl.is_constant)?;
+				Ok(StatementExecution::NormalFlow)
+			}
+			StatementKind::FunctionDeclarationStatement(..) => {
 				// Function declaration has already been hoisted
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::ReturnStatement(expr_opt) => {
+			StatementKind::ReturnStatement(expr_opt) => {
 				let res = if let Some(expr) = expr_opt.as_ref() {
 					self.eval(expr)?.consume_or_clone()?
 				} else {
@@ -271,16 +316,16 @@ impl Environment {
 				};
 				Ok(StatementExecution::Unrolling(UnrollingReason::ReturningValue(res)))
 			}
-			Statement::ClassDeclarationStatement(..) => {
+			StatementKind::ClassDeclarationStatement(..) => {
 				// Class declaration has already been hoisted
 				Ok(StatementExecution::NormalFlow)
 			}
-			Statement::NamespaceStatement { namespace, body } => {
+			StatementKind::NamespaceStatement { namespace, body } => {
 				let obj = self.get_namespace_object(namespace)?;
 				let mut env = Environment::new_with_object_scope(self, &obj)?;
 				env.setup_and_eval_statements(body)
 			}
-			Statement::ImportStatement { as_object, file_name } => {
+			StatementKind::ImportStatement { as_object, file_name } => {
 				let obj = self.get_namespace_object(as_object)?;
 				let file = match self.eval(file_name)?.consume_or_clone()?.consume_as_string() {
 					Left(str) => str,
@@ -294,6 +339,7 @@ impl Environment {
 				};
 				self.import_file(obj, file)
 			}
+			StatementKind::ErrorStatement => { Err(ErrorT::ExecutingErrorStatement.into()) }
 			/*stmt => {
 				Err(ErrorT::UnimplementedStatementTypeForInterpreter(stmt.clone()).into())
 			}*/
@@ -317,9 +363,9 @@ impl Environment {
 		body: &BoxStatement
 	) -> ResultWithError<StatementExecution> {
 		let mut env = Environment::new_with_parent(self)?;
-		let init_eval_res = match &**initialization {
-			Statement::BlockStatement(stmts) => { env.setup_and_eval_statements(stmts)? }
-			init => env.setup_and_eval_statement(init)?,
+		let init_eval_res = match &initialization.kind {
+			StatementKind::BlockStatement(stmts) => { env.setup_and_eval_statements(stmts)? }
+			_ => env.setup_and_eval_statement(initialization)?,
 		};
 		handle_unrolling!(init_eval_res);
 		'for_simulator: while env.eval(condition)?.is_truthy() {
@@ -355,6 +401,17 @@ impl Environment {
 		};
 	}
 
+	/// Declares `name` in the current scope, as a constant if `is_constant`.
+	#[inline]
+	pub fn declare_binding(
+		&mut self,
+		name: CowStringT,
+		value: PrimitiveValue,
+		is_constant: bool
+	) -> ResultWithError<()> {
+		return if is_constant { self.declare_constant(name, value) } else { self.declare(name, value) };
+	}
+
 	#[inline]
 	pub fn hoist_identifier(&mut self, iden: CowStringT) -> ResultWithError<StatementMetaGeneration> {
 		self.scope.hoist(iden)?;
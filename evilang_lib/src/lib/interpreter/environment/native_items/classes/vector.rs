@@ -7,7 +7,6 @@ use crate::implement_get_class_cached;
 use crate::interpreter::environment::Environment;
 use crate::interpreter::environment::native_items::classes::object::ObjectSuperclass;
 use crate::interpreter::runtime_values::functions::GcPtrToFunction;
-use crate::interpreter::runtime_values::functions::ifunction::IFunction;
 use crate::interpreter::runtime_values::i_native_struct::{
 	auto_unwrap_exec_fn,
 	INativeClass,
@@ -21,7 +20,9 @@ use crate::interpreter::runtime_values::i_native_struct::INativeClass_IsStructWr
 use crate::interpreter::runtime_values::i_native_struct::INativeStruct;
 use crate::interpreter::runtime_values::objects::runtime_object::{ GcPtrToObject, RuntimeObject };
 use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::variables_containers::map::IVariablesMapConstMembers;
 use crate::types::number::NumberT;
+use crate::types::string::StringT;
 
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct Vector {
@@ -40,6 +41,34 @@ implement_get_class_cached!(Vector);
 
 impl INativeStruct for Vector {}
 
+impl Vector {
+	/// Clones the items of `value`, which has to be a vector.
+	pub fn items_of(value: &PrimitiveValue, name: StringT) -> ResultWithError<Vec<PrimitiveValue>> {
+		return auto_unwrap_exec_fn(
+			value,
+			|vector: &GcCell<Vector>| {
+				vector
+					.borrow()
+					.vec.iter()
+					.map(PrimitiveValue::try_clone_err)
+					.collect()
+			},
+			|| name.clone().into()
+		);
+	}
+
+	/// Clones the items of `value` if it is a vector, without treating any other value as an error.
+	pub fn try_items_of(value: &PrimitiveValue) -> ResultWithError<Option<Vec<PrimitiveValue>>> {
+		let PrimitiveValue::Object(object) = value else {
+			return Ok(None);
+		};
+		if object.get_actual(Vector::NATIVE_BOX_WRAP_NAME.into()).is_none() {
+			return Ok(None);
+		}
+		return Vector::items_of(value, Vector::NAME.into()).map(Some);
+	}
+}
+
 #[derive_build_class(evilang_lib_crate = crate)]
 impl Vector {
 	#[export = "constructor"]
@@ -58,7 +87,7 @@ impl Vector {
 			vec: params,
 		};
 		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(env)?, None);
-		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res)?;
 		return Ok(obj.into());
 	}
 
@@ -74,7 +103,7 @@ impl Vector {
 				.collect::<ResultWithError<Vec<_>>>()?,
 		};
 		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(ctx.env)?, None);
-		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res)?;
 		return Ok(obj.into());
 	}
 
@@ -86,11 +115,11 @@ impl Vector {
 	) -> ResultWithError<PrimitiveValue> {
 		let res = Self {
 			vec: (0..n.floor_to_int() as usize)
-				.map(|i| func.execute(ctx.env, vec![NumberT::from(i as i128).into()]))
+				.map(|i| func.execute_callback(ctx.env, vec![NumberT::from(i as i128).into()]))
 				.collect::<ResultWithError<Vec<_>>>()?,
 		};
 		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(ctx.env)?, None);
-		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res)?;
 		return Ok(obj.into());
 	}
 
@@ -101,6 +130,7 @@ impl Vector {
 	}
 
 	#[export]
+	#[export(getter, _as = "length")]
 	#[inline]
 	pub fn len(&self, _ctx: NativeClassMemberFunctionContext) -> ResultWithError<NumberT> {
 		return Ok((self.vec.len() as i128).into());
@@ -213,7 +243,7 @@ impl Vector {
 		func: GcPtrToFunction
 	) -> ResultWithError<PrimitiveValue> {
 		for (i, v) in self.vec.iter().enumerate() {
-			func.execute(ctx.env, vec![v.try_clone_err()?, NumberT::from(i as i128).into()])?;
+			func.execute_callback(ctx.env, vec![v.try_clone_err()?, NumberT::from(i as i128).into()])?;
 		}
 		return Ok(PrimitiveValue::Null);
 	}
@@ -226,7 +256,7 @@ impl Vector {
 		func: GcPtrToFunction
 	) -> ResultWithError<PrimitiveValue> {
 		for (i, v) in self.vec.iter().enumerate() {
-			let new_value = func.execute(
+			let new_value = func.execute_callback(
 				ctx.env,
 				vec![initial, v.try_clone_err()?, NumberT::from(i as i128).into()]
 			)?;
@@ -243,7 +273,7 @@ impl Vector {
 	) -> ResultWithError<PrimitiveValue> {
 		for (i, v) in self.vec.iter_mut().enumerate() {
 			let value = v.try_clone_err()?;
-			*v = func.execute(ctx.env, vec![value, NumberT::from(i as i128).into()])?;
+			*v = func.execute_callback(ctx.env, vec![value, NumberT::from(i as i128).into()])?;
 		}
 		return ctx.this_param.try_clone_err();
 	}
@@ -260,13 +290,13 @@ impl Vector {
 				.enumerate()
 				.map(
 					|(i, value)| -> ResultWithError<PrimitiveValue> {
-						func.execute(ctx.env, vec![value.try_clone_err()?, NumberT::from(i as i128).into()])
+						func.execute_callback(ctx.env, vec![value.try_clone_err()?, NumberT::from(i as i128).into()])
 					}
 				)
 				.collect::<ResultWithError<Vec<_>>>()?,
 		};
 		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(ctx.env)?, None);
-		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res)?;
 		return Ok(obj.into());
 	}
 
@@ -276,7 +306,20 @@ impl Vector {
 			vec: self.vec.clone(),
 		};
 		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(ctx.env)?, None);
-		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), res)?;
+		return Ok(obj.into());
+	}
+
+	#[export = "__add__"]
+	pub fn concat(
+		&self,
+		ctx: NativeClassMemberFunctionContext,
+		oth: PrimitiveValue
+	) -> ResultWithError<PrimitiveValue> {
+		let mut vec = self.vec.clone();
+		vec.extend(Vector::items_of(&oth, "other parameter of Vector::concat".into())?);
+		let obj = RuntimeObject::allocate_instance(Vector::get_class_cached(ctx.env)?, None);
+		native_wrap(&obj, Vector::NATIVE_BOX_WRAP_NAME.into(), Self { vec })?;
 		return Ok(obj.into());
 	}
 
@@ -1,18 +1,27 @@
 use std::collections::HashMap;
 
+use itertools::{ Either::Left, Either::Right };
+
 use crate::ast::expression::IdentifierT;
 use crate::errors::{ Descriptor, ResultWithError, RuntimeError };
 use crate::interpreter::environment::Environment;
 use crate::interpreter::environment::native_items::classes::object::ObjectSuperclass;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::runtime_values::functions::Function;
 use crate::interpreter::runtime_values::functions::native_function::NativeFunctionFn;
 use crate::interpreter::runtime_values::functions::types::{
 	FunctionParameters,
 	FunctionReturnValue,
 };
 use crate::interpreter::runtime_values::i_native_struct::INativeClass_GetClassCached;
-use crate::interpreter::runtime_values::objects::runtime_object::RuntimeObject;
+use crate::interpreter::runtime_values::objects::runtime_object::{ GcPtrToObject, RuntimeObject };
 use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::runtime_values::ref_to_value::RefToValue;
 use crate::interpreter::utils::expect_object;
+use crate::types::cell_ref::{ gc_clone, GcPtr };
+use crate::types::consts::TO_STRING;
+use crate::types::string::StringT;
+use crate::types::traits::ConsumeOrCloneOf;
 
 pub mod classes;
 
@@ -24,45 +33,114 @@ pub fn push_res_stack(
 	Ok(PrimitiveValue::Null)
 }
 
+/// Converts a value to the string that `to_string` returns for it. Objects are converted by their
+/// own `to_string(this)` method when they have one, vectors are shown as `[1, 2, 3]` and any other
+/// object as the name of its class followed by its properties, like `Point { x: 1, y: 2 }`.
+pub fn stringify(env: &mut Environment, value: &PrimitiveValue) -> ResultWithError<StringT> {
+	return stringify_nested(env, value, &mut vec![]);
+}
+
+/// `enclosing` holds the objects being converted that `value` is a part of, an object containing
+/// itself being shown as `...` the second time around.
+fn stringify_nested(
+	env: &mut Environment,
+	value: &PrimitiveValue,
+	enclosing: &mut Vec<GcPtrToObject>
+) -> ResultWithError<StringT> {
+	let object = match value {
+		PrimitiveValue::Null => { return Ok("null".to_string()); }
+		PrimitiveValue::Boolean(v) => { return Ok((if *v { "true" } else { "false" }).to_string()); }
+		PrimitiveValue::Number(num) => { return Ok(num.to_string()); }
+		PrimitiveValue::String(v) => { return Ok(v.clone()); }
+		PrimitiveValue::Function(function) => { return Ok(function.to_string()); }
+		PrimitiveValue::Object(object) => object,
+		pv => {
+			return Err(
+				RuntimeError::InvalidArgumentsToFunction(
+					"Can't convert to string".to_string(),
+					Descriptor::Value(pv.clone__silently_fail())
+				).into()
+			);
+		}
+	};
+	// Classes, namespaces and other named objects are shown by their name
+	if !object.is_instance() {
+		return Ok(object.name.clone());
+	}
+	if RuntimeObject::has_method(object, TO_STRING.into()) {
+		let res = RuntimeObject::call_method_on_object(gc_clone(object), env, TO_STRING.into(), vec![])?;
+		return match res.consume_as_string() {
+			Left(v) => Ok(v),
+			Right(value) =>
+				Err(RuntimeError::ExpectedString(Descriptor::NameAndValue { name: TO_STRING.into(), value }).into()),
+		};
+	}
+	if enclosing.iter().any(|v| GcPtr::ptr_eq(v, object)) {
+		return Ok("...".to_string());
+	}
+	enclosing.push(gc_clone(object));
+	let res = if let Some(items) = Vector::try_items_of(value)? {
+		let items = items
+			.iter()
+			.map(|item| stringify_item(env, item, enclosing))
+			.collect::<ResultWithError<Vec<_>>>()?;
+		format!("[{}]", items.join(", "))
+	} else {
+		let properties = object
+			.own_property_names()
+			.into_iter()
+			.map(|name| {
+				let value = RefToValue::new_object_property_ref(gc_clone(object), name.clone()).consume_or_clone()?;
+				Ok(format!("{}: {}", name, stringify_item(env, &value, enclosing)?))
+			})
+			.collect::<ResultWithError<Vec<_>>>()?;
+		// Instances are shown with the name of their class rather than `Instance of ...`
+		let class_name = object.parent.as_ref().map_or(&object.name, |parent| &parent.name);
+		if properties.is_empty() {
+			format!("{} {{}}", class_name)
+		} else {
+			format!("{} {{ {} }}", class_name, properties.join(", "))
+		}
+	};
+	enclosing.pop();
+	return Ok(res);
+}
+
+/// Strings inside vectors and objects are quoted, so that `["1"]` and `[1]` can be told apart.
+fn stringify_item(
+	env: &mut Environment,
+	value: &PrimitiveValue,
+	enclosing: &mut Vec<GcPtrToObject>
+) -> ResultWithError<StringT> {
+	if let PrimitiveValue::String(v) = value {
+		return Ok(format!("{:?}", v));
+	}
+	return stringify_nested(env, value, enclosing);
+}
+
 pub fn to_string(
-	_env: &mut Environment,
+	env: &mut Environment,
 	params: FunctionParameters
 ) -> ResultWithError<FunctionReturnValue> {
-	Ok(
-		PrimitiveValue::String(match params.first().unwrap() {
-			PrimitiveValue::Null => { "null".to_string() }
-			PrimitiveValue::Boolean(v) => { (if *v { "true" } else { "false" }).to_string() }
-			PrimitiveValue::Number(num) => { num.to_string() }
-			PrimitiveValue::String(v) => { v.clone() }
-			PrimitiveValue::Function(function) => { function.to_string() }
-			pv => {
-				return Err(
-					RuntimeError::InvalidArgumentsToFunction(
-						"Can't convert to string".to_string(),
-						Descriptor::Value(pv.clone__silently_fail())
-					).into()
-				);
-			}
-		})
-	)
+	Ok(PrimitiveValue::String(stringify(env, params.first().unwrap())?))
 }
 
 pub fn print(
-	_env: &mut Environment,
+	env: &mut Environment,
 	params: FunctionParameters
 ) -> ResultWithError<FunctionReturnValue> {
-	for x in params.into_iter() {
-		print!("{}", x);
+	for x in params.iter() {
+		print!("{}", stringify(env, x)?);
 	}
 	Ok(PrimitiveValue::Null)
 }
 
 pub fn println(
-	_env: &mut Environment,
+	env: &mut Environment,
 	params: FunctionParameters
 ) -> ResultWithError<FunctionReturnValue> {
-	for x in params.into_iter() {
-		print!("{}", x);
+	for x in params.iter() {
+		print!("{}", stringify(env, x)?);
 	}
 	println!();
 	Ok(PrimitiveValue::Null)
@@ -76,6 +154,26 @@ pub fn debug(
 	Ok(PrimitiveValue::Null)
 }
 
+/// Returns a function calling `function` with the rest of the arguments given ahead of the ones
+/// it is called with, so `bind(f, 1)(2)` is `f(1, 2)`.
+pub fn bind(
+	_env: &mut Environment,
+	params: FunctionParameters
+) -> ResultWithError<FunctionReturnValue> {
+	let function = match params.first() {
+		Some(PrimitiveValue::Function(function)) => gc_clone(function),
+		v => {
+			return Err(
+				RuntimeError::ExpectedFunction(
+					Descriptor::Value(v.map_or(PrimitiveValue::Null, PrimitiveValue::clone__silently_fail))
+				).into()
+			);
+		}
+	};
+	let bound_args = params.into_iter().skip(1).collect();
+	return Ok(PrimitiveValue::Function(Function::new_bound(function, bound_args)));
+}
+
 pub fn allocate_object(
 	env: &mut Environment,
 	mut params: FunctionParameters
@@ -109,6 +207,7 @@ pub fn make_native_functions_list() -> HashMap<IdentifierT, NativeFunctionFn> {
 			("println", println as NativeFunctionFn),
 			("allocate_object", allocate_object as NativeFunctionFn),
 			("to_string", to_string as NativeFunctionFn),
+			("bind", bind as NativeFunctionFn),
 		]
 			.into_iter()
 			.map(|(name, val)| (name.into(), val))
@@ -0,0 +1,147 @@
+use crate::ast::expression::{ Expression, IdentifierT };
+use crate::ast::pattern::{ Pattern, PatternElement };
+use crate::ast::statement::BoxStatement;
+use crate::ast::structs::MatchArm;
+use crate::errors::{ Descriptor, ErrorT, ResultWithError, RuntimeError };
+use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
+use crate::interpreter::environment::statement_result::StatementExecution;
+use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::interpreter::runtime_values::ref_to_value::RefToValue;
+use crate::interpreter::variables_containers::map::IVariablesMapDelegator;
+use crate::types::cell_ref::gc_clone;
+use crate::types::traits::ConsumeOrCloneOf;
+
+/// The names bound by a pattern while it is matched, declared only once the whole pattern matches.
+type Bindings = Vec<(IdentifierT, PrimitiveValue)>;
+
+impl Environment {
+	pub(crate) fn eval_match_statement(
+		&mut self,
+		value: &Expression,
+		arms: &[MatchArm<BoxStatement>]
+	) -> ResultWithError<StatementExecution> {
+		let value = self.eval(value)?.consume_or_clone()?;
+		for arm in arms.iter() {
+			if let Some(mut env) = self.match_arm(arm, &value)? {
+				return env.eval_statement__creates_scope(&arm.body);
+			}
+		}
+		return Ok(StatementExecution::NormalFlow);
+	}
+
+	pub(crate) fn eval_match_expression(
+		&mut self,
+		value: &Expression,
+		arms: &[MatchArm<Expression>]
+	) -> ResultWithError<RefToValue> {
+		let value = self.eval(value)?.consume_or_clone()?;
+		for arm in arms.iter() {
+			if let Some(mut env) = self.match_arm(arm, &value)? {
+				return env.eval(&arm.body);
+			}
+		}
+		return Err(RuntimeError::NoMatchingArm(Descriptor::Value(value)).into());
+	}
+
+	/// The scope to run the body of `arm` in, with the names bound by its pattern declared, if the
+	/// pattern matches `value` and the guard holds.
+	fn match_arm<T>(
+		&mut self,
+		arm: &MatchArm<T>,
+		value: &PrimitiveValue
+	) -> ResultWithError<Option<Environment>> {
+		let mut bindings = Bindings::new();
+		if !self.match_pattern(&arm.pattern, value, &mut bindings)? {
+			return Ok(None);
+		}
+		let mut env = Environment::new_with_parent(self)?;
+		for (identifier, value) in bindings.into_iter() {
+			env.declare(identifier.into(), value)?;
+		}
+		if let Some(guard) = &arm.guard {
+			if !env.eval(guard)?.is_truthy() {
+				return Ok(None);
+			}
+		}
+		return Ok(Some(env));
+	}
+
+	fn match_pattern(
+		&mut self,
+		pattern: &Pattern,
+		value: &PrimitiveValue,
+		bindings: &mut Bindings
+	) -> ResultWithError<bool> {
+		match pattern {
+			Pattern::Identifier(identifier) => {
+				bindings.push((identifier.clone(), value.try_clone_err()?));
+			}
+			Pattern::Wildcard => {}
+			Pattern::Literal(literal) => {
+				return Ok(self.eval(literal)?.consume_or_clone()? == *value);
+			}
+			Pattern::Alternatives(alternatives) => {
+				for alternative in alternatives.iter() {
+					let bound_before = bindings.len();
+					if self.match_pattern(alternative, value, bindings)? {
+						return Ok(true);
+					}
+					bindings.truncate(bound_before);
+				}
+				return Ok(false);
+			}
+			Pattern::Vector { elements, rest } => {
+				let Some(items) = Vector::try_items_of(value)? else {
+					return Ok(false);
+				};
+				// Without a rest element the vector has to have exactly as many items as the pattern
+				if items.len() < elements.len() || (rest.is_none() && items.len() > elements.len()) {
+					return Ok(false);
+				}
+				let mut items = items.into_iter();
+				for element in elements.iter() {
+					let item = items.next().unwrap_or(PrimitiveValue::Null);
+					if !self.match_pattern_element(element, item, bindings)? {
+						return Ok(false);
+					}
+				}
+				if let Some(rest) = rest {
+					let rest_value = Vector::from(self, items.collect())?;
+					return self.match_pattern(rest, &rest_value, bindings);
+				}
+			}
+			Pattern::Object(properties) => {
+				// Missing properties are read as `null`, the same as when destructuring
+				let PrimitiveValue::Object(object) = value else {
+					return Ok(false);
+				};
+				for property in properties.iter() {
+					let name = self.name_from_member_indexer(&property.key)?.into_owned();
+					let property_value = RefToValue::get_object_property(self, gc_clone(object), name)?
+						.consume_or_clone()?;
+					if !self.match_pattern_element(&property.element, property_value, bindings)? {
+						return Ok(false);
+					}
+				}
+			}
+			Pattern::Target(target) => {
+				return Err(ErrorT::UnimplementedExpressionTypeForInterpreter(target.as_ref().clone()).into());
+			}
+		}
+		return Ok(true);
+	}
+
+	fn match_pattern_element(
+		&mut self,
+		element: &PatternElement,
+		value: PrimitiveValue,
+		bindings: &mut Bindings
+	) -> ResultWithError<bool> {
+		let value = match &element.default {
+			Some(default) if value.is_null() => self.eval(default)?.consume_or_clone()?,
+			_ => value,
+		};
+		return self.match_pattern(&element.pattern, &value, bindings);
+	}
+}
@@ -10,7 +10,7 @@ use crate::errors::{ Descriptor, EvilangError, ResultWithError, RuntimeError };
 use crate::interpreter::environment::Environment;
 use crate::interpreter::runtime_values::PrimitiveValue;
 use crate::interpreter::variables_containers::map::IVariablesMapConstMembers;
-use crate::parser::parse;
+use crate::parser::parse_file;
 use crate::types::consts::CURRENT_FILE;
 use crate::types::string::StringT;
 
@@ -164,7 +164,7 @@ impl IResolver for DefaultResolver {
 		// dbg!(&absolute_file_path);
 		let contents: StringT = fs::read_to_string(f_path).map_err(EvilangError::from)?;
 		return Ok(ResolveResult {
-			statements: parse(contents)?,
+			statements: parse_file(absolute_file_path.clone(), contents)?,
 			absolute_file_path,
 		});
 	}
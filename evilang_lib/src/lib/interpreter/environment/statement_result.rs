@@ -1,10 +1,14 @@
+use crate::errors::{ Descriptor, EvilangError, ResultWithError, RuntimeError };
 use crate::interpreter::runtime_values::PrimitiveValue;
+use crate::types::span::Span;
 
 #[derive(Debug, PartialEq)]
 pub enum UnrollingReason {
 	EncounteredBreak(i64),
 	EncounteredContinue(i64),
 	ReturningValue(PrimitiveValue),
+	/// A value thrown by the `throw` statement at the span, unrolling up to the nearest `catch`.
+	Throwing(PrimitiveValue, Span),
 }
 
 #[derive(Debug, PartialEq)]
@@ -13,6 +17,20 @@ pub enum StatementExecution {
 	Unrolling(UnrollingReason),
 }
 
+impl StatementExecution {
+	/// Raises a thrown value that has unrolled out of a function or the whole program as an error,
+	/// since no statement is left to catch it.
+	pub fn raise_uncaught_exception(self) -> ResultWithError<StatementExecution> {
+		return match self {
+			StatementExecution::Unrolling(UnrollingReason::Throwing(value, span)) => {
+				let error = RuntimeError::UncaughtException(Descriptor::Value(value));
+				Err(EvilangError::from(error).with_span(span))
+			}
+			res => Ok(res),
+		};
+	}
+}
+
 #[derive(Debug, PartialEq)]
 pub enum StatementMetaGeneration {
 	NormalGeneration,
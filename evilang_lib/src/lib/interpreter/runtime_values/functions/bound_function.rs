@@ -0,0 +1,40 @@
+use gc::{ Finalize, Trace };
+
+use crate::errors::ResultWithError;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::runtime_values::functions::GcPtrToFunction;
+use crate::interpreter::runtime_values::functions::ifunction::IFunction;
+use crate::interpreter::runtime_values::functions::types::{
+	FunctionParameters,
+	FunctionReturnValue,
+};
+use crate::interpreter::runtime_values::PrimitiveValue;
+
+/// A function called with some arguments given ahead of the ones it is called with, like a method
+/// read off of an object, which gets the object as its first argument.
+#[derive(Debug, PartialEq, Trace, Finalize)]
+pub struct BoundFunction {
+	pub function: GcPtrToFunction,
+	pub bound_args: FunctionParameters,
+}
+
+impl BoundFunction {
+	pub fn new(function: GcPtrToFunction, bound_args: FunctionParameters) -> Self {
+		Self { function, bound_args }
+	}
+}
+
+impl IFunction for BoundFunction {
+	fn execute(
+		&self,
+		env: &mut Environment,
+		params: FunctionParameters
+	) -> ResultWithError<FunctionReturnValue> {
+		let mut args = self.bound_args
+			.iter()
+			.map(PrimitiveValue::try_clone_err)
+			.collect::<ResultWithError<FunctionParameters>>()?;
+		args.extend(params);
+		return self.function.execute(env, args);
+	}
+}
@@ -2,9 +2,11 @@ use std::fmt::{ Debug, Formatter };
 
 use gc::{ Finalize, Trace };
 
+use crate::ast::pattern::Pattern;
 use crate::ast::structs::{ FunctionDeclaration, FunctionParameterDeclaration };
-use crate::errors::{ ErrorT, ResultWithError };
+use crate::errors::{ ErrorT, EvilangError, ResultWithError, RuntimeError };
 use crate::interpreter::environment::Environment;
+use crate::interpreter::environment::native_items::classes::vector::Vector;
 use crate::interpreter::environment::statement_result::{ StatementExecution, UnrollingReason };
 use crate::interpreter::runtime_values::functions::ifunction::IFunction;
 use crate::interpreter::runtime_values::functions::types::{
@@ -15,12 +17,19 @@ use crate::interpreter::runtime_values::PrimitiveValue;
 use crate::interpreter::variables_containers::map::IVariablesMapDelegator;
 use crate::interpreter::variables_containers::scope::GcPtrToVariableScope;
 use crate::types::cell_ref::gc_clone;
+use crate::types::consts::{ IMPLICIT_THIS, THIS };
+use crate::types::string::StringT;
+use crate::types::traits::ConsumeOrCloneOf;
 
 #[derive(Trace, Finalize)]
 pub struct Closure {
 	#[unsafe_ignore_trace]
 	pub code: FunctionDeclaration,
 	pub parent_scope: GcPtrToVariableScope,
+	/// Whether the closure is a method of a class.
+	pub is_method: bool,
+	/// Whether `this` is bound implicitly, see [`Closure::new_method`].
+	pub binds_this: bool,
 }
 
 impl PartialEq for Closure {
@@ -46,13 +55,19 @@ impl IFunction for Closure {
 			gc_clone(&this_env.global_scope)
 		);
 		let mut env = Environment::new_with_parent(&parent_env)?;
-		for (
-			FunctionParameterDeclaration { identifier: param_name },
-			param_value,
-		) in self.code.parameters.iter().zip(params.into_iter()) {
-			env.declare(param_name.into(), param_value)?;
-		}
-		let stmt_res = env.setup_and_eval_statement(&self.code.body)?;
+		let params = if self.binds_this {
+			declare_this(&mut env, params)?
+		} else {
+			if self.is_method {
+				env.declare(IMPLICIT_THIS.into(), PrimitiveValue::Null)?;
+			}
+			params
+		};
+		self.declare_parameters(&mut env, params)?;
+		let stmt_res = env
+			.setup_and_eval_statement(&self.code.body)
+			.and_then(StatementExecution::raise_uncaught_exception)
+			.map_err(|e| e.with_stack_frame(self.code.name.clone()))?;
 		let result = match stmt_res {
 			StatementExecution::NormalFlow => PrimitiveValue::Null,
 			StatementExecution::Unrolling(UnrollingReason::ReturningValue(ret_val)) => ret_val,
@@ -60,7 +75,7 @@ impl IFunction for Closure {
 				return Err(
 					ErrorT::InvalidUnrollingOfFunction(
 						self.code.name.clone(),
-						format!("{0:#?}", stmt_res)
+						format!("{0:?}", stmt_res)
 					).into()
 				);
 			}
@@ -71,6 +86,94 @@ impl IFunction for Closure {
 
 impl Closure {
 	pub fn new(code: FunctionDeclaration, parent_scope: GcPtrToVariableScope) -> Self {
-		Self { code, parent_scope }
+		Self { code, parent_scope, is_method: false, binds_this: false }
+	}
+
+	/// Methods of classes get `this` bound implicitly, unless they take it as their first parameter
+	/// the way methods used to, so that code written before keeps working. `super.method(args)`
+	/// inside methods binding `this` implicitly passes the current `this` along, while methods
+	/// taking it explicitly pass it themselves.
+	pub fn new_method(code: FunctionDeclaration, parent_scope: GcPtrToVariableScope) -> Self {
+		let binds_this = !code.parameters
+			.first()
+			.is_some_and(|param| matches!(&param.pattern, Pattern::Identifier(name) if name == THIS));
+		Self { code, parent_scope, is_method: true, binds_this }
+	}
+
+	/// Declares the parameters in the scope of the function, in order, so that default values
+	/// can refer to the parameters before them. Arguments beyond the parameters are an error,
+	/// unless collected by a rest parameter.
+	fn declare_parameters(
+		&self,
+		env: &mut Environment,
+		params: FunctionParameters
+	) -> ResultWithError<()> {
+		let got = params.len();
+		let mut params = params.into_iter();
+		for param in self.code.parameters.iter() {
+			let value = if param.is_rest {
+				Vector::from(env, params.by_ref().collect())?
+			} else if let Some(value) = params.next() {
+				value
+			} else if let Some(default) = &param.default {
+				env.eval(default)?.consume_or_clone()?
+			} else {
+				return Err(self.invalid_number_of_arguments(got));
+			};
+			env.declare_pattern(&param.pattern, value)?;
+		}
+		if params.len() != 0 {
+			return Err(self.invalid_number_of_arguments(got));
+		}
+		return Ok(());
 	}
+
+	/// Methods binding `this` implicitly take it as an extra first argument.
+	pub fn max_arguments(&self) -> Option<usize> {
+		let parameters = &self.code.parameters;
+		if parameters.last().is_some_and(|v| v.is_rest) {
+			return None;
+		}
+		return Some(parameters.len() + usize::from(self.binds_this));
+	}
+
+	fn invalid_number_of_arguments(&self, got: usize) -> EvilangError {
+		return (RuntimeError::InvalidNumberArgumentsToFunction {
+			got,
+			expected: Some(self.expected_number_of_arguments()),
+			func: self.code.name.as_str().into(),
+		}).into();
+	}
+
+	fn expected_number_of_arguments(&self) -> StringT {
+		let parameters = &self.code.parameters;
+		let required = parameters
+			.iter()
+			.rposition(FunctionParameterDeclaration::is_required)
+			.map_or(0, |v| v + 1);
+		let non_rest = parameters
+			.iter()
+			.filter(|v| !v.is_rest)
+			.count();
+		return if parameters.last().is_some_and(|v| v.is_rest) {
+			format!("at least {}", required)
+		} else if required == non_rest {
+			required.to_string()
+		} else {
+			format!("{} to {}", required, non_rest)
+		};
+	}
+}
+
+/// Method calls pass the object as the first argument, which methods binding `this` implicitly
+/// declare as a constant instead of as a parameter. It is also kept hidden from the methods, for
+/// the `super` calls made inside them.
+fn declare_this(
+	env: &mut Environment,
+	mut params: FunctionParameters
+) -> ResultWithError<FunctionParameters> {
+	let this = if params.is_empty() { PrimitiveValue::Null } else { params.remove(0) };
+	env.declare(IMPLICIT_THIS.into(), this.try_clone_err()?)?;
+	env.declare_constant(THIS.into(), this)?;
+	return Ok(params);
 }
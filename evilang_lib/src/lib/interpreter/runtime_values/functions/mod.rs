@@ -1,10 +1,14 @@
 use std::fmt::{ Display, Formatter };
+use std::ops::Deref;
 
 use gc::{ Finalize, Trace };
 
-use crate::ast::structs::FunctionDeclaration;
-use crate::errors::ResultWithError;
+use crate::ast::expression::IdentifierT;
+use crate::ast::structs::{ CaptureDeclaration, FunctionDeclaration };
+use crate::errors::{ ErrorT, ResultWithError };
 use crate::interpreter::environment::Environment;
+use crate::interpreter::runtime_values::{ GcPtrVariable, GcPtrVariableExt };
+use crate::interpreter::runtime_values::functions::bound_function::BoundFunction;
 use crate::interpreter::runtime_values::functions::closure::Closure;
 use crate::interpreter::runtime_values::functions::ifunction::IFunction;
 use crate::interpreter::runtime_values::functions::native_function::NativeFunction;
@@ -12,8 +16,13 @@ use crate::interpreter::runtime_values::functions::types::{
 	FunctionParameters,
 	FunctionReturnValue,
 };
-use crate::types::cell_ref::{ gc_clone, GcPtr };
+use crate::interpreter::variables_containers::{ VariableScope, VariablesMap };
+use crate::interpreter::variables_containers::map::IVariablesMapConstMembers;
+use crate::interpreter::variables_containers::scope::GcPtrToVariableScope;
+use crate::types::cell_ref::{ gc_clone, gc_ptr_cell_from, GcPtr };
+use crate::types::traits::ConsumeOrCloneOf;
 
+pub mod bound_function;
 pub mod closure;
 pub mod types;
 pub mod ifunction;
@@ -25,6 +34,7 @@ pub type GcPtrToFunction = GcPtr<Function>;
 pub enum Function {
 	NativeFunction(NativeFunction),
 	Closure(Closure),
+	BoundFunction(BoundFunction),
 }
 
 impl Display for Function {
@@ -42,16 +52,117 @@ impl Display for Function {
 				res
 			}
 			Function::Closure(cl) => f.write_str(cl.code.name.as_str()),
+			Function::BoundFunction(bf) => std::fmt::Display::fmt(bf.function.deref(), f),
 		}
 	}
 }
 
 impl Function {
-	pub fn new_closure(env: &Environment, decl: FunctionDeclaration) -> GcPtrToFunction {
-		let closure = Closure::new(decl, gc_clone(&env.scope));
-		let function_closure = Function::Closure(closure);
-		return GcPtr::new(function_closure);
+	/// Creates a closure over the scope of `env`, or, if the function has a capture list, over just
+	/// the bindings it captures and the builtins.
+	pub fn new_closure(
+		env: &mut Environment,
+		decl: FunctionDeclaration
+	) -> ResultWithError<GcPtrToFunction> {
+		let scope = closure_scope(env, &decl)?;
+		return Ok(GcPtr::new(Function::Closure(Closure::new(decl, scope))));
+	}
+
+	#[inline(always)]
+	pub fn new_bound(function: GcPtrToFunction, bound_args: FunctionParameters) -> GcPtrToFunction {
+		return GcPtr::new(Function::BoundFunction(BoundFunction::new(function, bound_args)));
+	}
+
+	/// Whether `a` and `b` are the same function. A method read off of an object is bound anew on
+	/// every read, so bound functions are the same when they bind equal arguments to the same
+	/// function.
+	pub fn is_same_function(a: &GcPtrToFunction, b: &GcPtrToFunction) -> bool {
+		if GcPtr::ptr_eq(a, b) {
+			return true;
+		}
+		return match (a.deref(), b.deref()) {
+			(Function::BoundFunction(a), Function::BoundFunction(b)) =>
+				Self::is_same_function(&a.function, &b.function) && a.bound_args == b.bound_args,
+			_ => false,
+		};
 	}
+
+	/// The largest number of arguments that the function takes, or `None` if it takes any number.
+	pub fn max_arguments(&self) -> Option<usize> {
+		return match self {
+			Function::NativeFunction(_) => None,
+			Function::Closure(cl) => cl.max_arguments(),
+			Function::BoundFunction(bf) =>
+				bf.function.max_arguments().map(|v| v.saturating_sub(bf.bound_args.len())),
+		};
+	}
+
+	/// Calls the function as a callback of a native function, which passes extra arguments, like
+	/// the index of an item next to the item, that callbacks are free to leave out.
+	pub fn execute_callback(
+		&self,
+		env: &mut Environment,
+		mut params: FunctionParameters
+	) -> ResultWithError<FunctionReturnValue> {
+		if let Some(max_arguments) = self.max_arguments() {
+			params.truncate(max_arguments);
+		}
+		return self.execute(env, params);
+	}
+
+	/// Creates a closure for a method of a class, see [`Closure::new_method`].
+	pub fn new_method(
+		env: &mut Environment,
+		decl: FunctionDeclaration
+	) -> ResultWithError<GcPtrToFunction> {
+		let scope = closure_scope(env, &decl)?;
+		return Ok(GcPtr::new(Function::Closure(Closure::new_method(decl, scope))));
+	}
+}
+
+fn closure_scope(
+	env: &mut Environment,
+	decl: &FunctionDeclaration
+) -> ResultWithError<GcPtrToVariableScope> {
+	return Ok(match &decl.captures {
+		None => gc_clone(&env.scope),
+		Some(captures) => {
+			let mut variables = VariablesMap::new();
+			for capture in captures.iter() {
+				let variable = match capture {
+					CaptureDeclaration::ByValue(name) => {
+						let variable = captured_variable(env, name)?;
+						if variable.is_hoisted() {
+							return Err(ErrorT::CantAccessHoistedVariable(name.clone()).into());
+						}
+						let value = variable.borrow().try_clone_err()?;
+						gc_ptr_cell_from(value)
+					}
+					CaptureDeclaration::ByReference(name) => {
+						// Constants captured by reference stay constants inside the closure
+						if env.is_constant(name.into()) {
+							variables.constants.insert(name.clone());
+						}
+						captured_variable(env, name)?
+					}
+					CaptureDeclaration::Initialized(_, expr) => {
+						gc_ptr_cell_from(env.eval(expr)?.consume_or_clone()?)
+					}
+				};
+				variables.variables.insert(capture.identifier().clone(), variable);
+			}
+			let builtins = gc_clone(&env.global_scope.borrow().builtins);
+			VariableScope::new_gc_for_captures(variables, builtins)
+		}
+	});
+}
+
+/// Captures only refer to variables that exist, rather than declaring the missing ones.
+fn captured_variable(env: &Environment, name: &IdentifierT) -> ResultWithError<GcPtrVariable> {
+	return env
+		.get_actual(name.into())
+		.map(|variable| variable.into_owned())
+		.ok_or_else(|| ErrorT::UnknownCapturedVariable(name.clone()).into());
 }
 
 impl IFunction for Function {
@@ -64,6 +175,7 @@ impl IFunction for Function {
 		return match self {
 			Function::Closure(cl) => cl.execute(env, params),
 			Function::NativeFunction(f) => f.execute(env, params),
+			Function::BoundFunction(bf) => bf.execute(env, params),
 		};
 	}
 }
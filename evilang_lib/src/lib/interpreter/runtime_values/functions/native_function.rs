@@ -13,11 +13,17 @@ pub type NativeFunctionFn = fn(
 	params: FunctionParameters
 ) -> ResultWithError<FunctionReturnValue>;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct NativeFunction {
 	pub f: NativeFunctionFn,
 }
 
+impl PartialEq for NativeFunction {
+	fn eq(&self, other: &Self) -> bool {
+		return std::ptr::fn_addr_eq(self.f, other.f);
+	}
+}
+
 impl Finalize for NativeFunction {}
 
 unsafe impl Trace for NativeFunction {
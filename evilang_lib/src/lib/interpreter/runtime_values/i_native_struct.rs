@@ -88,11 +88,12 @@ pub fn native_wrap<TSelf: INativeStruct>(
 	object: &GcPtrToObject,
 	box_wrap_name: CowStringT,
 	val: TSelf
-) {
+) -> ResultWithError<()> {
 	let gc_ptr_cell = gc_ptr_cell_from(val);
 	let cell_ptr = GcPtr::into_raw(gc_ptr_cell);
 	let new_gc = unsafe { GcPtrToNativeStruct::from_raw(cell_ptr) };
-	object.assign_locally(box_wrap_name, PrimitiveValue::NativeStruct(new_gc));
+	object.assign_locally(box_wrap_name, PrimitiveValue::NativeStruct(new_gc))?;
+	return Ok(());
 }
 
 pub fn native_unwrap_exec_fn<TSelf: INativeStruct, TExecFn, TExecFnRes, TNameFn>(
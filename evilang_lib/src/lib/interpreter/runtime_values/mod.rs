@@ -110,7 +110,7 @@ impl PartialEq for PrimitiveValue {
 			(PrimitiveValue::Number(self_0), PrimitiveValue::Number(othr_0)) => *self_0 == *othr_0,
 			(PrimitiveValue::String(self_0), PrimitiveValue::String(othr_0)) => *self_0 == *othr_0,
 			(PrimitiveValue::Function(self_0), PrimitiveValue::Function(othr_0)) =>
-				GcPtr::ptr_eq(self_0, othr_0),
+				Function::is_same_function(self_0, othr_0),
 			(PrimitiveValue::Object(self_0), PrimitiveValue::Object(othr_0)) =>
 				GcPtr::ptr_eq(self_0, othr_0),
 			(PrimitiveValue::NativeStruct(self_0), PrimitiveValue::NativeStruct(othr_0)) =>
@@ -152,6 +152,11 @@ impl PrimitiveValue {
 		return self == &PrimitiveValue::_HoistedVariable;
 	}
 
+	#[inline(always)]
+	pub fn is_null(&self) -> bool {
+		return matches!(self, PrimitiveValue::Null);
+	}
+
 	#[inline(always)]
 	pub fn try_clone_err(&self) -> ResultWithError<PrimitiveValue> {
 		return Ok(self.clone());
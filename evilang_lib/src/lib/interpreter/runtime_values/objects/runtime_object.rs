@@ -7,7 +7,7 @@ use crate::ast::structs::{ CallExpression, ClassDeclaration };
 use crate::errors::{ Descriptor, ResultWithError, RuntimeError };
 use crate::interpreter::environment::Environment;
 use crate::interpreter::environment::native_items::classes::object::ObjectSuperclass;
-use crate::interpreter::runtime_values::functions::Function;
+use crate::interpreter::runtime_values::functions::{ Function, GcPtrToFunction };
 use crate::interpreter::runtime_values::functions::ifunction::IFunction;
 use crate::interpreter::runtime_values::functions::types::{
 	FunctionParameters,
@@ -15,7 +15,6 @@ use crate::interpreter::runtime_values::functions::types::{
 };
 use crate::interpreter::runtime_values::i_native_struct::INativeClass_GetClassCached;
 use crate::interpreter::runtime_values::PrimitiveValue;
-use crate::interpreter::runtime_values::ref_to_value::RefToValue;
 use crate::interpreter::utils::expect_object;
 use crate::interpreter::variables_containers::map::{
 	GcPtrMutCellToVariablesMap,
@@ -25,9 +24,8 @@ use crate::interpreter::variables_containers::map::{
 use crate::interpreter::variables_containers::scope::IGenericVariablesScope;
 use crate::interpreter::variables_containers::VariablesMap;
 use crate::types::cell_ref::{ gc_clone, gc_ptr_cell_from, GcPtr };
-use crate::types::consts::{ INSTANCE_OF_, SUPER };
+use crate::types::consts::{ GETTER_PREFIX, HIDDEN_PREFIX, INSTANCE_OF_, SETTER_PREFIX, SUPER };
 use crate::types::string::{ CowStringT, StringT };
-use crate::types::traits::ConsumeOrCloneOf;
 
 pub type GcPtrToObject = GcPtr<RuntimeObject>;
 
@@ -57,27 +55,40 @@ impl RuntimeObject {
 		return Self::new_gc(VariablesMap::new(), Some(parent), instance_name);
 	}
 
+	/// Whether the object was allocated as an instance of its class, rather than being a class or
+	/// namespace itself.
+	#[inline(always)]
+	pub fn is_instance(&self) -> bool {
+		return self.name.starts_with(INSTANCE_OF_);
+	}
+
 	pub fn new_class_decl(
 		env: &mut Environment,
 		decl: &ClassDeclaration
 	) -> ResultWithError<GcPtrToObject> {
-		let ClassDeclaration { name, super_class, methods } = decl;
+		let ClassDeclaration { name, super_class, methods, getters, setters, .. } = decl;
 		let super_class = if let Some(v) = super_class {
 			expect_object(env.eval(v)?, Some(v))?
 		} else {
 			ObjectSuperclass::get_class_cached(env)?
 		};
-		let scope = Environment::new_with_parent(env)?;
+		let mut scope = Environment::new_with_parent(env)?;
 		scope.declare(SUPER.into(), PrimitiveValue::Object(gc_clone(&super_class)))?;
+		let accessors = getters
+			.iter()
+			.map(|fdecl| (GETTER_PREFIX, fdecl))
+			.chain(setters.iter().map(|fdecl| (SETTER_PREFIX, fdecl)));
 		let sub_class = RuntimeObject::new_gc(
 			VariablesMap::new_direct(
 				methods
-					.clone()
-					.into_iter()
-					.map(|fdecl| {
-						(fdecl.name.clone(), gc_ptr_cell_from(Function::new_closure(&scope, fdecl).into()))
+					.iter()
+					.map(|fdecl| (fdecl.name.clone(), fdecl))
+					.chain(accessors.map(|(prefix, fdecl)| (Self::accessor_key(prefix, &fdecl.name), fdecl)))
+					.map(|(key, fdecl)| {
+						let method = Function::new_method(&mut scope, fdecl.clone())?;
+						Ok((key, gc_ptr_cell_from(method.into())))
 					})
-					.collect()
+					.collect::<ResultWithError<_>>()?
 			),
 			Some(super_class),
 			name.clone()
@@ -85,30 +96,134 @@ impl RuntimeObject {
 		return Ok(sub_class);
 	}
 
+	#[inline(always)]
 	pub fn call_method_on_object_with_args(
 		this: GcPtrToObject,
 		env: &mut Environment,
 		method_name: CowStringT,
 		call_expr: &CallExpression
 	) -> ResultWithError<FunctionReturnValue> {
-		let Some(method_prop_box) = this.get_actual(method_name).map(MaybeOwned::into_owned) else {
-			return Err(
-				RuntimeError::ExpectedFunction(Descriptor::Expression((*call_expr.callee).clone())).into()
-			);
+		return Self::call_method_of_class_with_args(
+			gc_clone(&this),
+			Some(PrimitiveValue::Object(this)),
+			env,
+			method_name,
+			call_expr
+		);
+	}
+
+	/// Calls the method `method_name` found through `class` with `this` being another value, or
+	/// with only the arguments of the call if there is no `this`, for calls like
+	/// `super.method(args)`.
+	pub fn call_method_of_class_with_args(
+		class: GcPtrToObject,
+		this: Option<PrimitiveValue>,
+		env: &mut Environment,
+		method_name: CowStringT,
+		call_expr: &CallExpression
+	) -> ResultWithError<FunctionReturnValue> {
+		let method = Self::find_method(&class, method_name, |value| match value {
+			Some(value) => Descriptor::new_both(value.into(), call_expr.callee.deref().into()),
+			None => Descriptor::Expression((*call_expr.callee).clone()),
+		})?;
+		let mut args_with_this = this.into_iter().collect::<FunctionParameters>();
+		args_with_this.extend(env.eval_arguments(&call_expr.arguments)?);
+		return method.execute(env, args_with_this);
+	}
+
+	/// Calls the method `method_name` of `this` with already evaluated arguments, for calls made by
+	/// the interpreter itself rather than by a call expression, such as those of the iterator
+	/// protocol and of operator overloading. Like any method call it passes `this` as the first
+	/// argument, so functions stored on plain objects have to take it as their first parameter,
+	/// as in `{ next: fn(self) { ... } }`.
+	pub fn call_method_on_object(
+		this: GcPtrToObject,
+		env: &mut Environment,
+		method_name: CowStringT,
+		args: FunctionParameters
+	) -> ResultWithError<FunctionReturnValue> {
+		let name = method_name.to_string();
+		let method = Self::find_method(&this, method_name, |value| match value {
+			Some(value) => Descriptor::NameAndValue { name, value },
+			None => Descriptor::Name(name),
+		})?;
+		let mut args_with_this = vec![PrimitiveValue::Object(this)];
+		args_with_this.extend(args);
+		return method.execute(env, args_with_this);
+	}
+
+	/// The function named `method_name` found through `object`, with `describe` describing what was
+	/// called, given the value found if it isn't a function, when there is no such function.
+	fn find_method(
+		object: &GcPtrToObject,
+		method_name: CowStringT,
+		describe: impl FnOnce(Option<PrimitiveValue>) -> Descriptor
+	) -> ResultWithError<GcPtrToFunction> {
+		let Some(method_prop_box) = object.get_actual(method_name).map(MaybeOwned::into_owned) else {
+			return Err(RuntimeError::ExpectedFunction(describe(None)).into());
 		};
 		let method_prop = method_prop_box.borrow();
 		let PrimitiveValue::Function(ref method) = method_prop.deref() else {
-			return Err(
-				RuntimeError::ExpectedFunction(
-					Descriptor::new_both(method_prop.deref().into(), call_expr.callee.deref().into())
-				).into()
-			);
+			let value = method_prop.deref().clone__silently_fail();
+			return Err(RuntimeError::ExpectedFunction(describe(Some(value))).into());
 		};
-		let args_with_this = Some(Ok(PrimitiveValue::Object(this)) as ResultWithError<PrimitiveValue>)
-			.into_iter()
-			.chain(call_expr.arguments.iter().map(|v| env.eval(v).and_then(RefToValue::consume_or_clone)))
-			.collect::<ResultWithError<FunctionParameters>>()?;
-		return method.execute(env, args_with_this);
+		return Ok(gc_clone(method));
+	}
+
+	/// Whether `this` or one of its ancestors has a function named `method_name`.
+	pub fn has_method(this: &GcPtrToObject, method_name: CowStringT) -> bool {
+		return this
+			.get_actual(method_name)
+			.map(|method| matches!(method.borrow().deref(), PrimitiveValue::Function(_)))
+			.unwrap_or(false);
+	}
+
+	/// The name of the hidden property that the getter or setter, depending on `prefix`, of
+	/// `property_name` is stored as in the class.
+	#[inline(always)]
+	pub fn accessor_key(prefix: &str, property_name: &str) -> StringT {
+		return prefix.to_string() + property_name;
+	}
+
+	/// The getter or setter of `property_name` found through the class of `this`. Properties of
+	/// the object itself take precedence over the accessors of its class.
+	pub fn find_accessor(
+		this: &GcPtrToObject,
+		prefix: &str,
+		property_name: &str
+	) -> Option<GcPtrToFunction> {
+		if this.properties.borrow().contains_key(property_name.into()) {
+			return None;
+		}
+		let accessor = this.parent
+			.as_ref()?
+			.get_actual(Self::accessor_key(prefix, property_name).into())?
+			.into_owned();
+		let accessor_ref = accessor.borrow();
+		return match accessor_ref.deref() {
+			PrimitiveValue::Function(function) => Some(gc_clone(function)),
+			_ => None,
+		};
+	}
+
+	/// The names of the properties of the object itself in sorted order, leaving out those of its
+	/// class and the internal ones.
+	pub fn own_property_names(&self) -> Vec<StringT> {
+		let mut names = self.properties
+			.borrow()
+			.variables
+			.iter()
+			.filter(|(name, value)| {
+				!name.starts_with(HIDDEN_PREFIX) &&
+					!matches!(
+						value.borrow().deref(),
+						PrimitiveValue::NativeStruct(_) | PrimitiveValue::_HoistedVariable
+					)
+			})
+			.map(|(name, _)| name.clone())
+			.collect::<Vec<_>>();
+		names.sort();
+		return names;
 	}
 }
 
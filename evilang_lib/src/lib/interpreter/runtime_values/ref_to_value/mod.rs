@@ -5,15 +5,19 @@ use delegate::delegate;
 use maybe_owned::MaybeOwned;
 
 use crate::ast::expression::IdentifierT;
-use crate::errors::{ ErrorT, ResultWithError };
+use crate::errors::{ ErrorT, ResultWithError, RuntimeError };
+use crate::interpreter::environment::Environment;
 use crate::interpreter::runtime_values::{ GcPtrVariable, PrimitiveValue };
-use crate::interpreter::runtime_values::objects::runtime_object::GcPtrToObject;
+use crate::interpreter::runtime_values::functions::Function;
+use crate::interpreter::runtime_values::functions::ifunction::IFunction;
+use crate::interpreter::runtime_values::objects::runtime_object::{ GcPtrToObject, RuntimeObject };
 pub use crate::interpreter::runtime_values::ref_to_value::deref_of_ref_to_value::DerefOfRefToValue;
 use crate::interpreter::variables_containers::map::{
 	IVariablesMapConstMembers,
 	IVariablesMapDelegator,
 };
-use crate::types::cell_ref::gc_ptr_cell_from;
+use crate::types::cell_ref::{ gc_clone, gc_ptr_cell_from };
+use crate::types::consts::{ GETTER_PREFIX, SETTER_PREFIX };
 use crate::types::traits::ConsumeOrCloneOf;
 
 pub mod deref_of_ref_to_value;
@@ -22,6 +26,11 @@ pub mod deref_of_ref_to_value;
 pub enum RefToValue {
 	Value(PrimitiveValue),
 	Variable(GcPtrVariable),
+	/// A variable declared as a constant, which can be read but not set.
+	Constant {
+		name: IdentifierT,
+		value: GcPtrVariable,
+	},
 	ObjectProperty {
 		object: GcPtrToObject,
 		property_name: IdentifierT,
@@ -51,8 +60,9 @@ impl ConsumeOrCloneOf for RefToValue {
 		return match self {
 			RefToValue::Value(v) => Ok(v),
 			RefToValue::ObjectProperty { snapshot: None, .. } => Ok(PrimitiveValue::Null),
-			RefToValue::ObjectProperty { snapshot: Some(v), .. } | RefToValue::Variable(v) =>
-				v.borrow().try_clone_err(),
+			| RefToValue::ObjectProperty { snapshot: Some(v), .. }
+			| RefToValue::Variable(v)
+			| RefToValue::Constant { value: v, .. } => v.borrow().try_clone_err(),
 		};
 	}
 }
@@ -72,18 +82,85 @@ impl RefToValue {
 		}
 	}
 
-	pub fn set(&mut self, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>> {
+	/// Methods found through the parent chain of instances are read bound to them, so that
+	/// `let f = p.calc; f();` still calls `calc` with `p` as `this`.
+	fn bind_inherited_method(self) -> Self {
+		return match self {
+			RefToValue::ObjectProperty { object, property_name, snapshot: Some(value) } => {
+				let function = match value.borrow().deref() {
+					PrimitiveValue::Function(function) if
+						object.is_instance() &&
+						!object.properties.borrow().contains_key(property_name.deref().into())
+					=> Some(gc_clone(function)),
+					_ => None,
+				};
+				let snapshot = match function {
+					Some(function) => {
+						let bound_args = vec![PrimitiveValue::Object(gc_clone(&object))];
+						gc_ptr_cell_from(PrimitiveValue::Function(Function::new_bound(function, bound_args)))
+					}
+					None => value,
+				};
+				RefToValue::ObjectProperty { object, property_name, snapshot: Some(snapshot) }
+			}
+			other => other,
+		};
+	}
+
+	/// Reads the property `property_name` of `object` with `.`, calling its getter if the class
+	/// of the object has one for it.
+	pub fn get_object_property(
+		env: &mut Environment,
+		object: GcPtrToObject,
+		property_name: IdentifierT
+	) -> ResultWithError<Self> {
+		return Ok(Self::get_object_member(env, object, property_name)?.bind_inherited_method());
+	}
+
+	/// Reads the member `property_name` of `object` with `::`, which unlike `.` never binds the
+	/// methods read.
+	pub fn get_object_member(
+		env: &mut Environment,
+		object: GcPtrToObject,
+		property_name: IdentifierT
+	) -> ResultWithError<Self> {
+		let Some(getter) = RuntimeObject::find_accessor(&object, GETTER_PREFIX, &property_name) else {
+			return Ok(Self::new_object_property_ref(object, property_name));
+		};
+		let value = getter.execute(env, vec![PrimitiveValue::Object(gc_clone(&object))])?;
+		return Ok(RefToValue::ObjectProperty {
+			object,
+			property_name,
+			snapshot: Some(gc_ptr_cell_from(value)),
+		});
+	}
+
+	/// Assigns `value` to what is referred to, properties with a setter in the class of their
+	/// object are assigned to by calling it.
+	pub fn set(
+		&mut self,
+		env: &mut Environment,
+		value: PrimitiveValue
+	) -> ResultWithError<Option<PrimitiveValue>> {
 		return match self {
 			RefToValue::Value(_v) => Err(ErrorT::ExpectedLhsExpression.into()),
 			RefToValue::Variable(v) => { Ok(Some(replace(v.borrow_mut().deref_mut(), value))) }
+			RefToValue::Constant { name, .. } => Err(ErrorT::AssignmentToConstant(name.clone()).into()),
 			RefToValue::ObjectProperty { object, property_name, snapshot: _ } => {
-				Ok(object.assign_locally(property_name.as_str().into(), value))
+				if let Some(setter) = RuntimeObject::find_accessor(object, SETTER_PREFIX, property_name) {
+					setter.execute(env, vec![PrimitiveValue::Object(gc_clone(object)), value])?;
+					return Ok(None);
+				}
+				if RuntimeObject::find_accessor(object, GETTER_PREFIX, property_name).is_some() {
+					return Err(RuntimeError::PropertyWithoutSetter(property_name.clone()).into());
+				}
+				object.assign_locally(property_name.as_str().into(), value)
 			}
 		};
 	}
 
 	#[inline(always)]
-	pub fn borrow(&self) -> DerefOfRefToValue {
+	pub fn borrow(&self) -> DerefOfRefToValue<'_> {
 		/*
 		return match self {
 			RefToValue::Value(v) |
@@ -97,9 +174,9 @@ impl RefToValue {
 			RefToValue::Value(v) => DerefOfRefToValue::DerefRValue(v),
 			RefToValue::ObjectProperty { snapshot: None, .. } =>
 				DerefOfRefToValue::Value(PrimitiveValue::Null),
-			RefToValue::ObjectProperty { snapshot: Some(v), .. } | RefToValue::Variable(v) => {
-				DerefOfRefToValue::DerefLValue(v.borrow())
-			}
+			| RefToValue::ObjectProperty { snapshot: Some(v), .. }
+			| RefToValue::Variable(v)
+			| RefToValue::Constant { value: v, .. } => DerefOfRefToValue::DerefLValue(v.borrow()),
 		};
 	}
 
@@ -117,11 +194,14 @@ impl RefToValue {
 		to match self {
 			RefToValue::Value(v) => v,
 			RefToValue::ObjectProperty { snapshot: None, .. } => PrimitiveValue::Null,
-			RefToValue::ObjectProperty { snapshot: Some(v), .. } | RefToValue::Variable(v) => v.borrow(),
+			| RefToValue::ObjectProperty { snapshot: Some(v), .. }
+			| RefToValue::Variable(v)
+			| RefToValue::Constant { value: v, .. } => v.borrow(),
 		}
 		{
 			pub fn is_truthy(&self) -> bool;
 			pub fn is_hoisted(&self) -> bool;
+			pub fn is_null(&self) -> bool;
 		}
 	}
 }
@@ -51,7 +51,7 @@ pub fn expect_object_or_set_object_if_null<T>(
 				Some(ObjectSuperclass::get_class_cached(env)?),
 				object_name.into()
 			);
-			object.set(PrimitiveValue::Object(gc_clone(&obj)))?;
+			object.set(env, PrimitiveValue::Object(gc_clone(&obj)))?;
 			obj
 		} else {
 			gc_clone(expect_object_fn(object.borrow().deref(), expr_fn)?)
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{ HashMap, HashSet };
 use std::mem::replace;
 use std::ops::{ Deref, DerefMut };
 
@@ -12,8 +12,9 @@ use crate::types::cell_ref::{ gc_ptr_cell_from, GcPtr, GcPtrCell };
 use crate::types::string::CowStringT;
 
 pub trait IVariablesMapConstMembers {
-	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<GcPtrVariable>>;
+	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<'_, GcPtrVariable>>;
 	fn contains_key(&self, name: CowStringT) -> bool;
+	fn is_constant(&self, name: CowStringT) -> bool;
 }
 
 pub trait IVariablesMap: IVariablesMapConstMembers {
@@ -24,16 +25,19 @@ pub trait IVariablesMap: IVariablesMapConstMembers {
 	/// * `name`:
 	/// * `value`:
 	///
-	/// returns: Option<PrimitiveValue> The previous value stored in the variable
-	fn assign(&mut self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue>;
+	/// returns: Option<PrimitiveValue> The previous value stored in the variable, or an error if
+	/// the variable is a constant
+	fn assign(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>>;
 	fn declare(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
+	fn declare_constant(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
 	fn hoist(&mut self, name: CowStringT) -> ResultWithError<()>;
 }
 
 pub trait IVariablesMapDelegator: IVariablesMapConstMembers {
-	fn assign_locally(&self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue>;
-	fn assign(&self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue>;
+	fn assign_locally(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>>;
+	fn assign(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>>;
 	fn declare(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
+	fn declare_constant(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
 	fn hoist(&self, name: CowStringT) -> ResultWithError<()>;
 }
 
@@ -45,21 +49,25 @@ macro_rules! delegate_ivariables_map {
 	) => {
 		impl IVariablesMapConstMembers for $for_type {
 			#[inline(always)]
-			fn get_actual(&$self, name: CowStringT) -> Option<::maybe_owned::MaybeOwned<GcPtrVariable>> {
+			fn get_actual(&$self, name: CowStringT) -> Option<::maybe_owned::MaybeOwned<'_, GcPtrVariable>> {
 				return $const_delegator.get_actual(name);
 			}
 			#[inline(always)]
 			fn contains_key(&$self, name: CowStringT) -> bool {
 				return $const_delegator.contains_key(name);
 			}
+			#[inline(always)]
+			fn is_constant(&$self, name: CowStringT) -> bool {
+				return $const_delegator.is_constant(name);
+			}
 		}
 		impl IVariablesMapDelegator for $for_type {
 			#[inline(always)]
-			fn assign(&$mut_self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue> {
+			fn assign(&$mut_self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>> {
 				return $mut_delegator.assign(name, value);
 			}
 			#[inline(always)]
-			fn assign_locally(&$mut_self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue> {
+			fn assign_locally(&$mut_self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>> {
 				return $mut_delegator.assign_locally(name, value);
 			}
 			#[inline(always)]
@@ -67,6 +75,10 @@ macro_rules! delegate_ivariables_map {
 				return $mut_delegator.declare(name, value);
 			}
 			#[inline(always)]
+			fn declare_constant(&$mut_self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()> {
+				return $mut_delegator.declare_constant(name, value);
+			}
+			#[inline(always)]
 			fn hoist(&$mut_self, name: CowStringT) -> ResultWithError<()> {
 				return $mut_delegator.hoist(name);
 			}
@@ -78,9 +90,13 @@ pub use delegate_ivariables_map;
 
 pub type GcPtrMutCellToVariablesMap = GcPtr<GcPtrCell<VariablesMap>>;
 
-#[derive(Debug, PartialEq, Trace, Finalize)]
+/// Clones share the variables, rather than copying their values.
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct VariablesMap {
 	pub variables: HashMap<IdentifierT, GcPtrVariable>,
+	/// The names of the variables that can't be assigned to after being declared.
+	#[unsafe_ignore_trace]
+	pub constants: HashSet<IdentifierT>,
 }
 
 impl Default for VariablesMap {
@@ -92,11 +108,16 @@ impl Default for VariablesMap {
 impl VariablesMap {
 	#[inline(always)]
 	pub fn new() -> Self {
-		Self { variables: HashMap::new() }
+		Self { variables: HashMap::new(), constants: HashSet::new() }
 	}
 	#[inline(always)]
 	pub fn new_direct(variables: HashMap<IdentifierT, GcPtrVariable>) -> Self {
-		Self { variables }
+		Self { variables, constants: HashSet::new() }
+	}
+	/// A map where all of `variables` are constants.
+	pub fn new_direct_constants(variables: HashMap<IdentifierT, GcPtrVariable>) -> Self {
+		let constants = variables.keys().cloned().collect();
+		Self { variables, constants }
 	}
 	pub fn new_from_primitives(variables: HashMap<IdentifierT, PrimitiveValue>) -> Self {
 		Self {
@@ -104,12 +125,13 @@ impl VariablesMap {
 				.into_iter()
 				.map(|(iden, val)| (iden, gc_ptr_cell_from(val)))
 				.collect(),
+			constants: HashSet::new(),
 		}
 	}
 }
 
 impl IVariablesMapConstMembers for VariablesMap {
-	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<GcPtrVariable>> {
+	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<'_, GcPtrVariable>> {
 		return self.variables.get(name.deref()).map(|v| v.into());
 	}
 
@@ -117,17 +139,27 @@ impl IVariablesMapConstMembers for VariablesMap {
 	fn contains_key(&self, name: CowStringT) -> bool {
 		self.variables.contains_key(name.deref())
 	}
+
+	#[inline(always)]
+	fn is_constant(&self, name: CowStringT) -> bool {
+		self.constants.contains(name.deref())
+	}
 }
 
 impl IVariablesMap for VariablesMap {
-	fn assign(&mut self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue> {
-		return if let Some(v) = self.variables.get(name.deref()) {
-			let mut res = v.borrow_mut();
-			Some(replace(res.deref_mut(), value))
-		} else {
-			self.variables.insert(name.into(), gc_ptr_cell_from(value));
-			None
-		};
+	fn assign(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>> {
+		if self.constants.contains(name.deref()) {
+			return Err(ErrorT::AssignmentToConstant(name.into()).into());
+		}
+		return Ok(
+			if let Some(v) = self.variables.get(name.deref()) {
+				let mut res = v.borrow_mut();
+				Some(replace(res.deref_mut(), value))
+			} else {
+				self.variables.insert(name.into(), gc_ptr_cell_from(value));
+				None
+			}
+		);
 	}
 
 	fn declare(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()> {
@@ -139,7 +171,14 @@ impl IVariablesMap for VariablesMap {
 		if value.is_hoisted() {
 			return Err(ErrorT::CantSetToHoistedValue.into());
 		}
-		self.assign(name, value);
+		self.assign(name, value)?;
+		return Ok(());
+	}
+
+	fn declare_constant(&mut self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()> {
+		let constant_name = IdentifierT::from(name.deref());
+		self.declare(name, value)?;
+		self.constants.insert(constant_name);
 		return Ok(());
 	}
 
@@ -147,7 +186,7 @@ impl IVariablesMap for VariablesMap {
 		if let Some(_v) = self.variables.get(name.deref()) {
 			return Err(ErrorT::CantRedeclareVariable(name.into()).into());
 		}
-		self.assign(name, PrimitiveValue::_HoistedVariable);
+		self.assign(name, PrimitiveValue::_HoistedVariable)?;
 		return Ok(());
 	}
 }
@@ -22,6 +22,11 @@ pub type GcPtrMutCellToGlobalScope = GcPtr<GcPtrCell<GlobalScope>>;
 
 #[derive(PartialEq, Trace, Finalize)]
 pub struct GlobalScope {
+	/// The native functions and classes, which are the only variables that closures with a capture
+	/// list can refer to without capturing them.
+	pub builtins: GcPtrToVariableScope,
+	/// The top-level scope of the program, which shares the variables of `builtins` so that they
+	/// can't be redeclared.
 	pub scope: GcPtrToVariableScope,
 	pub res_stack: Vec<PrimitiveValue>,
 	pub resolver: BoxIResolver,
@@ -33,6 +38,7 @@ impl GlobalScope {
 		resolver: BoxIResolver
 	) -> GcPtrMutCellToGlobalScope {
 		gc_ptr_cell_from(GlobalScope {
+			builtins: VariableScope::new_gc_from_map(variables.clone(), None),
 			scope: VariableScope::new_gc_from_map(variables, None),
 			res_stack: Vec::new(),
 			resolver,
@@ -39,7 +39,7 @@ pub trait IGenericVariablesScope<T: IGenericVariablesScope<T> + 'static>: Trace
 }
 
 impl<T: IGenericVariablesScope<T> + 'static> IVariablesMapConstMembers for T {
-	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<GcPtrVariable>> {
+	fn get_actual(&self, name: CowStringT) -> Option<MaybeOwned<'_, GcPtrVariable>> {
 		self
 			.resolve_variable_scope(name.deref().into())
 			.borrow()
@@ -50,6 +50,7 @@ impl<T: IGenericVariablesScope<T> + 'static> IVariablesMapConstMembers for T {
 	delegate! {
 		to self.resolve_variable_scope(name.deref().into()).borrow() {
 			fn contains_key(&self, name: CowStringT) -> bool;
+			fn is_constant(&self, name: CowStringT) -> bool;
 		}
 	}
 }
@@ -57,12 +58,13 @@ impl<T: IGenericVariablesScope<T> + 'static> IVariablesMapConstMembers for T {
 impl<T: IGenericVariablesScope<T> + 'static> IVariablesMapDelegator for T {
 	delegate! {
 		to self.resolve_variable_scope(name.deref().into()).borrow_mut() {
-			fn assign(&self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue>;
+			fn assign(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>>;
 		}
 		to self.get_variables().borrow_mut() {
 			#[call(assign)]
-			fn assign_locally(&self, name: CowStringT, value: PrimitiveValue) -> Option<PrimitiveValue>;
+			fn assign_locally(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<Option<PrimitiveValue>>;
 			fn declare(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
+			fn declare_constant(&self, name: CowStringT, value: PrimitiveValue) -> ResultWithError<()>;
 			fn hoist(&self, name: CowStringT) -> ResultWithError<()>;
 		}
 	}
@@ -74,6 +76,9 @@ pub type GcPtrToVariableScope = GcPtr<VariableScope>;
 pub struct VariableScope {
 	pub variables: GcPtrMutCellToVariablesMap,
 	pub parent: Option<GcPtrToVariableScope>,
+	/// Whether this scope holds the bindings captured by a closure with a `captures` list, the
+	/// variables it doesn't hold are only looked up in the global scope.
+	pub is_capture_list: bool,
 }
 
 impl IGenericVariablesScope<VariableScope> for VariableScope {
@@ -94,7 +99,7 @@ impl VariableScope {
 		variables: GcPtrMutCellToVariablesMap,
 		parent: Option<GcPtrToVariableScope>
 	) -> GcPtrToVariableScope {
-		GcPtr::new(Self { variables, parent })
+		GcPtr::new(Self { variables, parent, is_capture_list: false })
 	}
 
 	pub fn new_gc_from_map(
@@ -104,6 +109,33 @@ impl VariableScope {
 		GcPtr::new(Self {
 			variables: gc_ptr_cell_from(variables),
 			parent,
+			is_capture_list: false,
 		})
 	}
+
+	pub fn new_gc_for_captures(
+		variables: VariablesMap,
+		builtins: GcPtrToVariableScope
+	) -> GcPtrToVariableScope {
+		GcPtr::new(Self {
+			variables: gc_ptr_cell_from(variables),
+			parent: Some(builtins),
+			is_capture_list: true,
+		})
+	}
+
+	/// Whether this scope is, or is nested inside, the capture list of a closure.
+	pub fn is_inside_capture_list(&self) -> bool {
+		if self.is_capture_list {
+			return true;
+		}
+		let mut parent_opt = self.parent.clone();
+		while let Some(parent) = parent_opt {
+			if parent.is_capture_list {
+				return true;
+			}
+			parent_opt = parent.parent.clone();
+		}
+		return false;
+	}
 }
@@ -2,6 +2,7 @@
 #![allow(clippy::needless_return)]
 
 pub mod ast;
+pub mod diagnostics;
 pub mod errors;
 pub mod interpreter;
 pub mod parser;
@@ -1,20 +1,28 @@
-use std::iter::Peekable;
+use itertools::{ peek_nth, PeekNth };
 
 use crate::ast::{
-	expression::{ BoxExpression, Expression },
+	expression::{ BoxExpression, Expression, ExpressionKind },
 	operator::Operator,
-	statement::{ Statement, StatementList },
+	statement::{ Statement, StatementKind, StatementList },
 };
-use crate::ast::expression::{ DottedIdentifiers, IdentifierT };
+use crate::ast::expression::{ DottedIdentifiers, IdentifierT, MemberIndexer };
+use crate::ast::pattern::{ Pattern, PatternElement, PropertyPattern };
 use crate::ast::statement::BoxStatement;
 use crate::ast::structs::{
+	CaptureDeclaration,
+	CatchClause,
 	ClassDeclaration,
 	FunctionDeclaration,
 	FunctionParameterDeclaration,
+	MatchArm,
 	VariableDeclaration,
 };
-use crate::errors::{ ensure, ErrorT, ResultWithError };
-use crate::tokenizer::{ Keyword, Token, TokenStream, TokenType };
+use crate::errors::{ ensure, ErrorT, EvilangError, ResultWithError };
+use crate::tokenizer::{ template_string, Keyword, TemplatePart, Token, TokenStream, TokenType };
+use crate::types::consts::LAMBDA;
+use crate::types::number::NumberT;
+use crate::types::source_map::add_source_file;
+use crate::types::span::Span;
 use crate::types::string::StringT;
 
 #[inline(always)]
@@ -23,8 +31,54 @@ pub fn parse(program: StringT) -> ResultWithError<StatementList> {
 	return p.program();
 }
 
+/// Parses the contents of a file, registering it in the source map so that diagnostics can refer
+/// back to its source code.
+pub fn parse_file(file_name: StringT, program: StringT) -> ResultWithError<StatementList> {
+	let file_id = add_source_file(file_name, program.clone());
+	let mut p = Parser::new(TokenStream::new_with_file_id(program, file_id));
+	return p.program();
+}
+
+#[inline(always)]
+pub fn parse_with_recovery(program: StringT) -> ParseResult {
+	let mut p = Parser::new(TokenStream::new(program));
+	return p.program_with_recovery();
+}
+
+/// Like `parse_file`, but reports every syntax error in the file instead of only the first one.
+pub fn parse_file_with_recovery(file_name: StringT, program: StringT) -> ParseResult {
+	let file_id = add_source_file(file_name, program.clone());
+	let mut p = Parser::new(TokenStream::new_with_file_id(program, file_id));
+	return p.program_with_recovery();
+}
+
+/// The statements parsed from a program along with all the syntax errors found in it.
+///
+/// Statements that could not be parsed are replaced by `StatementKind::ErrorStatement`
+/// placeholders, so the statements are only executable if there are no errors.
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+	pub statements: StatementList,
+	pub errors: Vec<EvilangError>,
+}
+
+/// A member of the body of a class declaration.
+enum ClassMember {
+	Method(FunctionDeclaration),
+	Getter(FunctionDeclaration),
+	Setter(FunctionDeclaration),
+}
+
 pub struct Parser {
-	peekable_stream: Peekable<TokenStream>,
+	peekable_stream: PeekNth<TokenStream>,
+	previous_span: Span,
+	consumed_tokens: usize,
+	errors: Vec<EvilangError>,
+	/// The labels of the loops enclosing the statement being parsed, innermost last, used to turn
+	/// `break label;` and `continue label;` into the number of loops to unroll.
+	loop_labels: Vec<Option<IdentifierT>>,
+	/// The label of the loop about to be parsed, set by a labeled statement.
+	next_loop_label: Option<IdentifierT>,
 }
 
 macro_rules! binary_expressions {
@@ -82,7 +136,14 @@ macro_rules! binary_expressions {
 impl Parser {
 	#[inline(always)]
 	pub fn new(stream: TokenStream) -> Parser {
-		return Parser { peekable_stream: stream.peekable() };
+		return Parser {
+			peekable_stream: peek_nth(stream),
+			previous_span: Span::default(),
+			consumed_tokens: 0,
+			errors: Vec::new(),
+			loop_labels: Vec::new(),
+			next_loop_label: None,
+		};
 	}
 
 	#[inline]
@@ -91,20 +152,33 @@ impl Parser {
 	}
 
 	#[inline]
+	fn end_of_token_stream(&self) -> EvilangError {
+		return EvilangError::new(ErrorT::EndOfTokenStream).with_span(self.previous_span);
+	}
+
 	fn eat_any(&mut self) -> ResultWithError<Token> {
-		return self.peekable_stream.next().ok_or(ErrorT::EndOfTokenStream)?;
+		let token = self.peekable_stream.next().ok_or_else(|| self.end_of_token_stream())??;
+		self.previous_span = token.span;
+		self.consumed_tokens += 1;
+		return Ok(token);
 	}
 
 	fn eat(&mut self, typ: TokenType) -> ResultWithError<Token> {
-		let token = self.peekable_stream.next().ok_or(ErrorT::EndOfTokenStream)??;
-		if token.typ != typ {
-			return Err(ErrorT::InvalidTokenType(token).into());
+		if self.lookahead_type()? != typ {
+			return self.unexpected_token();
 		}
-		return Ok(token);
+		return self.eat_any();
+	}
+
+	/// Fails on the next token, without consuming it so that error recovery can look at it.
+	#[inline]
+	fn unexpected_token<T>(&mut self) -> ResultWithError<T> {
+		return Err(ErrorT::InvalidTokenType(self.lookahead()?.clone()).into());
 	}
 
 	fn lookahead(&mut self) -> ResultWithError<&Token> {
-		return match self.peekable_stream.peek().ok_or(ErrorT::EndOfTokenStream.into()) {
+		let end_of_token_stream = self.end_of_token_stream();
+		return match self.peekable_stream.peek().ok_or(end_of_token_stream) {
 			Ok(Ok(v)) => Ok(v),
 			Ok(Err(e)) => Err(e.clone()),
 			Err(e) => Err(e),
@@ -116,13 +190,58 @@ impl Parser {
 		return Ok(self.lookahead()?.typ);
 	}
 
+	#[inline]
+	fn lookahead_span(&mut self) -> ResultWithError<Span> {
+		return Ok(self.lookahead()?.span);
+	}
+
+	/// Type of the token `n` tokens after the next one, or [`None`] if the tokens up to it can't
+	/// be lexed.
+	#[inline]
+	fn lookahead_nth_type(&mut self, n: usize) -> Option<TokenType> {
+		return match self.peekable_stream.peek_nth(n) {
+			Some(Ok(token)) => Some(token.typ),
+			_ => None,
+		};
+	}
+
+	/// The span from the start of `start` to the end of the last consumed token.
+	#[inline(always)]
+	fn span_from(&self, start: Span) -> Span {
+		return start.to(&self.previous_span);
+	}
+
+	#[inline(always)]
+	fn statement_from(&self, start: Span, kind: StatementKind) -> Statement {
+		return Statement { kind, span: self.span_from(start) };
+	}
+
+	#[inline(always)]
+	fn expression_from(&self, start: Span, kind: ExpressionKind) -> Expression {
+		return Expression { kind, span: self.span_from(start) };
+	}
+
 	/*
 	program:
 		| statement_list
 	*/
-	#[inline]
 	pub fn program(&mut self) -> ResultWithError<StatementList> {
-		return self.statement_list(None);
+		let ParseResult { statements, errors } = self.program_with_recovery();
+		return match errors.into_iter().next() {
+			None => Ok(statements),
+			Some(err) => Err(err),
+		};
+	}
+
+	pub fn program_with_recovery(&mut self) -> ParseResult {
+		let statements = match self.statement_list(None) {
+			Ok(statements) => statements,
+			Err(err) => {
+				self.errors.push(err);
+				StatementList::new()
+			}
+		};
+		return ParseResult { statements, errors: std::mem::take(&mut self.errors) };
 	}
 
 	/*
@@ -135,24 +254,78 @@ impl Parser {
 	) -> ResultWithError<StatementList> {
 		let mut res = StatementList::new();
 		let stop = stop_lookahead_type.unwrap_or(TokenType::_EOFDummy);
-		while let Ok(lookahead) = self.lookahead() {
-			if lookahead.typ == stop {
-				break;
+		loop {
+			let start_consumed_tokens = self.consumed_tokens;
+			let start = match self.lookahead() {
+				Ok(token) if token.typ == stop => break,
+				// The enclosing statement can't be completed, so it has to be the one to recover.
+				Ok(token) if token.typ == TokenType::_EOFDummy => return self.unexpected_token(),
+				Ok(token) => token.span,
+				Err(err) => err.span.unwrap_or(self.previous_span),
+			};
+			match self.statement() {
+				Ok(statement) => res.push(statement),
+				Err(err) => {
+					self.errors.push(err);
+					self.skip_erroneous_statement(start_consumed_tokens);
+					res.push(self.statement_from(start, StatementKind::ErrorStatement));
+				}
 			}
-			res.push(self.statement()?);
 		}
 		return Ok(res);
 	}
 
+	/// Skips the rest of a statement that failed to parse: up to and including the next `;` or
+	/// block that it contains, or up to the `}` that closes the block it is in.
+	fn skip_erroneous_statement(&mut self, start_consumed_tokens: usize) {
+		// Always skip at least one token, so that parsing can't get stuck on a token that can't
+		// start a statement.
+		let mut must_skip = self.consumed_tokens == start_consumed_tokens;
+		let mut depth = 0usize;
+		loop {
+			let typ = match self.peekable_stream.peek() {
+				None => return,
+				Some(Ok(token)) => token.typ,
+				Some(Err(_)) => {
+					self.peekable_stream.next();
+					must_skip = false;
+					continue;
+				}
+			};
+			let stop = match typ {
+				TokenType::_EOFDummy => return,
+				TokenType::Semicolon => depth == 0,
+				TokenType::OpenBlock => {
+					depth += 1;
+					false
+				}
+				TokenType::CloseBlock if depth == 0 && !must_skip => return,
+				TokenType::CloseBlock => {
+					depth = depth.saturating_sub(1);
+					depth == 0
+				}
+				_ => false,
+			};
+			let _ = self.eat_any();
+			must_skip = false;
+			if stop {
+				return;
+			}
+		}
+	}
+
 	/*
 	statement:
 		| ...
 	*/
 	fn statement(&mut self) -> ResultWithError<Statement> {
 		return match self.lookahead_type()? {
+			// A `{` that starts a statement always starts a block, so object literals used as
+			// statements have to be parenthesized: `({ x: 1 });`
 			TokenType::OpenBlock => self.block_statement(),
 			TokenType::Semicolon => self.empty_statement(),
 			TokenType::Keyword(Keyword::Let) => self.variable_declarations_statement(),
+			TokenType::Keyword(Keyword::Const) => self.constant_declarations_statement(),
 			TokenType::Keyword(Keyword::If) => self.if_statement(),
 			TokenType::Keyword(Keyword::While) => self.while_loop(),
 			TokenType::Keyword(Keyword::Do) => self.do_while_loop(),
@@ -164,6 +337,18 @@ impl Parser {
 			TokenType::Keyword(Keyword::Continue) => self.continue_statement(),
 			TokenType::Keyword(Keyword::Namespace) => self.namespace_statement(),
 			TokenType::Keyword(Keyword::Import) => self.import_statement(),
+			TokenType::Keyword(Keyword::Throw) => self.throw_statement(),
+			TokenType::Keyword(Keyword::Try) => self.try_statement(),
+			TokenType::Keyword(Keyword::Match) => self.match_statement(),
+			// Only loops can be labeled, so that `{ x: 1 }` still fails at the `:` as an object
+			// literal used as a block
+			TokenType::Identifier if
+				self.lookahead_nth_type(1) == Some(TokenType::Colon) &&
+				matches!(
+					self.lookahead_nth_type(2),
+					Some(TokenType::Keyword(Keyword::While | Keyword::Do | Keyword::For))
+				)
+			=> self.labeled_loop(),
 			_ => self.expression_statement(),
 		};
 	}
@@ -173,12 +358,12 @@ impl Parser {
 		| 'import' expression(file_name) 'as' expression(namespace_object) ';'
 	*/
 	fn import_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Import))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Import))?.span;
 		let file_name = self.expression()?;
 		self.eat(TokenType::Keyword(Keyword::As))?;
 		let as_object = self.dotted_identifiers()?;
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::import_statement(file_name, as_object));
+		return Ok(Statement::import_statement(file_name, as_object).with_span(self.span_from(start)));
 	}
 
 	/*
@@ -186,42 +371,115 @@ impl Parser {
 		| 'namespace' expression surrounded_statement_list
 	*/
 	fn namespace_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Namespace))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Namespace))?.span;
 		let module = self.dotted_identifiers()?;
 		let body = self.surrounded_statement_list()?;
-		return Ok(Statement::namespace_statement(module, body));
+		return Ok(Statement::namespace_statement(module, body).with_span(self.span_from(start)));
 	}
 
 	/*
 	break_statement:
-		| 'break' ';'
-		| 'break' integer_literal ';'
+		| 'break' loop_depth ';'
 	*/
 	fn break_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Break))?;
-		let num = if self.lookahead_type()? == TokenType::Semicolon {
-			1
-		} else {
-			self.eat(TokenType::Number)?.data.parse().unwrap()
-		};
+		let start = self.eat(TokenType::Keyword(Keyword::Break))?.span;
+		let num = self.loop_depth()?;
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::BreakStatement(num));
+		return Ok(self.statement_from(start, StatementKind::BreakStatement(num)));
 	}
 
 	/*
 	continue_statement:
-		| 'continue' ';'
-		| 'continue' integer_literal ';'
+		| 'continue' loop_depth ';'
 	*/
 	fn continue_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Continue))?;
-		let num = if self.lookahead_type()? == TokenType::Semicolon {
-			1
-		} else {
-			self.eat(TokenType::Number)?.data.parse().unwrap()
-		};
+		let start = self.eat(TokenType::Keyword(Keyword::Continue))?.span;
+		let num = self.loop_depth()?;
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::ContinueStatement(num));
+		return Ok(self.statement_from(start, StatementKind::ContinueStatement(num)));
+	}
+
+	/*
+	loop_depth:
+		| <empty>
+		| integer_literal
+		| Identifier
+	*/
+	// A label is resolved to the number of loops up to and including the one it labels, a number
+	// must be a positive integer written with decimal digits only
+	fn loop_depth(&mut self) -> ResultWithError<i64> {
+		return match self.lookahead_type()? {
+			TokenType::Semicolon => Ok(1),
+			TokenType::Identifier => {
+				let token = self.eat(TokenType::Identifier)?;
+				let index = self.loop_labels
+					.iter()
+					.rposition(|label| label.as_ref() == Some(&token.data))
+					.ok_or_else(|| {
+						EvilangError::new(ErrorT::UnknownLoopLabel(token.data.clone())).with_span(token.span)
+					})?;
+				Ok((self.loop_labels.len() - index) as i64)
+			}
+			_ => {
+				let token = self.eat(TokenType::Number)?;
+				let is_decimal = token.data.bytes().all(|c| c.is_ascii_digit());
+				let depth = match NumberT::from_literal(&token.data).map_err(|e| e.with_span(token.span))? {
+					NumberT::Integer(depth) if is_decimal && depth > 0 => i64::try_from(depth).ok(),
+					_ => None,
+				};
+				depth.ok_or_else(|| {
+					EvilangError::new(ErrorT::InvalidLoopDepth(token.data.clone())).with_span(token.span)
+				})
+			}
+		};
+	}
+
+	/*
+	labeled_loop:
+		| Identifier ':' while_loop
+		| Identifier ':' do_while_loop
+		| Identifier ':' for_loop
+	*/
+	fn labeled_loop(&mut self) -> ResultWithError<Statement> {
+		let start = self.lookahead_span()?;
+		let label = self.identifier()?;
+		self.eat(TokenType::Colon)?;
+		let parse_loop = match self.lookahead_type()? {
+			TokenType::Keyword(Keyword::While) => Self::while_loop,
+			TokenType::Keyword(Keyword::Do) => Self::do_while_loop,
+			TokenType::Keyword(Keyword::For) => Self::for_loop,
+			_ => {
+				return self.unexpected_token();
+			}
+		};
+		self.next_loop_label = Some(label);
+		let res = parse_loop(self)?;
+		return Ok(res.with_span(self.span_from(start)));
+	}
+
+	/// Parses the body of a loop with the label of the loop, if any, in scope.
+	fn loop_body(
+		&mut self,
+		label: Option<IdentifierT>,
+		body: fn(&mut Self) -> ResultWithError<Statement>
+	) -> ResultWithError<BoxStatement> {
+		self.loop_labels.push(label);
+		let res = body(self);
+		self.loop_labels.pop();
+		return Ok(res?.into());
+	}
+
+	/*
+	function_body:
+		| block_statement
+	*/
+	// The loops around a function can't be broken out of from inside it, so their labels are
+	// hidden while its body is parsed
+	fn function_body(&mut self) -> ResultWithError<Statement> {
+		let enclosing_loop_labels = std::mem::take(&mut self.loop_labels);
+		let res = self.block_statement();
+		self.loop_labels = enclosing_loop_labels;
+		return res;
 	}
 
 	/*
@@ -230,17 +488,25 @@ impl Parser {
 	*/
 	#[inline]
 	fn class_declaration_statement(&mut self) -> ResultWithError<Statement> {
-		return Ok(Statement::ClassDeclarationStatement(self.class_declaration()?));
+		let class_declaration = self.class_declaration()?;
+		let span = class_declaration.span;
+		return Ok(Statement {
+			kind: StatementKind::ClassDeclarationStatement(class_declaration),
+			span,
+		});
 	}
 
 	/*
 	class_declaration:
 		| 'class' identifier ('extends' identifier)? '{'
-			  function_declarations
+			  class_member*
 		  '}'
+	class_member:
+		| function_declaration
+		| ('get' | 'set') Identifier function_parameters captures_list? block_statement
 	*/
 	fn class_declaration(&mut self) -> ResultWithError<ClassDeclaration> {
-		self.eat(TokenType::Keyword(Keyword::Class))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Class))?.span;
 		let name = self.identifier()?;
 		let super_class = if self.lookahead_type()? == TokenType::Keyword(Keyword::Extends) {
 			self.eat(TokenType::Keyword(Keyword::Extends))?;
@@ -249,9 +515,39 @@ impl Parser {
 			None
 		};
 		self.eat(TokenType::OpenBlock)?;
-		let methods = self.un_delimited_items(Self::function_declaration, TokenType::CloseBlock)?;
+		let members = self.un_delimited_items(Self::class_member, TokenType::CloseBlock)?;
+		let mut methods = vec![];
+		let mut getters = vec![];
+		let mut setters = vec![];
+		for member in members {
+			match member {
+				ClassMember::Method(fdecl) => methods.push(fdecl),
+				ClassMember::Getter(fdecl) => getters.push(fdecl),
+				ClassMember::Setter(fdecl) => setters.push(fdecl),
+			}
+		}
 		self.eat(TokenType::CloseBlock)?;
-		return Ok(ClassDeclaration::new(name, super_class, methods));
+		return Ok(
+			ClassDeclaration::new(name, super_class, methods)
+				.with_accessors(getters, setters)
+				.with_span(self.span_from(start))
+		);
+	}
+
+	fn class_member(&mut self) -> ResultWithError<ClassMember> {
+		let token = self.lookahead()?;
+		if token.typ != TokenType::Identifier {
+			return Ok(ClassMember::Method(self.function_declaration()?));
+		}
+		// `get` and `set` are only special at the start of a class member, so that they can still
+		// be used as names everywhere else
+		let is_getter = token.data == "get";
+		if !is_getter && token.data != "set" {
+			return self.unexpected_token();
+		}
+		let start = self.eat(TokenType::Identifier)?.span;
+		let accessor = self.function_declaration_from(start)?;
+		return Ok(if is_getter { ClassMember::Getter(accessor) } else { ClassMember::Setter(accessor) });
 	}
 
 	/*
@@ -260,14 +556,197 @@ impl Parser {
 		| 'return' expression ';'
 	*/
 	fn return_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Return))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Return))?.span;
 		let res = if self.lookahead_type()? != TokenType::Semicolon {
 			Some(self.expression()?)
 		} else {
 			None
 		};
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::ReturnStatement(res));
+		return Ok(self.statement_from(start, StatementKind::ReturnStatement(res)));
+	}
+
+	/*
+	throw_statement:
+		| 'throw' expression ';'
+	*/
+	fn throw_statement(&mut self) -> ResultWithError<Statement> {
+		let start = self.eat(TokenType::Keyword(Keyword::Throw))?.span;
+		let res = self.expression()?;
+		self.eat(TokenType::Semicolon)?;
+		return Ok(self.statement_from(start, StatementKind::ThrowStatement(res)));
+	}
+
+	/*
+	try_statement:
+		| 'try' block_statement catch_clause
+		| 'try' block_statement 'finally' block_statement
+		| 'try' block_statement catch_clause 'finally' block_statement
+	*/
+	fn try_statement(&mut self) -> ResultWithError<Statement> {
+		let start = self.eat(TokenType::Keyword(Keyword::Try))?.span;
+		let body = self.block_statement()?;
+		let catch_clause = if self.lookahead_type()? == TokenType::Keyword(Keyword::Catch) {
+			Some(self.catch_clause()?)
+		} else {
+			None
+		};
+		let finally_block = if self.lookahead_type()? == TokenType::Keyword(Keyword::Finally) {
+			self.eat(TokenType::Keyword(Keyword::Finally))?;
+			Some(self.block_statement()?.into())
+		} else {
+			None
+		};
+		if catch_clause.is_none() && finally_block.is_none() {
+			return Err(ErrorT::ExpectedCatchOrFinally.into());
+		}
+		let statement = Statement::try_statement(body.into(), catch_clause, finally_block);
+		return Ok(statement.with_span(self.span_from(start)));
+	}
+
+	/*
+	catch_clause:
+		| 'catch' block_statement
+		| 'catch' '(' binding_pattern ')' block_statement
+	*/
+	fn catch_clause(&mut self) -> ResultWithError<CatchClause> {
+		self.eat(TokenType::Keyword(Keyword::Catch))?;
+		let parameter = if self.lookahead_type()? == TokenType::OpenParen {
+			self.eat(TokenType::OpenParen)?;
+			let parameter = self.binding_pattern()?;
+			self.eat(TokenType::CloseParen)?;
+			Some(parameter)
+		} else {
+			None
+		};
+		return Ok(CatchClause::new(parameter, self.block_statement()?.into()));
+	}
+
+	/*
+	match_statement:
+		| 'match' '(' expression ')' match_arms(match_statement_arm_body)
+	*/
+	fn match_statement(&mut self) -> ResultWithError<Statement> {
+		let start = self.lookahead_span()?;
+		let value = self.match_value()?;
+		let arms = self.match_arms(
+			Self::match_statement_arm_body,
+			|body| matches!(body.kind, StatementKind::BlockStatement(_))
+		)?;
+		return Ok(Statement::match_statement(value, arms).with_span(self.span_from(start)));
+	}
+
+	/*
+	match_statement_arm_body:
+		| block_statement
+		| assignment_expression
+	*/
+	fn match_statement_arm_body(&mut self) -> ResultWithError<BoxStatement> {
+		if self.lookahead_type()? == TokenType::OpenBlock {
+			return Ok(self.block_statement()?.into());
+		}
+		return Ok(Statement::from(self.assignment_expression()?).into());
+	}
+
+	/*
+	match_value:
+		| 'match' '(' expression ')'
+	*/
+	fn match_value(&mut self) -> ResultWithError<Expression> {
+		self.eat(TokenType::Keyword(Keyword::Match))?;
+		self.eat(TokenType::OpenParen)?;
+		let value = self.expression()?;
+		self.eat(TokenType::CloseParen)?;
+		return Ok(value);
+	}
+
+	/*
+	match_arms(body):
+		| '{' (match_arm(body) ',')* match_arm(body)? '}'
+	match_arm(body):
+		| match_pattern '=>' body
+		| match_pattern 'if' conditional_expression '=>' body
+	*/
+	// The comma after an arm can be left out when its body is a block, as in `1 => { ... } _ => x`
+	fn match_arms<T>(
+		&mut self,
+		body: fn(&mut Self) -> ResultWithError<T>,
+		is_block: fn(&T) -> bool
+	) -> ResultWithError<Vec<MatchArm<T>>> {
+		self.eat(TokenType::OpenBlock)?;
+		let mut arms = vec![];
+		while self.lookahead_type()? != TokenType::CloseBlock {
+			let pattern = self.match_pattern()?;
+			let guard = if self.lookahead_type()? == TokenType::Keyword(Keyword::If) {
+				self.eat(TokenType::Keyword(Keyword::If))?;
+				// Not an assignment_expression, which would take `x => ...` for an arrow function
+				Some(self.conditional_expression()?)
+			} else {
+				None
+			};
+			self.eat(TokenType::FatArrow)?;
+			let body = body(self)?;
+			let needs_comma = !is_block(&body);
+			arms.push(MatchArm::new(pattern, guard, body));
+			if self.lookahead_type()? == TokenType::Comma {
+				self.eat(TokenType::Comma)?;
+			} else if needs_comma {
+				break;
+			}
+		}
+		self.eat(TokenType::CloseBlock)?;
+		return Ok(arms);
+	}
+
+	/*
+	match_pattern:
+		| match_pattern_item
+		| match_pattern_item '|' match_pattern
+	*/
+	fn match_pattern(&mut self) -> ResultWithError<Pattern> {
+		let first = self.match_pattern_item()?;
+		if self.lookahead_type()? != TokenType::Pipe {
+			return Ok(first);
+		}
+		let mut alternatives = vec![first];
+		while self.lookahead_type()? == TokenType::Pipe {
+			self.eat(TokenType::Pipe)?;
+			alternatives.push(self.match_pattern_item()?);
+		}
+		return Ok(Pattern::Alternatives(alternatives));
+	}
+
+	/*
+	match_pattern_item:
+		| '_'
+		| Identifier
+		| numeric_literal
+		| '-' numeric_literal
+		| string_literal
+		| 'true'
+		| 'false'
+		| 'null'
+		| vector_pattern(match_pattern)
+		| object_pattern(match_pattern)
+	*/
+	fn match_pattern_item(&mut self) -> ResultWithError<Pattern> {
+		let literal = match self.lookahead_type()? {
+			TokenType::OpenSquareBracket => return self.vector_pattern(Self::match_pattern),
+			TokenType::OpenBlock => return self.object_pattern(Self::match_pattern),
+			TokenType::Identifier => {
+				let identifier = self.identifier()?;
+				return Ok(if identifier == "_" { Pattern::Wildcard } else { Pattern::Identifier(identifier) });
+			}
+			TokenType::Number => self.numeric_literal()?,
+			TokenType::String => self.string_literal()?,
+			TokenType::AdditiveOperator if self.lookahead()?.data == "-" => {
+				let start = self.eat(TokenType::AdditiveOperator)?.span;
+				let argument = self.numeric_literal()?;
+				Expression::unary_expression(Operator::Minus, argument.into()).with_span(self.span_from(start))
+			}
+			_ => self.singular_literal()?,
+		};
+		return Ok(Pattern::Literal(literal.into()));
 	}
 
 	/*
@@ -276,16 +755,80 @@ impl Parser {
 	*/
 	#[inline]
 	fn function_declaration_statement(&mut self) -> ResultWithError<Statement> {
-		return Ok(Statement::FunctionDeclarationStatement(self.function_declaration()?));
+		let function_declaration = self.function_declaration()?;
+		let span = function_declaration.span;
+		return Ok(Statement {
+			kind: StatementKind::FunctionDeclarationStatement(function_declaration),
+			span,
+		});
 	}
 
 	/*
 	function_declaration:
-		| 'fn' Identifier '(' function_parameter_declarations ')' block_statement
+		| 'fn' Identifier function_parameters captures_list? block_statement
 	*/
 	fn function_declaration(&mut self) -> ResultWithError<FunctionDeclaration> {
-		self.eat(TokenType::Keyword(Keyword::Fn))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Fn))?.span;
+		return self.function_declaration_from(start);
+	}
+
+	/// The rest of a function declaration after the token it starts with, which is `fn` for
+	/// functions and `get` or `set` for accessors of classes.
+	fn function_declaration_from(&mut self, start: Span) -> ResultWithError<FunctionDeclaration> {
 		let name: IdentifierT = self.identifier()?;
+		let params = self.function_parameters()?;
+		let captures = self.optional_captures_list()?;
+		let body = self.function_body()?;
+		let function_declaration = FunctionDeclaration::new(name, params, body.into())
+			.with_captures(captures)
+			.with_span(self.span_from(start));
+		Ok(function_declaration)
+	}
+
+	/*
+	captures_list:
+		| 'captures' '(' delimited_items(capture_declaration, ',') ')'
+	*/
+	fn optional_captures_list(&mut self) -> ResultWithError<Option<Vec<CaptureDeclaration>>> {
+		if self.lookahead_type()? != TokenType::Keyword(Keyword::Captures) {
+			return Ok(None);
+		}
+		self.eat(TokenType::Keyword(Keyword::Captures))?;
+		self.eat(TokenType::OpenParen)?;
+		let captures = self.delimited_items(
+			Self::capture_declaration,
+			TokenType::Comma,
+			TokenType::CloseParen
+		)?;
+		self.eat(TokenType::CloseParen)?;
+		return Ok(Some(captures));
+	}
+
+	/*
+	capture_declaration:
+		| Identifier
+		| '&' Identifier
+		| Identifier variable_initializer
+	*/
+	fn capture_declaration(&mut self) -> ResultWithError<CaptureDeclaration> {
+		if self.lookahead_type()? == TokenType::Ampersand {
+			self.eat(TokenType::Ampersand)?;
+			return Ok(CaptureDeclaration::ByReference(self.identifier()?));
+		}
+		let identifier = self.identifier()?;
+		return Ok(match self.lookahead_type()? {
+			TokenType::AssignmentOperator => {
+				CaptureDeclaration::Initialized(identifier, self.variable_initializer()?)
+			}
+			_ => CaptureDeclaration::ByValue(identifier),
+		});
+	}
+
+	/*
+	function_parameters:
+		| '(' delimited_items(function_parameter_declaration, ',') ')'
+	*/
+	fn function_parameters(&mut self) -> ResultWithError<Vec<FunctionParameterDeclaration>> {
 		self.eat(TokenType::OpenParen)?;
 		let params = self.delimited_items(
 			Self::function_parameter_declaration,
@@ -293,17 +836,25 @@ impl Parser {
 			TokenType::CloseParen
 		)?;
 		self.eat(TokenType::CloseParen)?;
-		let body = self.block_statement()?;
-		let function_declaration = FunctionDeclaration::new(name, params, body.into());
-		Ok(function_declaration)
+		if let Some(rest) = params.iter().rev().skip(1).find(|v| v.is_rest) {
+			return Err(ErrorT::RestParameterNotLast(rest.pattern.to_string()).into());
+		}
+		return Ok(params);
 	}
 
 	/*
 	function_parameter_declaration:
-		| Identifier
+		| binding_pattern
+		| binding_pattern variable_initializer
+		| '...' Identifier
 	*/
 	fn function_parameter_declaration(&mut self) -> ResultWithError<FunctionParameterDeclaration> {
-		return Ok(FunctionParameterDeclaration::new(self.identifier()?));
+		if self.lookahead_type()? == TokenType::Ellipsis {
+			self.eat(TokenType::Ellipsis)?;
+			return Ok(FunctionParameterDeclaration::new_rest(self.identifier()?));
+		}
+		let element = self.pattern_element(Self::binding_pattern)?;
+		return Ok(FunctionParameterDeclaration::new_destructuring(element.pattern, element.default));
 	}
 
 	/*
@@ -312,7 +863,7 @@ impl Parser {
 		| 'if' '(' expression ')' statement 'else' statement
 	*/
 	fn if_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::If))?;
+		let start = self.eat(TokenType::Keyword(Keyword::If))?.span;
 		self.eat(TokenType::OpenParen)?;
 		let condition = self.expression()?;
 		self.eat(TokenType::CloseParen)?;
@@ -324,7 +875,9 @@ impl Parser {
 			}
 			_ => None,
 		};
-		return Ok(Statement::if_statement(condition, if_branch, else_branch));
+		return Ok(
+			Statement::if_statement(condition, if_branch, else_branch).with_span(self.span_from(start))
+		);
 	}
 
 	/*
@@ -332,12 +885,13 @@ impl Parser {
 		| 'while' '(' expression ')' statement
 	*/
 	fn while_loop(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::While))?;
+		let label = self.next_loop_label.take();
+		let start = self.eat(TokenType::Keyword(Keyword::While))?.span;
 		self.eat(TokenType::OpenParen)?;
 		let condition = self.expression()?;
 		self.eat(TokenType::CloseParen)?;
-		let body = BoxStatement::from(self.statement()?);
-		return Ok(Statement::while_loop(condition, body));
+		let body = self.loop_body(label, Self::statement)?;
+		return Ok(Statement::while_loop(condition, body).with_span(self.span_from(start)));
 	}
 
 	/*
@@ -345,29 +899,92 @@ impl Parser {
 		| 'do' block_statement 'while' '(' expression ')' ';'
 	*/
 	fn do_while_loop(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Do))?;
-		let body = BoxStatement::from(self.block_statement()?);
+		let label = self.next_loop_label.take();
+		let start = self.eat(TokenType::Keyword(Keyword::Do))?.span;
+		let body = self.loop_body(label, Self::block_statement)?;
 		self.eat(TokenType::Keyword(Keyword::While))?;
 		self.eat(TokenType::OpenParen)?;
 		let condition = self.expression()?;
 		self.eat(TokenType::CloseParen)?;
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::do_while_loop(condition, body));
+		return Ok(Statement::do_while_loop(condition, body).with_span(self.span_from(start)));
 	}
 
 	/*
 	for_loop:
 		| 'for' '(' expression ')' statement
+		| for_in_or_of_loop
 	*/
 	fn for_loop(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::For))?;
+		let label = self.next_loop_label.take();
+		let start = self.eat(TokenType::Keyword(Keyword::For))?.span;
 		self.eat(TokenType::OpenParen)?;
+		if self.is_for_in_or_of_loop_ahead() {
+			return self.for_in_or_of_loop(start, label);
+		}
 		let init = self.for_loop_initialization_statement()?;
 		let condition = self.for_loop_condition_expression()?;
 		let increment = self.for_loop_increment_statement()?;
 		self.eat(TokenType::CloseParen)?;
-		let body = self.statement()?;
-		return Ok(Statement::for_loop(init.into(), condition, increment.into(), body.into()));
+		let body = self.loop_body(label, Self::statement)?;
+		return Ok(
+			Statement::for_loop(init.into(), condition, increment.into(), body).with_span(
+				self.span_from(start)
+			)
+		);
+	}
+
+	/// Whether the next tokens are a `let` with a single pattern followed by `in` or `of`, rather
+	/// than the initialization statement of a C-style loop.
+	fn is_for_in_or_of_loop_ahead(&mut self) -> bool {
+		if self.lookahead_nth_type(0) != Some(TokenType::Keyword(Keyword::Let)) {
+			return false;
+		}
+		let i = match self.lookahead_nth_type(1) {
+			Some(TokenType::Identifier) => 1,
+			Some(TokenType::OpenSquareBracket | TokenType::OpenBlock) => {
+				match self.lookahead_closing_bracket_index(1) {
+					Some(i) => i,
+					None => return false,
+				}
+			}
+			_ => return false,
+		};
+		return matches!(
+			self.lookahead_nth_type(i + 1),
+			Some(TokenType::Keyword(Keyword::In | Keyword::Of))
+		);
+	}
+
+	/*
+	for_in_or_of_loop:
+		| 'for' '(' 'let' binding_pattern 'of' expression ')' statement
+		| 'for' '(' 'let' binding_pattern 'in' expression ')' statement
+	*/
+	fn for_in_or_of_loop(
+		&mut self,
+		start: Span,
+		label: Option<IdentifierT>
+	) -> ResultWithError<Statement> {
+		self.eat(TokenType::Keyword(Keyword::Let))?;
+		let pattern = self.binding_pattern()?;
+		let is_for_of = match self.lookahead_type()? {
+			TokenType::Keyword(Keyword::Of) => true,
+			TokenType::Keyword(Keyword::In) => false,
+			_ => {
+				return self.unexpected_token();
+			}
+		};
+		self.eat_any()?;
+		let iterable = self.expression()?;
+		self.eat(TokenType::CloseParen)?;
+		let body = self.loop_body(label, Self::statement)?;
+		let statement = if is_for_of {
+			Statement::for_of_loop(pattern, iterable, body)
+		} else {
+			Statement::for_in_loop(pattern, iterable, body)
+		};
+		return Ok(statement.with_span(self.span_from(start)));
 	}
 
 	/*
@@ -397,7 +1014,7 @@ impl Parser {
 	*/
 	fn for_loop_condition_expression(&mut self) -> ResultWithError<Expression> {
 		let result = match self.lookahead_type()? {
-			TokenType::Semicolon => Expression::BooleanLiteral(true),
+			TokenType::Semicolon => ExpressionKind::BooleanLiteral(true).into(),
 			_ => self.expression()?,
 		};
 		self.eat(TokenType::Semicolon)?;
@@ -412,9 +1029,10 @@ impl Parser {
 	*/
 	fn for_loop_increment_statement(&mut self) -> ResultWithError<Statement> {
 		return match self.lookahead_type()? {
+			// Like in `statement`, a `{` here starts a block
 			TokenType::OpenBlock => self.block_statement(),
-			TokenType::CloseParen => Ok(Statement::EmptyStatement),
-			_ => Ok(Statement::ExpressionStatement(self.expression()?)),
+			TokenType::CloseParen => Ok(StatementKind::EmptyStatement.into()),
+			_ => Ok(Statement::from(self.expression()?)),
 		};
 	}
 
@@ -423,7 +1041,7 @@ impl Parser {
 		| 'let' variable_declarations ';'
 	*/
 	fn variable_declarations_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Keyword(Keyword::Let))?;
+		let start = self.eat(TokenType::Keyword(Keyword::Let))?.span;
 		let res = self.delimited_items(
 			Self::variable_declaration,
 			TokenType::Comma,
@@ -433,15 +1051,61 @@ impl Parser {
 			return Err(ErrorT::ExpectedVariableDeclaration.into());
 		}
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::VariableDeclarations(res));
+		return Ok(self.statement_from(start, StatementKind::VariableDeclarations(res)));
+	}
+
+	/*
+	constant_declarations_statement:
+		| 'const' function_declaration
+		| 'const' class_declaration
+		| 'const' constant_declarations ';'
+	*/
+	fn constant_declarations_statement(&mut self) -> ResultWithError<Statement> {
+		let start = self.eat(TokenType::Keyword(Keyword::Const))?.span;
+		let kind = match self.lookahead_type()? {
+			TokenType::Keyword(Keyword::Fn) => {
+				StatementKind::FunctionDeclarationStatement(self.function_declaration()?.as_constant())
+			}
+			TokenType::Keyword(Keyword::Class) => {
+				StatementKind::ClassDeclarationStatement(self.class_declaration()?.as_constant())
+			}
+			_ => {
+				let res = self.delimited_items(
+					Self::constant_declaration,
+					TokenType::Comma,
+					TokenType::Semicolon
+				)?;
+				if res.is_empty() {
+					return Err(ErrorT::ExpectedVariableDeclaration.into());
+				}
+				self.eat(TokenType::Semicolon)?;
+				StatementKind::ConstantDeclarations(res)
+			}
+		};
+		return Ok(self.statement_from(start, kind));
+	}
+
+	/*
+	constant_declaration:
+		| binding_pattern variable_initializer
+	*/
+	#[inline]
+	fn constant_declaration(&mut self) -> ResultWithError<VariableDeclaration> {
+		let pattern = self.binding_pattern()?;
+		return Ok(VariableDeclaration::new_destructuring(pattern, self.variable_initializer()?));
 	}
 
 	/*
 	variable_declaration:
 		| Identifier
 		| Identifier variable_initializer
+		| binding_pattern variable_initializer
 	*/
 	fn variable_declaration(&mut self) -> ResultWithError<VariableDeclaration> {
+		if self.lookahead_type()? != TokenType::Identifier {
+			let pattern = self.binding_pattern()?;
+			return Ok(VariableDeclaration::new_destructuring(pattern, self.variable_initializer()?));
+		}
 		let identifier = self.identifier()?;
 		let initializer = match self.lookahead_type()? {
 			TokenType::Semicolon | TokenType::Comma => None,
@@ -451,7 +1115,135 @@ impl Parser {
 	}
 
 	/*
-	variable_declaration:
+	binding_pattern:
+		| Identifier
+		| vector_pattern(binding_pattern)
+		| object_pattern(binding_pattern)
+	*/
+	fn binding_pattern(&mut self) -> ResultWithError<Pattern> {
+		return match self.lookahead_type()? {
+			TokenType::OpenSquareBracket => self.vector_pattern(Self::binding_pattern),
+			TokenType::OpenBlock => self.object_pattern(Self::binding_pattern),
+			_ => Ok(Pattern::Identifier(self.identifier()?)),
+		};
+	}
+
+	/*
+	assignment_pattern:
+		| vector_pattern(assignment_pattern)
+		| object_pattern(assignment_pattern)
+		| lhs
+	*/
+	fn assignment_pattern(&mut self) -> ResultWithError<Pattern> {
+		return match self.lookahead_type()? {
+			TokenType::OpenSquareBracket => self.vector_pattern(Self::assignment_pattern),
+			TokenType::OpenBlock => self.object_pattern(Self::assignment_pattern),
+			_ => {
+				let target = self.conditional_expression()?;
+				ensure(target.is_lhs(), ErrorT::ExpectedLhsExpression)
+					.map_err(|e| e.with_span(target.span))?;
+				Ok(match target.kind {
+					ExpressionKind::Identifier(identifier) => Pattern::Identifier(identifier),
+					_ => Pattern::Target(target.into()),
+				})
+			}
+		};
+	}
+
+	/*
+	vector_pattern(pattern):
+		| '[' delimited_items(pattern_element(pattern), ',') ']'
+		| '[' delimited_items(pattern_element(pattern), ',') ',' '...' pattern ']'
+		| '[' '...' pattern ']'
+	*/
+	fn vector_pattern(&mut self, pattern: fn(&mut Self) -> ResultWithError<Pattern>) -> ResultWithError<Pattern> {
+		self.eat(TokenType::OpenSquareBracket)?;
+		let mut elements = vec![];
+		let mut rest = None;
+		if self.lookahead_type()? != TokenType::CloseSquareBracket {
+			loop {
+				if self.lookahead_type()? == TokenType::Ellipsis {
+					// The rest element has to be the last one, so a `]` is expected right after it
+					self.eat(TokenType::Ellipsis)?;
+					rest = Some(pattern(self)?.into());
+					break;
+				}
+				elements.push(self.pattern_element(pattern)?);
+				if self.lookahead_type()? != TokenType::Comma {
+					break;
+				}
+				self.eat(TokenType::Comma)?;
+			}
+		}
+		self.eat(TokenType::CloseSquareBracket)?;
+		return Ok(Pattern::Vector { elements, rest });
+	}
+
+	/*
+	object_pattern(pattern):
+		| '{' delimited_items(property_pattern(pattern), ',') '}'
+	*/
+	fn object_pattern(&mut self, pattern: fn(&mut Self) -> ResultWithError<Pattern>) -> ResultWithError<Pattern> {
+		self.eat(TokenType::OpenBlock)?;
+		let mut properties = vec![];
+		if self.lookahead_type()? != TokenType::CloseBlock {
+			loop {
+				properties.push(self.property_pattern(pattern)?);
+				if self.lookahead_type()? != TokenType::Comma {
+					break;
+				}
+				self.eat(TokenType::Comma)?;
+			}
+		}
+		self.eat(TokenType::CloseBlock)?;
+		return Ok(Pattern::Object(properties));
+	}
+
+	/*
+	property_pattern(pattern):
+		| Identifier
+		| Identifier variable_initializer
+		| object_property_key ':' pattern_element(pattern)
+	*/
+	fn property_pattern(
+		&mut self,
+		pattern: fn(&mut Self) -> ResultWithError<Pattern>
+	) -> ResultWithError<PropertyPattern> {
+		if self.lookahead_type()? == TokenType::Identifier &&
+			self.lookahead_nth_type(1) != Some(TokenType::Colon) {
+			let name = self.identifier()?;
+			let default = self.optional_pattern_default()?;
+			return Ok(PropertyPattern::new_shorthand(name, default));
+		}
+		let key = self.object_property_key()?;
+		self.eat(TokenType::Colon)?;
+		return Ok(PropertyPattern::new(key, self.pattern_element(pattern)?));
+	}
+
+	/*
+	pattern_element(pattern):
+		| pattern
+		| pattern variable_initializer
+	*/
+	fn pattern_element(
+		&mut self,
+		pattern: fn(&mut Self) -> ResultWithError<Pattern>
+	) -> ResultWithError<PatternElement> {
+		let pattern = pattern(self)?;
+		return Ok(PatternElement::new(pattern, self.optional_pattern_default()?));
+	}
+
+	#[inline(always)]
+	fn optional_pattern_default(&mut self) -> ResultWithError<Option<Expression>> {
+		return if self.lookahead_type()? == TokenType::AssignmentOperator {
+			Ok(Some(self.variable_initializer()?))
+		} else {
+			Ok(None)
+		};
+	}
+
+	/*
+	variable_initializer:
 		| '=' assignment_expression
 	*/
 	fn variable_initializer(&mut self) -> ResultWithError<Expression> {
@@ -467,7 +1259,9 @@ impl Parser {
 		| surrounded_statement_list
 	*/
 	fn block_statement(&mut self) -> ResultWithError<Statement> {
-		return Ok(Statement::BlockStatement(self.surrounded_statement_list()?));
+		let start = self.lookahead_span()?;
+		let statements = self.surrounded_statement_list()?;
+		return Ok(self.statement_from(start, StatementKind::BlockStatement(statements)));
 	}
 
 	/*
@@ -491,8 +1285,9 @@ impl Parser {
 	*/
 	fn expression_statement(&mut self) -> ResultWithError<Statement> {
 		let res = self.expression()?;
+		let start = res.span;
 		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::ExpressionStatement(res));
+		return Ok(self.statement_from(start, StatementKind::ExpressionStatement(res)));
 	}
 
 	/*
@@ -501,8 +1296,8 @@ impl Parser {
 	*/
 	#[inline]
 	fn empty_statement(&mut self) -> ResultWithError<Statement> {
-		self.eat(TokenType::Semicolon)?;
-		return Ok(Statement::EmptyStatement);
+		let start = self.eat(TokenType::Semicolon)?.span;
+		return Ok(self.statement_from(start, StatementKind::EmptyStatement));
 	}
 
 	/*
@@ -516,33 +1311,182 @@ impl Parser {
 
 	/*
 	assignment_expression:
-		| relational_expression
+		| arrow_function_expression
+		| destructuring_assignment_expression
+		| conditional_expression
 		| lhs AssignmentOperator assignment_expression
 	*/
 	fn assignment_expression(&mut self) -> ResultWithError<Expression> {
-		let left = self.base_binary_expression()?;
+		if self.is_arrow_function_ahead() {
+			return self.arrow_function_expression();
+		}
+		if self.is_destructuring_assignment_ahead() {
+			return self.destructuring_assignment_expression();
+		}
+		let left = self.conditional_expression()?;
 		if self.lookahead_type()? != TokenType::AssignmentOperator {
 			return Ok(left);
 		}
 		let op = self.eat(TokenType::AssignmentOperator)?;
-		ensure(left.is_lhs(), ErrorT::ExpectedLhsExpression)?;
+		ensure(left.is_lhs(), ErrorT::ExpectedLhsExpression).map_err(|e| e.with_span(left.span))?;
+		let start = left.span;
 		let right = self.assignment_expression()?;
 		return Ok(
 			Expression::assignment_expression(
 				Operator::try_from(&op.data)?,
 				BoxExpression::from(left),
 				BoxExpression::from(right)
-			)
+			).with_span(self.span_from(start))
+		);
+	}
+
+	/// Whether the next tokens are the parameters of an arrow function followed by its `=>`, which
+	/// can only be told apart from a parenthesized expression by looking past the closing `)`.
+	fn is_arrow_function_ahead(&mut self) -> bool {
+		let i = match self.lookahead_nth_type(0) {
+			Some(TokenType::Identifier) => 0,
+			Some(TokenType::OpenParen) => match self.lookahead_closing_bracket_index(0) {
+				Some(i) => i,
+				None => return false,
+			},
+			_ => return false,
+		};
+		return self.lookahead_nth_type(i + 1) == Some(TokenType::FatArrow);
+	}
+
+	/// Whether the next tokens are a vector or object pattern followed by an assignment operator,
+	/// which can only be told apart from a literal by looking past the closing bracket.
+	fn is_destructuring_assignment_ahead(&mut self) -> bool {
+		if !matches!(
+			self.lookahead_nth_type(0),
+			Some(TokenType::OpenSquareBracket | TokenType::OpenBlock)
+		) {
+			return false;
+		}
+		return self.lookahead_closing_bracket_index(0).is_some_and(|i|
+			self.lookahead_nth_type(i + 1) == Some(TokenType::AssignmentOperator)
+		);
+	}
+
+	/// The index of the bracket closing the one that is the token at index `start`, if it is in the
+	/// stream.
+	fn lookahead_closing_bracket_index(&mut self, start: usize) -> Option<usize> {
+		let mut depth = 0usize;
+		let mut i = start;
+		loop {
+			match self.lookahead_nth_type(i)? {
+				TokenType::OpenParen | TokenType::OpenSquareBracket | TokenType::OpenBlock => depth += 1,
+				TokenType::CloseParen | TokenType::CloseSquareBracket | TokenType::CloseBlock => {
+					depth = depth.checked_sub(1)?;
+				}
+				TokenType::_EOFDummy => return None,
+				_ => {}
+			}
+			if depth == 0 {
+				return Some(i);
+			}
+			i += 1;
+		}
+	}
+
+	/*
+	destructuring_assignment_expression:
+		| assignment_pattern variable_initializer
+	*/
+	fn destructuring_assignment_expression(&mut self) -> ResultWithError<Expression> {
+		let start = self.lookahead_span()?;
+		let pattern = self.assignment_pattern()?;
+		let left = self.expression_from(start, ExpressionKind::DestructuringPattern(pattern));
+		let right = self.variable_initializer()?;
+		return Ok(
+			Expression::assignment_expression(
+				Operator::Assignment,
+				BoxExpression::from(left),
+				BoxExpression::from(right)
+			).with_span(self.span_from(start))
+		);
+	}
+
+	/*
+	arrow_function_expression:
+		| Identifier '=>' arrow_function_body
+		| function_parameters '=>' arrow_function_body
+	arrow_function_body:
+		| block_statement
+		| assignment_expression
+	*/
+	fn arrow_function_expression(&mut self) -> ResultWithError<Expression> {
+		let start = self.lookahead_span()?;
+		let params = if self.lookahead_type()? == TokenType::Identifier {
+			vec![self.function_parameter_declaration()?]
+		} else {
+			self.function_parameters()?
+		};
+		self.eat(TokenType::FatArrow)?;
+		let body = if self.lookahead_type()? == TokenType::OpenBlock {
+			self.function_body()?
+		} else {
+			let expr = self.assignment_expression()?;
+			let span = expr.span;
+			Statement { kind: StatementKind::ReturnStatement(Some(expr)), span }
+		};
+		return Ok(self.lambda_expression(start, params, None, body));
+	}
+
+	/// An anonymous function that was parsed from `start` up to the last consumed token.
+	fn lambda_expression(
+		&mut self,
+		start: Span,
+		params: Vec<FunctionParameterDeclaration>,
+		captures: Option<Vec<CaptureDeclaration>>,
+		body: Statement
+	) -> Expression {
+		let span = self.span_from(start);
+		let function_declaration = FunctionDeclaration::new(LAMBDA.into(), params, body.into())
+			.with_captures(captures)
+			.with_span(span);
+		return Expression {
+			kind: ExpressionKind::LambdaExpression(function_declaration),
+			span,
+		};
+	}
+
+	/*
+	conditional_expression:
+		| nullish_coalescing_expression
+		| nullish_coalescing_expression '?' assignment_expression ':' assignment_expression
+	*/
+	fn conditional_expression(&mut self) -> ResultWithError<Expression> {
+		let condition = self.base_binary_expression()?;
+		if self.lookahead_type()? != TokenType::QuestionMark {
+			return Ok(condition);
+		}
+		self.eat(TokenType::QuestionMark)?;
+		let start = condition.span;
+		let consequent = self.assignment_expression()?;
+		self.eat(TokenType::Colon)?;
+		let alternate = self.assignment_expression()?;
+		return Ok(
+			Expression::conditional_expression(
+				condition.into(),
+				consequent.into(),
+				alternate.into()
+			).with_span(self.span_from(start))
 		);
 	}
 
 	binary_expressions!(
 		fn base_binary_expression(&mut self) -> ResultWithError<Expression>{
 			wrapper_function: left_to_right_binary_expression;
+			nullish_coalescing_expression: TokenType::NullishCoalescingOperator;
 			logical_or_expresion: TokenType::LogicalOrOperator;
 			logical_and_expresion: TokenType::LogicalAndOperator;
 			equality_expression: TokenType::EqualityOperator;
 			relational_expression: TokenType::RelationalOperator;
+			bitwise_or_expression: TokenType::Pipe;
+			bitwise_xor_expression: TokenType::BitwiseXorOperator;
+			bitwise_and_expression: TokenType::Ampersand;
+			shift_expression: TokenType::ShiftOperator;
 			additive_expression: TokenType::AdditiveOperator;
 			multiplicative_expression: TokenType::MultiplicativeOperator;
 			base_unary_expression: None;
@@ -551,25 +1495,87 @@ impl Parser {
 
 	/*
 	unary_expression:
-		| primary_expression
+		| exponent_expression
+		| UpdateOperator unary_expression
 		| AdditiveOperator unary_expression
 		| LogicalNotOperator unary_expression
+		| BitwiseNotOperator unary_expression
 	*/
 	fn base_unary_expression(&mut self) -> ResultWithError<Expression> {
+		if self.lookahead_type()? == TokenType::UpdateOperator {
+			let operator_token = self.eat(TokenType::UpdateOperator)?;
+			let argument = self.base_unary_expression()?;
+			return self.update_expression(&operator_token, true, argument, operator_token.span);
+		}
 		if !self.lookahead_type()?.is_unary_operator() {
-			return self.base_expression();
+			return self.exponent_expression();
+		}
+		let operator_token = self.eat_any()?;
+		let operator = Operator::try_from(&operator_token.data)?;
+		let argument = self.base_unary_expression()?;
+		return Ok(
+			Expression::unary_expression(operator, argument.into()).with_span(
+				self.span_from(operator_token.span)
+			)
+		);
+	}
+
+	/*
+	exponent_expression:
+		| base_expression
+		| base_expression ExponentOperator unary_expression
+	*/
+	// Right associative, and binding tighter than a unary operator to its left, so that `-2 ** 2`
+	// is `-(2 ** 2)` and `2 ** -1` is still valid.
+	fn exponent_expression(&mut self) -> ResultWithError<Expression> {
+		let base = self.base_expression()?;
+		if self.lookahead_type()? != TokenType::ExponentOperator {
+			return Ok(base);
 		}
-		let operator = Operator::try_from(&self.eat_any()?.data)?;
-		return Ok(Expression::unary_expression(operator, self.base_unary_expression()?.into()));
+		let op = self.eat(TokenType::ExponentOperator)?;
+		let start = base.span;
+		let exponent = self.base_unary_expression()?;
+		return Ok(
+			Expression::binary_expression(
+				Operator::try_from(&op.data)?,
+				base.into(),
+				exponent.into()
+			).with_span(self.span_from(start))
+		);
 	}
 
 	/*
 	base_expression:
-		| primary_expression
+		| call_or_member_expression
+		| call_or_member_expression UpdateOperator
 	 */
-	#[inline]
 	fn base_expression(&mut self) -> ResultWithError<Expression> {
-		return self.call_or_member_expression();
+		let expression = self.call_or_member_expression()?;
+		if self.lookahead_type()? != TokenType::UpdateOperator {
+			return Ok(expression);
+		}
+		let operator_token = self.eat(TokenType::UpdateOperator)?;
+		let start = expression.span;
+		return self.update_expression(&operator_token, false, expression, start);
+	}
+
+	fn update_expression(
+		&mut self,
+		operator_token: &Token,
+		prefix: bool,
+		argument: Expression,
+		start: Span
+	) -> ResultWithError<Expression> {
+		let is_updatable =
+			argument.is_lhs() && !matches!(argument.kind, ExpressionKind::DestructuringPattern(_));
+		ensure(is_updatable, ErrorT::ExpectedLhsExpression).map_err(|e| e.with_span(argument.span))?;
+		return Ok(
+			Expression::update_expression(
+				Operator::try_from(&operator_token.data)?,
+				prefix,
+				argument.into()
+			).with_span(self.span_from(start))
+		);
 	}
 
 	/*
@@ -578,23 +1584,52 @@ impl Parser {
 		| call_or_member_expression . Identifier
 		| call_or_member_expression '[' expression ']'
 		| call_or_member_expression '(' function_call_arguments ')'
+		| call_or_member_expression '?.' Identifier
+		| call_or_member_expression '?.' '[' expression ']'
 	*/
+	// A chain containing `?.` is wrapped as a whole, so that all of it is skipped when an object
+	// accessed with `?.` is null.
 	fn call_or_member_expression(&mut self) -> ResultWithError<Expression> {
 		let mut res = self.primary_or_super_expression()?;
+		let start = res.span;
+		let mut is_optional_chain = false;
 		while self.check_if_next_token_is_member_access_or_call_like()? {
+			if self.lookahead_type()? == TokenType::OptionalChaining {
+				res = self.optional_member_access_part(res)?;
+				is_optional_chain = true;
+				continue;
+			}
 			let (res2, changed) = self.member_access_part(res)?;
 			res = res2;
 			if !changed {
 				if self.lookahead_type()? == TokenType::OpenParen {
-					res = Expression::function_call(res.into(), self.function_call_args_in_parens()?);
+					let args = self.function_call_args_in_parens()?;
+					res = Expression::function_call(res.into(), args).with_span(self.span_from(start));
 				} else {
-					return Err(ErrorT::InvalidTokenType(self.eat_any()?).into());
+					return self.unexpected_token();
 				}
 			}
 		}
+		if is_optional_chain {
+			return Ok(Expression::optional_chain(res.into()).with_span(self.span_from(start)));
+		}
 		return Ok(res);
 	}
 
+	fn optional_member_access_part(&mut self, res: Expression) -> ResultWithError<Expression> {
+		let start = res.span;
+		self.eat(TokenType::OptionalChaining)?;
+		let member = if self.lookahead_type()? == TokenType::OpenSquareBracket {
+			self.eat(TokenType::OpenSquareBracket)?;
+			let expr = self.expression()?;
+			self.eat(TokenType::CloseSquareBracket)?;
+			MemberIndexer::SubscriptExpression(expr.into())
+		} else {
+			MemberIndexer::PropertyName(self.identifier()?)
+		};
+		return Ok(Expression::optional_member_access(res.into(), member).with_span(self.span_from(start)));
+	}
+
 	/*
 	member_expression:
 		| primary_expression
@@ -607,7 +1642,7 @@ impl Parser {
 			let (res2, changed) = self.member_access_part(res)?;
 			res = res2;
 			if !changed {
-				return Err(ErrorT::InvalidTokenType(self.eat_any()?).into());
+				return self.unexpected_token();
 			}
 		}
 		return Ok(res);
@@ -637,17 +1672,33 @@ impl Parser {
 	/*
 	function_call_args:
 		| !empty!
-		| expression
-		| expression ',' function_call_args
+		| function_call_arg
+		| function_call_arg ',' function_call_args
 	*/
 	#[inline]
 	fn function_call_args(&mut self) -> ResultWithError<Vec<Expression>> {
-		return self.delimited_items(Self::expression, TokenType::Comma, TokenType::CloseParen);
+		return self.delimited_items(Self::function_call_arg, TokenType::Comma, TokenType::CloseParen);
+	}
+
+	/*
+	function_call_arg:
+		| expression
+		| '...' expression
+	*/
+	fn function_call_arg(&mut self) -> ResultWithError<Expression> {
+		if self.lookahead_type()? != TokenType::Ellipsis {
+			return self.expression();
+		}
+		let start = self.eat(TokenType::Ellipsis)?.span;
+		let expr = self.expression()?;
+		return Ok(self.expression_from(start, ExpressionKind::SpreadExpression(expr.into())));
 	}
 
 	/*
 	primary_expression:
 		| literal
+		| array_literal
+		| object_literal
 		| parenthesized_expression
 		| identifier
 	*/
@@ -657,10 +1708,13 @@ impl Parser {
 			return self.literal();
 		}
 		return match token_type {
+			TokenType::OpenSquareBracket => self.array_literal(),
+			TokenType::OpenBlock => self.object_literal(),
 			TokenType::OpenParen => self.parenthesized_expression(),
 			TokenType::Keyword(Keyword::New) => self.new_expression(),
 			TokenType::Keyword(Keyword::Fn) => self.function_expression(),
 			TokenType::Keyword(Keyword::Class) => self.class_declaration_expression(),
+			TokenType::Keyword(Keyword::Match) => self.match_expression(),
 			_ => self.dotted_identifier_expression(),
 		};
 	}
@@ -668,10 +1722,33 @@ impl Parser {
 	/*
 	function_expression:
 		| function_declaration
+		| 'fn' function_parameters captures_list? block_statement
 	*/
-	#[inline]
 	fn function_expression(&mut self) -> ResultWithError<Expression> {
-		return Ok(Expression::FunctionExpression(self.function_declaration()?));
+		if self.lookahead_nth_type(1) == Some(TokenType::OpenParen) {
+			let start = self.eat(TokenType::Keyword(Keyword::Fn))?.span;
+			let params = self.function_parameters()?;
+			let captures = self.optional_captures_list()?;
+			let body = self.function_body()?;
+			return Ok(self.lambda_expression(start, params, captures, body));
+		}
+		let function_declaration = self.function_declaration()?;
+		let span = function_declaration.span;
+		return Ok(Expression {
+			kind: ExpressionKind::FunctionExpression(function_declaration),
+			span,
+		});
+	}
+
+	/*
+	match_expression:
+		| 'match' '(' expression ')' match_arms(assignment_expression)
+	*/
+	fn match_expression(&mut self) -> ResultWithError<Expression> {
+		let start = self.lookahead_span()?;
+		let value = self.match_value()?;
+		let arms = self.match_arms(Self::assignment_expression, |_| false)?;
+		return Ok(Expression::match_expression(value.into(), arms).with_span(self.span_from(start)));
 	}
 
 	/*
@@ -680,7 +1757,12 @@ impl Parser {
 	*/
 	#[inline]
 	fn class_declaration_expression(&mut self) -> ResultWithError<Expression> {
-		return Ok(Expression::ClassDeclarationExpression(self.class_declaration()?.into()));
+		let class_declaration = self.class_declaration()?;
+		let span = class_declaration.span;
+		return Ok(Expression {
+			kind: ExpressionKind::ClassDeclarationExpression(class_declaration.into()),
+			span,
+		});
 	}
 
 	/*
@@ -688,10 +1770,12 @@ impl Parser {
 		| 'new' member_expression '(' function_call_arguments ')'
 	*/
 	fn new_expression(&mut self) -> ResultWithError<Expression> {
-		self.eat(TokenType::Keyword(Keyword::New))?;
+		let start = self.eat(TokenType::Keyword(Keyword::New))?.span;
 		let class_val = self.member_expression()?;
 		let args = self.function_call_args_in_parens()?;
-		return Ok(Expression::new_object_expression(class_val.into(), args));
+		return Ok(
+			Expression::new_object_expression(class_val.into(), args).with_span(self.span_from(start))
+		);
 	}
 
 	// #[inline]
@@ -701,12 +1785,14 @@ impl Parser {
 	// }
 
 	fn dotted_identifier_expression(&mut self) -> ResultWithError<Expression> {
+		let start = self.lookahead_span()?;
 		let mut res = self.dotted_identifiers()?;
-		return if res.identifiers.len() == 1 {
-			Ok(Expression::Identifier(res.identifiers.pop().unwrap()))
+		let kind = if res.identifiers.len() == 1 {
+			ExpressionKind::Identifier(res.identifiers.pop().unwrap())
 		} else {
-			Ok(Expression::DottedIdentifiers(res))
+			ExpressionKind::DottedIdentifiers(res)
 		};
+		return Ok(self.expression_from(start, kind));
 	}
 
 	/*
@@ -714,16 +1800,79 @@ impl Parser {
 		| '(' expression ')'
 	*/
 	fn parenthesized_expression(&mut self) -> ResultWithError<Expression> {
-		self.eat(TokenType::OpenParen)?;
+		let start = self.eat(TokenType::OpenParen)?.span;
 		let res = self.expression()?;
 		self.eat(TokenType::CloseParen)?;
-		return Ok(Expression::ParenthesizedExpression(res.into()));
+		return Ok(self.expression_from(start, ExpressionKind::ParenthesizedExpression(res.into())));
+	}
+
+	/*
+	array_literal:
+		| '[' delimited_items(expression, ',') ']'
+	*/
+	fn array_literal(&mut self) -> ResultWithError<Expression> {
+		let start = self.eat(TokenType::OpenSquareBracket)?.span;
+		let items = self.delimited_items(
+			Self::expression,
+			TokenType::Comma,
+			TokenType::CloseSquareBracket
+		)?;
+		self.eat(TokenType::CloseSquareBracket)?;
+		return Ok(self.expression_from(start, ExpressionKind::ArrayLiteral(items)));
+	}
+
+	/*
+	object_literal:
+		| '{' delimited_items(object_property, ',') '}'
+	*/
+	fn object_literal(&mut self) -> ResultWithError<Expression> {
+		let start = self.eat(TokenType::OpenBlock)?.span;
+		let properties = self.delimited_items(
+			Self::object_property,
+			TokenType::Comma,
+			TokenType::CloseBlock
+		)?;
+		self.eat(TokenType::CloseBlock)?;
+		return Ok(self.expression_from(start, ExpressionKind::ObjectLiteral(properties)));
+	}
+
+	/*
+	object_property:
+		| object_property_key ':' expression
+	*/
+	fn object_property(&mut self) -> ResultWithError<(MemberIndexer, Expression)> {
+		let key = self.object_property_key()?;
+		self.eat(TokenType::Colon)?;
+		return Ok((key, self.expression()?));
+	}
+
+	/*
+	object_property_key:
+		| Identifier
+		| string_literal
+		| '[' expression ']'
+	*/
+	fn object_property_key(&mut self) -> ResultWithError<MemberIndexer> {
+		return Ok(match self.lookahead_type()? {
+			TokenType::Identifier => MemberIndexer::PropertyName(self.identifier()?),
+			TokenType::String => MemberIndexer::PropertyName(self.string_value()?),
+			TokenType::OpenSquareBracket => {
+				self.eat(TokenType::OpenSquareBracket)?;
+				let expr = self.expression()?;
+				self.eat(TokenType::CloseSquareBracket)?;
+				MemberIndexer::SubscriptExpression(expr.into())
+			}
+			_ => {
+				return self.unexpected_token();
+			}
+		});
 	}
 
 	/*
 	literal:
 		| numeric_literal
 		| string_literal
+		| template_literal
 		| 'true'
 		| 'false'
 	*/
@@ -731,32 +1880,83 @@ impl Parser {
 		return match self.lookahead_type()? {
 			TokenType::Number => self.numeric_literal(),
 			TokenType::String => self.string_literal(),
+			TokenType::TemplateString => self.template_literal(),
 			_ => self.singular_literal(),
 		};
 	}
 
 	fn singular_literal(&mut self) -> ResultWithError<Expression> {
-		let res = match self.lookahead_type()? {
-			TokenType::Keyword(Keyword::True) => Ok(Expression::BooleanLiteral(true)),
-			TokenType::Keyword(Keyword::False) => Ok(Expression::BooleanLiteral(false)),
-			TokenType::Keyword(Keyword::Null) => Ok(Expression::NullLiteral),
-			_ => Err(ErrorT::InvalidTokenType(self.eat_any()?).into()),
+		let kind = match self.lookahead_type()? {
+			TokenType::Keyword(Keyword::True) => ExpressionKind::BooleanLiteral(true),
+			TokenType::Keyword(Keyword::False) => ExpressionKind::BooleanLiteral(false),
+			TokenType::Keyword(Keyword::Null) => ExpressionKind::NullLiteral,
+			_ => {
+				return self.unexpected_token();
+			}
 		};
-		if res.is_ok() {
-			self.eat_any()?;
-		}
-		return res;
+		let token = self.eat_any()?;
+		return Ok(Expression { kind, span: token.span });
 	}
 
+	/*
+	string_literal:
+		| '"' characters '"'
+		| "'" characters "'"
+		| '"""' characters '"""'
+		| 'r"' raw_characters '"'
+	*/
 	fn string_literal(&mut self) -> ResultWithError<Expression> {
+		let start = self.lookahead_span()?;
+		let value = self.string_value()?;
+		return Ok(self.expression_from(start, ExpressionKind::StringLiteral(value)));
+	}
+
+	fn string_value(&mut self) -> ResultWithError<StringT> {
 		let v = self.eat(TokenType::String)?;
-		let rep_v = v.data[1..v.data.len() - 1].replace("\\\"", "\"");
-		return Ok(Expression::StringLiteral(rep_v));
+		let data = v.data.as_str();
+		if let Some(raw) = data.strip_prefix("r\"") {
+			return Ok(raw[..raw.len() - 1].into());
+		}
+		let quote_len = if data.starts_with("\"\"\"") { 3 } else { 1 };
+		return unescape_string(&v, quote_len, data.len() - quote_len);
+	}
+
+	/*
+	template_literal:
+		| '`' (characters | '${' expression '}')* '`'
+	*/
+	fn template_literal(&mut self) -> ResultWithError<Expression> {
+		let token = self.eat(TokenType::TemplateString)?;
+		let (_, parts) = template_string(&token.data).ok_or_else(|| {
+			EvilangError::new(ErrorT::TokenCannotBeParsed).with_span(token.span)
+		})?;
+		let mut strings = vec![];
+		let mut expressions = vec![];
+		for part in parts.into_iter() {
+			match part {
+				TemplatePart::Text(range) => {
+					strings.push(unescape_string(&token, range.start, range.end)?);
+				}
+				TemplatePart::Expression(range) => {
+					let span = token.span.sub_span(&token.data, range.start, range.end);
+					let stream = TokenStream::new_embedded(token.data[range].into(), span);
+					let mut parser = Parser::new(stream);
+					expressions.push(parser.expression()?);
+					parser.eat(TokenType::_EOFDummy)?;
+				}
+			}
+		}
+		return Ok(Expression {
+			kind: ExpressionKind::TemplateLiteral { strings, expressions },
+			span: token.span,
+		});
 	}
 
 	fn numeric_literal(&mut self) -> ResultWithError<Expression> {
 		let v = self.eat(TokenType::Number)?;
-		return Expression::numeric_literal(v.data.as_str());
+		return Ok(
+			Expression::numeric_literal(v.data.as_str()).map_err(|e| e.with_span(v.span))?.with_span(v.span)
+		);
 	}
 
 	// region ...Utilities
@@ -771,6 +1971,7 @@ impl Parser {
 		expression_operator_token_type: TokenType
 	) -> ResultWithError<Expression> {
 		let mut left = sub_expression(self)?;
+		let start = left.span;
 		while self.lookahead_type()? == expression_operator_token_type {
 			let op = self.eat(expression_operator_token_type)?;
 			let right = sub_expression(self)?;
@@ -778,7 +1979,7 @@ impl Parser {
 				Operator::try_from(&op.data)?,
 				BoxExpression::from(left),
 				BoxExpression::from(right)
-			);
+			).with_span(self.span_from(start));
 		}
 		return Ok(left);
 	}
@@ -839,20 +2040,28 @@ impl Parser {
 	fn check_if_next_token_is_member_access_or_call_like(&mut self) -> ResultWithError<bool> {
 		return Ok(
 			self.check_if_next_token_is_member_access_like()? ||
-				matches!(self.lookahead_type()?, TokenType::OpenParen)
+				matches!(self.lookahead_type()?, TokenType::OpenParen | TokenType::OptionalChaining)
 		);
 	}
 
 	fn member_access_part(&mut self, res: Expression) -> ResultWithError<(Expression, bool)> {
+		let start = res.span;
 		if self.lookahead_type()? == TokenType::Dot {
 			self.eat(TokenType::Dot)?;
 			let property_name = self.identifier()?;
-			return Ok((Expression::member_property_access(res.into(), property_name), true));
+			return Ok((
+				Expression::member_property_access(res.into(), property_name).with_span(
+					self.span_from(start)
+				),
+				true,
+			));
 		} else if self.lookahead_type()? == TokenType::DoubleColon {
 			self.eat(TokenType::DoubleColon)?;
 			let property_name = self.identifier()?;
 			return Ok((
-				Expression::member_property_access(res.into(), property_name).consume_as_parenthesized(),
+				Expression::member_property_access(res.into(), property_name)
+					.with_span(self.span_from(start))
+					.consume_as_parenthesized(),
 				true,
 			));
 		} else if
@@ -866,7 +2075,10 @@ impl Parser {
 			let expr = self.expression()?.into();
 			self.eat(TokenType::CloseSquareBracket)?;
 
-			return Ok((Expression::member_subscript(res.into(), expr), true));
+			return Ok((
+				Expression::member_subscript(res.into(), expr).with_span(self.span_from(start)),
+				true,
+			));
 		}
 		return Ok((res, false));
 	}
@@ -893,3 +2105,42 @@ impl Parser {
 	}
 	// endregion
 }
+
+/// Processes the escape sequences in the bytes `from..to` of a string literal token.
+fn unescape_string(token: &Token, from: usize, to: usize) -> ResultWithError<StringT> {
+	let data = token.data.as_str();
+	let mut res = StringT::with_capacity(to - from);
+	let mut chars = data[..to].char_indices().skip_while(|(i, _)| *i < from).peekable();
+	while let Some((i, ch)) = chars.next() {
+		if ch != '\\' {
+			res.push(ch);
+			continue;
+		}
+		let escaped = match chars.next().map(|(_, v)| v) {
+			Some('n') => Some('\n'),
+			Some('t') => Some('\t'),
+			Some('r') => Some('\r'),
+			Some('0') => Some('\0'),
+			Some(v @ ('\\' | '"' | '\'' | '`' | '$')) => Some(v),
+			Some('u') if chars.next_if(|(_, v)| *v == '{').is_some() => {
+				let mut code = StringT::new();
+				while let Some((_, v)) = chars.next_if(|(_, v)| v.is_ascii_hexdigit()) {
+					code.push(v);
+				}
+				let closed = chars.next_if(|(_, v)| *v == '}').is_some();
+				u32::from_str_radix(&code, 16)
+					.ok()
+					.filter(|_| closed && code.len() <= 6)
+					.and_then(char::from_u32)
+			}
+			_ => None,
+		};
+		let Some(escaped) = escaped else {
+			let end = chars.peek().map_or(to, |(v, _)| *v);
+			let span = token.span.sub_span(data, i, end);
+			return Err(EvilangError::new(ErrorT::InvalidEscapeSequence(data[i..end].into())).with_span(span));
+		};
+		res.push(escaped);
+	}
+	return Ok(res);
+}
@@ -1,126 +1,305 @@
-use regex::Regex;
+use std::ops::Range;
 
 use crate::tokenizer::{ Keyword, TokenType };
 
-//language=regexp
-const NUMBER_REGEX: &str = r"^[-+]?[0-9]*\.?[0-9]+([eE][-+]?[0-9]+)?";
-//language=regexp
-const WHITESPACE_REGEX: &str = r"^[\s\r\n]+";
-//language=regexp
-// const INTEGER_REGEX: &str = r"^\d+";
-//language=regexp
-const STRING_REGEX: &str = r#"^("[^"\\]*(?:\\.[^"\\]*)*")"#;
-//language=regexp
-const SINGLE_LINE_COMMENT_REGEX: &str = r#"^//.*"#;
-//language=regexp
-const MULTI_LINE_COMMENT_REGEX: &str = r#"^/\*[^*]*\*+(?:[^/*][^*]*\*+)*/"#;
-//language=regexp
-const IDENTIFIER_REGEX: &str = r#"^[a-zA-Z_$][a-zA-Z0-9_$]*"#;
-
-pub(super) type Matcher = Box<dyn Fn(&str) -> Option<&str>>;
+/// A matched token: the number of bytes it spans, and its type, or [`None`] for whitespace and
+/// comments, which are skipped.
+pub(super) type Match = (usize, Option<TokenType>);
 
+#[inline(always)]
+fn is_identifier_start(ch: char) -> bool {
+	return ch.is_ascii_alphabetic() || matches!(ch, '_' | '$');
+}
+
+#[inline(always)]
 fn is_identifier_char(ch: char) -> bool {
-	if ch.is_alphanumeric() {
-		return true;
-	}
-	return matches!(ch, '_' | '$');
+	return ch.is_ascii_alphanumeric() || matches!(ch, '_' | '$');
+}
+
+/// Characters that prevent the preceding word from being read as a keyword.
+#[inline(always)]
+fn is_keyword_boundary(ch: char) -> bool {
+	return !ch.is_alphanumeric() && !matches!(ch, '_' | '$');
 }
 
-fn regex_matcher(regex_str: &str) -> Matcher {
-	let reg = Regex::new(regex_str).unwrap();
-	return Box::new(move |s: &str| Some(reg.find(s)?.as_str()));
+fn keyword(word: &str) -> Option<Keyword> {
+	return Some(match word {
+		"let" => Keyword::Let,
+		"if" => Keyword::If,
+		"else" => Keyword::Else,
+		"true" => Keyword::True,
+		"false" => Keyword::False,
+		"null" => Keyword::Null,
+		"while" => Keyword::While,
+		"do" => Keyword::Do,
+		"for" => Keyword::For,
+		"fn" => Keyword::Fn,
+		"captures" => Keyword::Captures,
+		"return" => Keyword::Return,
+		"class" => Keyword::Class,
+		"extends" => Keyword::Extends,
+		"new" => Keyword::New,
+		"break" => Keyword::Break,
+		"continue" => Keyword::Continue,
+		"namespace" => Keyword::Namespace,
+		"import" => Keyword::Import,
+		"as" => Keyword::As,
+		"throw" => Keyword::Throw,
+		"try" => Keyword::Try,
+		"catch" => Keyword::Catch,
+		"finally" => Keyword::Finally,
+		"match" => Keyword::Match,
+		"in" => Keyword::In,
+		"of" => Keyword::Of,
+		"const" => Keyword::Const,
+		_ => return None,
+	});
 }
 
-fn starts_with_matcher(start: &'static str) -> Matcher {
-	return Box::new(move |s: &str| if s.starts_with(start) { Some(start) } else { None });
+/// Number of leading bytes of `s` that are ASCII digits or digit separators, provided that the
+/// first one is a digit.
+#[inline(always)]
+fn digits(s: &[u8]) -> usize {
+	if !s.first().is_some_and(u8::is_ascii_digit) {
+		return 0;
+	}
+	return s
+		.iter()
+		.take_while(|v| v.is_ascii_digit() || **v == b'_')
+		.count();
 }
 
-fn keyword_matcher(start: &'static str) -> Matcher {
-	let start_code_points_len = start.chars().count();
-	return Box::new(move |s: &str| (
-		if s.starts_with(start) {
-			if
-				s
-					.chars()
-					.nth(start_code_points_len)
-					.is_some_and(|v| !is_identifier_char(v))
-			{
-				Some(start)
-			} else {
-				None
+/* number:
+	| '0' [xob] [a-zA-Z0-9_]*
+	| [0-9] [0-9_]* ("." [0-9] [0-9_]*)? ([eE] [-+]? [0-9] [0-9_]*)? suffix
+	;
+suffix:
+	| [a-zA-Z0-9_]*
+	;
+*/
+/// Anything that looks like it continues a number is lexed as part of it, the parser then
+/// rejects invalid digits and suffixes.
+fn number(s: &str) -> usize {
+	let bytes = s.as_bytes();
+	let mut len = if matches!(bytes, [b'0', b'x' | b'o' | b'b', ..]) {
+		2
+	} else {
+		let mut len = digits(bytes);
+		if bytes.get(len) == Some(&b'.') {
+			let fraction = digits(&bytes[len + 1..]);
+			if fraction > 0 {
+				len += 1 + fraction;
 			}
-		} else {
-			None
 		}
-	));
+		if matches!(bytes.get(len), Some(b'e' | b'E')) {
+			let sign = usize::from(matches!(bytes.get(len + 1), Some(b'-' | b'+')));
+			let exponent = digits(&bytes[(len + 1 + sign).min(bytes.len())..]);
+			if exponent > 0 {
+				len += 1 + sign + exponent;
+			}
+		}
+		len
+	};
+	len += bytes[len..]
+		.iter()
+		.take_while(|v| v.is_ascii_alphanumeric() || **v == b'_')
+		.count();
+	return len;
 }
 
-fn one_of_many<const COUNT: usize>(starters: [&'static str; COUNT]) -> Matcher {
-	return Box::new(move |s: &str| {
-		for starter in starters.iter() {
-			let start = *starter;
-			if s.starts_with(start) {
-				return Some(start);
-			}
+/* string:
+	| '"""' ([^\\] | '\\' .)* '"""'
+	| '"' ([^"\\] | '\\' .)* '"'
+	| "'" ([^'\\] | '\\' .)* "'"
+	| 'r"' [^"]* '"'
+	;
+*/
+fn string(s: &str) -> Option<usize> {
+	if let Some(rest) = s.strip_prefix("r\"") {
+		return rest.find('"').map(|v| v + 3);
+	}
+	let (quote, content) = if let Some(rest) = s.strip_prefix("\"\"\"") {
+		("\"\"\"", rest)
+	} else {
+		let quote = if s.starts_with('\'') { "'" } else { "\"" };
+		(quote, &s[1..])
+	};
+	// Escapes are only validated by the parser, here they just keep the quote they precede from
+	// ending the string.
+	let mut chars = content.char_indices();
+	while let Some((i, ch)) = chars.next() {
+		if ch == '\\' {
+			chars.next()?;
+		} else if content[i..].starts_with(quote) {
+			return Some(s.len() - content.len() + i + quote.len());
 		}
-		return None;
-	});
+	}
+	return None;
+}
+
+/// A part of a template string, as the range of its bytes that holds either (escaped) text or the
+/// source code of an embedded expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TemplatePart {
+	Text(Range<usize>),
+	Expression(Range<usize>),
+}
+
+/* template_string:
+	| '`' ([^`\\$] | '\\' . | '$' [^{] | '${' token* '}')* '`'
+	;
+*/
+/// Splits the template string at the start of `s` into its parts, also returning its length.
+pub(crate) fn template_string(s: &str) -> Option<(usize, Vec<TemplatePart>)> {
+	let mut parts = vec![];
+	let mut text_start = 1;
+	let mut position = 1;
+	while position < s.len() {
+		let rest = &s[position..];
+		if rest.starts_with('`') {
+			parts.push(TemplatePart::Text(text_start..position));
+			return Some((position + 1, parts));
+		}
+		if rest.starts_with("${") {
+			parts.push(TemplatePart::Text(text_start..position));
+			let len = interpolation(&s[position + 2..])?;
+			parts.push(TemplatePart::Expression(position + 2..position + 1 + len));
+			position += 2 + len;
+			text_start = position;
+			continue;
+		}
+		let mut chars = rest.chars();
+		let ch = chars.next()?;
+		position += ch.len_utf8();
+		if ch == '\\' {
+			position += chars.next()?.len_utf8();
+		}
+	}
+	return None;
+}
+
+/// Length of the embedded expression of a template string up to and including its closing `}`.
+/// Its tokens are lexed to find where it ends, so strings and blocks inside it may contain `}`.
+fn interpolation(s: &str) -> Option<usize> {
+	let mut depth = 1;
+	let mut position = 0;
+	while position < s.len() {
+		let rest = &s[position..];
+		// Characters that can't be lexed are left for the parser to report.
+		let (len, typ) = match_token(rest).unwrap_or((rest.chars().next()?.len_utf8(), None));
+		position += len;
+		match typ {
+			Some(TokenType::OpenBlock) => depth += 1,
+			Some(TokenType::CloseBlock) => depth -= 1,
+			_ => {}
+		}
+		if depth == 0 {
+			return Some(position);
+		}
+	}
+	return None;
+}
+
+/// Length of the comment at the start of `s`, if there is one. An unterminated multi-line
+/// comment is not a comment, and gets lexed as operators instead.
+fn comment(s: &str) -> Option<usize> {
+	if s.starts_with("//") {
+		return Some(s.find('\n').unwrap_or(s.len()));
+	}
+	if let Some(rest) = s.strip_prefix("/*") {
+		return rest.find("*/").map(|v| v + 4);
+	}
+	return None;
 }
 
-pub(super) fn get_token_matchers() -> Vec<(Matcher, Option<TokenType>)> {
-	let regex_str_with_type = vec![
-		(regex_matcher(WHITESPACE_REGEX), None),
-		(regex_matcher(SINGLE_LINE_COMMENT_REGEX), None),
-		(regex_matcher(MULTI_LINE_COMMENT_REGEX), None),
-		//
-		(starts_with_matcher(";"), Some(TokenType::Semicolon)),
-		(starts_with_matcher("{"), Some(TokenType::OpenBlock)),
-		(starts_with_matcher("}"), Some(TokenType::CloseBlock)),
-		(starts_with_matcher("("), Some(TokenType::OpenParen)),
-		(starts_with_matcher(")"), Some(TokenType::CloseParen)),
-		(starts_with_matcher("["), Some(TokenType::OpenSquareBracket)),
-		(starts_with_matcher("]"), Some(TokenType::CloseSquareBracket)),
-		(starts_with_matcher(","), Some(TokenType::Comma)),
-		(starts_with_matcher("."), Some(TokenType::Dot)),
-		(starts_with_matcher("::"), Some(TokenType::DoubleColon)),
-		// (starts_with_matcher("->"), Some(TokenType::Arrow)),
-		//
-		(one_of_many(["==", "!="]), Some(TokenType::EqualityOperator)),
-		(starts_with_matcher("&&"), Some(TokenType::LogicalAndOperator)),
-		(starts_with_matcher("||"), Some(TokenType::LogicalOrOperator)),
-		(starts_with_matcher("!"), Some(TokenType::LogicalNotOperator)),
-		(one_of_many(["=", "+=", "-=", "*=", "/=", "%="]), Some(TokenType::AssignmentOperator)),
-		(one_of_many(["*", "/", "%"]), Some(TokenType::MultiplicativeOperator)),
-		(one_of_many(["+", "-"]), Some(TokenType::AdditiveOperator)),
-		(one_of_many(["<=", ">="]), Some(TokenType::RelationalOperator)),
-		(one_of_many(["<", ">"]), Some(TokenType::RelationalOperator)),
-		//
-		(regex_matcher(NUMBER_REGEX), Some(TokenType::Number)),
-		// (regex_matcher(INTEGER_REGEX), Some(TokenType::Integer)),
-		(regex_matcher(STRING_REGEX), Some(TokenType::String)),
-		//
-		(keyword_matcher("let"), Some(TokenType::Keyword(Keyword::Let))),
-		(keyword_matcher("if"), Some(TokenType::Keyword(Keyword::If))),
-		(keyword_matcher("else"), Some(TokenType::Keyword(Keyword::Else))),
-		(keyword_matcher("true"), Some(TokenType::Keyword(Keyword::True))),
-		(keyword_matcher("false"), Some(TokenType::Keyword(Keyword::False))),
-		(keyword_matcher("null"), Some(TokenType::Keyword(Keyword::Null))),
-		(keyword_matcher("while"), Some(TokenType::Keyword(Keyword::While))),
-		(keyword_matcher("do"), Some(TokenType::Keyword(Keyword::Do))),
-		(keyword_matcher("for"), Some(TokenType::Keyword(Keyword::For))),
-		(keyword_matcher("fn"), Some(TokenType::Keyword(Keyword::Fn))),
-		(keyword_matcher("captures"), Some(TokenType::Keyword(Keyword::Captures))),
-		(keyword_matcher("return"), Some(TokenType::Keyword(Keyword::Return))),
-		(keyword_matcher("class"), Some(TokenType::Keyword(Keyword::Class))),
-		(keyword_matcher("extends"), Some(TokenType::Keyword(Keyword::Extends))),
-		(keyword_matcher("new"), Some(TokenType::Keyword(Keyword::New))),
-		(keyword_matcher("break"), Some(TokenType::Keyword(Keyword::Break))),
-		(keyword_matcher("continue"), Some(TokenType::Keyword(Keyword::Continue))),
-		(keyword_matcher("namespace"), Some(TokenType::Keyword(Keyword::Namespace))),
-		(keyword_matcher("import"), Some(TokenType::Keyword(Keyword::Import))),
-		(keyword_matcher("as"), Some(TokenType::Keyword(Keyword::As))),
-		//
-		(regex_matcher(IDENTIFIER_REGEX), Some(TokenType::Identifier))
-	];
-	return regex_str_with_type;
+fn identifier_or_keyword(s: &str) -> Match {
+	let len = s
+		.char_indices()
+		.find(|(_, ch)| !is_identifier_char(*ch))
+		.map_or(s.len(), |(i, _)| i);
+	let word = &s[..len];
+	let keyword = keyword(word).filter(|_| s[len..].chars().next().is_none_or(is_keyword_boundary));
+	if let Some(keyword) = keyword {
+		return (len, Some(TokenType::Keyword(keyword)));
+	}
+	return (len, Some(TokenType::Identifier));
+}
+
+/// Whether all of `name` would be lexed as a single identifier.
+pub(crate) fn is_identifier(name: &str) -> bool {
+	return match_token(name) == Some((name.len(), Some(TokenType::Identifier)));
+}
+
+/// Matches the token at the start of `s`, deciding what to lex from its first character.
+/// Returns [`None`] when no token starts there.
+pub(super) fn match_token(s: &str) -> Option<Match> {
+	if let Some(len) = comment(s) {
+		return Some((len, None));
+	}
+	let mut chars = s.chars();
+	let first = chars.next()?;
+	let second = chars.next();
+	let third = chars.next();
+	let token = |len: usize, typ: TokenType| Some((len, Some(typ)));
+	return match (first, second) {
+		(ch, _) if ch.is_whitespace() => {
+			let len = s
+				.char_indices()
+				.find(|(_, ch)| !ch.is_whitespace())
+				.map_or(s.len(), |(i, _)| i);
+			Some((len, None))
+		}
+		(';', _) => token(1, TokenType::Semicolon),
+		('{', _) => token(1, TokenType::OpenBlock),
+		('}', _) => token(1, TokenType::CloseBlock),
+		('(', _) => token(1, TokenType::OpenParen),
+		(')', _) => token(1, TokenType::CloseParen),
+		('[', _) => token(1, TokenType::OpenSquareBracket),
+		(']', _) => token(1, TokenType::CloseSquareBracket),
+		(',', _) => token(1, TokenType::Comma),
+		('.', Some('.')) if third == Some('.') => token(3, TokenType::Ellipsis),
+		('.', _) => token(1, TokenType::Dot),
+		(':', Some(':')) => token(2, TokenType::DoubleColon),
+		(':', _) => token(1, TokenType::Colon),
+		('=' | '!', Some('=')) => token(2, TokenType::EqualityOperator),
+		('?' | '&' | '|', Some(ch)) if ch == first && third == Some('=') => {
+			token(3, TokenType::AssignmentOperator)
+		}
+		('?', Some('?')) => token(2, TokenType::NullishCoalescingOperator),
+		('?', Some('.')) => token(2, TokenType::OptionalChaining),
+		('?', _) => token(1, TokenType::QuestionMark),
+		('&' | '|' | '^', Some('=')) => token(2, TokenType::AssignmentOperator),
+		('&', Some('&')) => token(2, TokenType::LogicalAndOperator),
+		('|', Some('|')) => token(2, TokenType::LogicalOrOperator),
+		('&', _) => token(1, TokenType::Ampersand),
+		('|', _) => token(1, TokenType::Pipe),
+		('^', _) => token(1, TokenType::BitwiseXorOperator),
+		// `~/` is floor division, unless the `/` starts a comment
+		('~', Some('/')) if third == Some('=') => token(3, TokenType::AssignmentOperator),
+		('~', Some('/')) if !matches!(third, Some('/' | '*')) => {
+			token(2, TokenType::MultiplicativeOperator)
+		}
+		('~', _) => token(1, TokenType::BitwiseNotOperator),
+		('!', _) => token(1, TokenType::LogicalNotOperator),
+		('=', Some('>')) => token(2, TokenType::FatArrow),
+		('=', _) => token(1, TokenType::AssignmentOperator),
+		('*' | '<' | '>', Some(ch)) if ch == first && third == Some('=') => {
+			token(3, TokenType::AssignmentOperator)
+		}
+		('*', Some('*')) => token(2, TokenType::ExponentOperator),
+		('<' | '>', Some(ch)) if ch == first => token(2, TokenType::ShiftOperator),
+		('+' | '-', Some(ch)) if ch == first => token(2, TokenType::UpdateOperator),
+		('+' | '-' | '*' | '/' | '%', Some('=')) => token(2, TokenType::AssignmentOperator),
+		('*' | '/' | '%', _) => token(1, TokenType::MultiplicativeOperator),
+		('+' | '-', _) => token(1, TokenType::AdditiveOperator),
+		('<' | '>', Some('=')) => token(2, TokenType::RelationalOperator),
+		('<' | '>', _) => token(1, TokenType::RelationalOperator),
+		('0'..='9', _) => token(number(s), TokenType::Number),
+		('"' | '\'', _) => token(string(s)?, TokenType::String),
+		('`', _) => token(template_string(s)?.0, TokenType::TemplateString),
+		// An unterminated raw string is just an `r` followed by an unterminated string.
+		('r', Some('"')) if let Some(len) = string(s) => token(len, TokenType::String),
+		(ch, _) if is_identifier_start(ch) => Some(identifier_or_keyword(s)),
+		_ => None,
+	};
 }
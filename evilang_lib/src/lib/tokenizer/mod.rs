@@ -1,21 +1,56 @@
-use crate::errors::{ ErrorT, ResultWithError };
-use crate::tokenizer::matchers::{ get_token_matchers, Matcher };
+use std::fmt::{ Display, Formatter };
+
+use crate::errors::{ ErrorT, EvilangError, ResultWithError };
+use crate::tokenizer::matchers::match_token;
+pub(crate) use crate::tokenizer::matchers::{ is_identifier, template_string, TemplatePart };
 pub use crate::tokenizer::token::{ Keyword, TokenType };
+use crate::types::source_map::ANONYMOUS_FILE_ID;
+use crate::types::span::{ FileIdT, Span };
 use crate::types::string::StringT;
 
 mod matchers;
 mod token;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Token {
 	pub typ: TokenType,
 	pub data: StringT,
+	pub span: Span,
+}
+
+/// Tokens are compared by their type and contents only, the position they were found at is ignored.
+impl PartialEq for Token {
+	fn eq(&self, other: &Self) -> bool {
+		return self.typ == other.typ && self.data == other.data;
+	}
+}
+
+impl Token {
+	#[inline(always)]
+	pub fn new(typ: TokenType, data: StringT) -> Self {
+		Self { typ, data, span: Span::default() }
+	}
+}
+
+impl Display for Token {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		if self.typ == TokenType::_EOFDummy {
+			f.write_str("end of input")
+		} else {
+			write!(f, "`{}`", self.data)
+		}
+	}
 }
 
 pub struct TokenStream {
 	str: StringT,
 	position: usize,
-	token_matchers: Vec<(Matcher, Option<TokenType>)>,
+	file_id: FileIdT,
+	/// Position of `str` in its file, for source code embedded in other tokens.
+	offset: usize,
+	first_line_column: usize,
+	line: usize,
+	line_start: usize,
 	sent_eof_dummy: bool,
 }
 
@@ -23,26 +58,31 @@ impl Iterator for TokenStream {
 	type Item = ResultWithError<Token>;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		'outer: loop {
+		loop {
 			if self.position >= self.str.len() {
 				return if self.sent_eof_dummy {
 					None
 				} else {
-					Some(Ok(Token { typ: TokenType::_EOFDummy, data: StringT::new() }))
+					self.sent_eof_dummy = true;
+					let span = self.span_to(self.position);
+					Some(Ok(Token { typ: TokenType::_EOFDummy, data: StringT::new(), span }))
 				};
 			}
 			let from = &self.str[self.position..];
-			for (matcher, token_t) in self.token_matchers.iter() {
-				let Some(s) = matcher(from) else {
-					continue;
-				};
-				self.position += s.len();
-				let Some(token_type) = token_t else {
-					continue 'outer;
-				};
-				return Some(Ok(Token { typ: *token_type, data: s.parse().unwrap() }));
-			}
-			return Some(Err(ErrorT::TokenCannotBeParsed.into()));
+			let Some((len, token_t)) = match_token(from) else {
+				// Skip the offending character, so that the rest of the input can still be tokenized.
+				let len = from.chars().next().map_or(1, char::len_utf8);
+				let span = self.span_to(self.position + len);
+				self.advance(len);
+				return Some(Err(EvilangError::new(ErrorT::TokenCannotBeParsed).with_span(span)));
+			};
+			let data: StringT = self.str[self.position..self.position + len].into();
+			let span = self.span_to(self.position + len);
+			self.advance(len);
+			let Some(token_type) = token_t else {
+				continue;
+			};
+			return Some(Ok(Token { typ: token_type, data, span }));
 		}
 	}
 }
@@ -50,11 +90,50 @@ impl Iterator for TokenStream {
 impl TokenStream {
 	#[inline(always)]
 	pub fn new(str: StringT) -> TokenStream {
+		return TokenStream::new_with_file_id(str, ANONYMOUS_FILE_ID);
+	}
+
+	#[inline(always)]
+	pub fn new_with_file_id(str: StringT, file_id: FileIdT) -> TokenStream {
+		return TokenStream::new_embedded(str, Span::new(file_id, 0, 0, 1, 1));
+	}
+
+	/// Tokenizes source code embedded in a larger file, such as an expression inside a template
+	/// string, where `start` is the position in the file at which `str` begins.
+	pub fn new_embedded(str: StringT, start: Span) -> TokenStream {
 		return TokenStream {
 			str,
 			position: 0,
-			token_matchers: get_token_matchers(),
+			file_id: start.file_id,
+			offset: start.start,
+			first_line_column: start.column,
+			line: start.line,
+			line_start: 0,
 			sent_eof_dummy: false,
 		};
 	}
+
+	#[inline(always)]
+	pub fn file_id(&self) -> FileIdT {
+		return self.file_id;
+	}
+
+	fn span_to(&self, end: usize) -> Span {
+		let mut column = self.str[self.line_start..self.position].chars().count() + 1;
+		if self.line_start == 0 {
+			column += self.first_line_column - 1;
+		}
+		return Span::new(self.file_id, self.offset + self.position, self.offset + end, self.line, column);
+	}
+
+	fn advance(&mut self, by: usize) {
+		let consumed = &self.str[self.position..self.position + by];
+		for (i, ch) in consumed.char_indices() {
+			if ch == '\n' {
+				self.line += 1;
+				self.line_start = self.position + i + 1;
+			}
+		}
+		self.position += by;
+	}
 }
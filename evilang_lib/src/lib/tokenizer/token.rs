@@ -20,6 +20,14 @@ pub enum Keyword {
 	Namespace,
 	Import,
 	As,
+	Throw,
+	Try,
+	Catch,
+	Finally,
+	Match,
+	In,
+	Of,
+	Const,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -28,16 +36,23 @@ pub enum TokenType {
 
 	Number,
 	String,
+	TemplateString,
 	Semicolon,
 	OpenBlock,
 	CloseBlock,
 	MultiplicativeOperator,
 	AdditiveOperator,
+	ExponentOperator,
+	ShiftOperator,
+	BitwiseXorOperator,
+	BitwiseNotOperator,
+	UpdateOperator,
 	RelationalOperator,
 	EqualityOperator,
 	LogicalAndOperator,
 	LogicalOrOperator,
 	LogicalNotOperator,
+	NullishCoalescingOperator,
 	AssignmentOperator,
 	OpenParen,
 	CloseParen,
@@ -46,7 +61,14 @@ pub enum TokenType {
 	Identifier,
 	Comma,
 	Dot,
+	Ellipsis,
+	OptionalChaining,
+	QuestionMark,
+	FatArrow,
+	Ampersand,
+	Pipe,
 	// Arrow,
+	Colon,
 	DoubleColon,
 	Keyword(Keyword),
 }
@@ -57,6 +79,7 @@ impl TokenType {
 		return matches!(
 			self,
 			TokenType::String |
+				TokenType::TemplateString |
 				TokenType::Number |
 				TokenType::Keyword(Keyword::True | Keyword::False | Keyword::Null)
 		);
@@ -64,6 +87,9 @@ impl TokenType {
 
 	#[inline(always)]
 	pub fn is_unary_operator(&self) -> bool {
-		return matches!(self, TokenType::AdditiveOperator | TokenType::LogicalNotOperator);
+		return matches!(
+			self,
+			TokenType::AdditiveOperator | TokenType::LogicalNotOperator | TokenType::BitwiseNotOperator
+		);
 	}
 }
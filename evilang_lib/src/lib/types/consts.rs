@@ -3,9 +3,22 @@
 pub use const_str::concat as concat_str;
 
 pub const SUPER: &str = "super";
+pub const THIS: &str = "this";
 pub const OBJECT: &str = "Object";
 pub const VECTOR: &str = "Vector";
 pub const INSTANCE_OF_: &str = "Instance of ";
 pub const CONSTRUCTOR: &str = "constructor";
+pub const LAMBDA: &str = "lambda";
+pub const ERROR_MESSAGE: &str = "message";
+pub const ERROR_KIND: &str = "kind";
+pub const ERROR_STACK: &str = "stack";
+pub const TO_STRING: &str = "to_string";
+pub const ITER: &str = "iter";
+pub const NEXT: &str = "next";
+pub const ITERATOR_DONE: &str = "done";
+pub const ITERATOR_VALUE: &str = "value";
 pub const HIDDEN_PREFIX: &str = "__HIDDEN";
 pub const CURRENT_FILE: &str = concat_str!(HIDDEN_PREFIX, "__CURRENT_FILE__");
+pub const IMPLICIT_THIS: &str = concat_str!(HIDDEN_PREFIX, "__IMPLICIT_THIS__");
+pub const GETTER_PREFIX: &str = concat_str!(HIDDEN_PREFIX, "__GET__");
+pub const SETTER_PREFIX: &str = concat_str!(HIDDEN_PREFIX, "__SET__");
@@ -3,3 +3,5 @@ pub mod string;
 pub mod traits;
 pub mod cell_ref;
 pub mod consts;
+pub mod span;
+pub mod source_map;
@@ -200,6 +200,46 @@ impl Num for NumberT {
 }
 
 impl NumberT {
+	/// Parses a numeric literal: either a decimal number with an optional fraction and exponent,
+	/// or an integer prefixed with `0x`, `0o` or `0b`. Digits may be separated by single `_`s, and
+	/// a decimal number may end with an `i` or `f` suffix to force it to be an integer or a float.
+	pub fn from_literal(literal: &str) -> Result<Self, EvilangError> {
+		let invalid = || EvilangError::new(ErrorT::InvalidNumericLiteral(literal.into()));
+		let radix = match literal.get(..2) {
+			Some("0x") => 16,
+			Some("0o") => 8,
+			Some("0b") => 2,
+			_ => 10,
+		};
+		let (body, suffix) = if radix == 10 {
+			let end = literal
+				.find(|c: char| !c.is_ascii_digit() && !matches!(c, '_' | '.' | 'e' | 'E' | '+' | '-'))
+				.unwrap_or(literal.len());
+			literal.split_at(end)
+		} else {
+			(&literal[2..], "")
+		};
+		let chars = body.chars().collect::<Vec<_>>();
+		let valid_separators = chars
+			.iter()
+			.enumerate()
+			.filter(|(_, c)| **c == '_')
+			.all(|(i, _)| {
+				i > 0 && chars[i - 1].is_digit(radix) && chars.get(i + 1).is_some_and(|v| v.is_digit(radix))
+			});
+		if !valid_separators {
+			return Err(invalid());
+		}
+		let digits = body.replace('_', "");
+		return match (radix, suffix) {
+			(10, "") => NumberT::from_str_radix(&digits, 10).map_err(|_| invalid()),
+			(10, "i") => digits.parse::<i128>().map(NumberT::Integer).map_err(|_| invalid()),
+			(10, "f") => digits.parse::<f64>().map(NumberT::Float).map_err(|_| invalid()),
+			(10, _) => Err(invalid()),
+			_ => i128::from_str_radix(&digits, radix).map(NumberT::Integer).map_err(|_| invalid()),
+		};
+	}
+
 	#[inline(always)]
 	pub fn round_to_int(&self) -> i128 {
 		match self {
@@ -221,6 +261,30 @@ impl NumberT {
 			NumberT::Float(f) => f.ceil() as i128,
 		}
 	}
+	/// `self` raised to `exponent`, an integer when both are integers and the exponent isn't
+	/// negative, `None` when that integer overflows.
+	pub fn checked_pow(&self, exponent: &NumberT) -> Option<NumberT> {
+		return match (self, exponent) {
+			(NumberT::Integer(a), NumberT::Integer(b)) if *b >= 0 => {
+				u32::try_from(*b).ok().and_then(|b| a.checked_pow(b)).map(NumberT::Integer)
+			}
+			(a, b) => Some(NumberT::Float(a.as_float().powf(b.as_float()))),
+		};
+	}
+
+	/// The quotient rounded towards negative infinity, `None` when dividing an integer by zero or
+	/// when the quotient overflows.
+	pub fn checked_floor_div(&self, rhs: &NumberT) -> Option<NumberT> {
+		return match (self, rhs) {
+			(NumberT::Integer(a), NumberT::Integer(b)) => {
+				let quotient = a.checked_div(*b)?;
+				let is_inexact_negative = a % b != 0 && (*a < 0) != (*b < 0);
+				Some(NumberT::Integer(if is_inexact_negative { quotient - 1 } else { quotient }))
+			}
+			(a, b) => Some(NumberT::Float((a.as_float() / b.as_float()).floor())),
+		};
+	}
+
 	#[inline(always)]
 	pub fn as_float(&self) -> f64 {
 		match self {
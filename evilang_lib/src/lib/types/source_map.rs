@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::types::span::FileIdT;
+use crate::types::string::StringT;
+
+/// File id of source code that was not registered in the source map, such as programs evaluated
+/// directly from a string.
+pub const ANONYMOUS_FILE_ID: FileIdT = 0;
+
+#[derive(Debug, PartialEq)]
+pub struct SourceFile {
+	pub name: StringT,
+	pub contents: StringT,
+}
+
+impl SourceFile {
+	/// The full line of source code containing the byte offset `position`, along with the byte
+	/// offset at which that line starts.
+	pub fn line_at(&self, position: usize) -> (&str, usize) {
+		let position = position.min(self.contents.len());
+		let start = self.contents[..position].rfind('\n').map_or(0, |v| v + 1);
+		let end = self.contents[position..].find('\n').map_or(self.contents.len(), |v| v + position);
+		return (self.contents[start..end].trim_end_matches('\r'), start);
+	}
+}
+
+thread_local! {
+	static SOURCE_FILES: RefCell<Vec<Rc<SourceFile>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Registers the source code of a file so that diagnostics can show it, returning the file id
+/// to parse it with. Registering the same contents under the same name again reuses its id.
+pub fn add_source_file(name: StringT, contents: StringT) -> FileIdT {
+	return SOURCE_FILES.with(|files| {
+		let mut files = files.borrow_mut();
+		if let Some(idx) = files.iter().position(|v| v.name == name && v.contents == contents) {
+			return idx + 1;
+		}
+		files.push(Rc::new(SourceFile { name, contents }));
+		return files.len();
+	});
+}
+
+pub fn get_source_file(file_id: FileIdT) -> Option<Rc<SourceFile>> {
+	if file_id == ANONYMOUS_FILE_ID {
+		return None;
+	}
+	return SOURCE_FILES.with(|files| files.borrow().get(file_id - 1).cloned());
+}
@@ -0,0 +1,69 @@
+use std::fmt::{ Display, Formatter };
+
+pub type FileIdT = usize;
+
+/// A region of source code: the byte range `start..end` inside the file identified by `file_id`,
+/// along with the 1-based line and column (counted in characters) at which the region starts.
+///
+/// A default constructed span (line 0) is a dummy span, used for nodes that were not produced by
+/// the parser.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+	pub file_id: FileIdT,
+	pub start: usize,
+	pub end: usize,
+	pub line: usize,
+	pub column: usize,
+}
+
+impl Span {
+	#[inline(always)]
+	pub fn new(file_id: FileIdT, start: usize, end: usize, line: usize, column: usize) -> Self {
+		Self { file_id, start, end, line, column }
+	}
+
+	#[inline(always)]
+	pub fn is_dummy(&self) -> bool {
+		return self.line == 0;
+	}
+
+	#[inline(always)]
+	pub fn len(&self) -> usize {
+		return self.end - self.start;
+	}
+
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool {
+		return self.start == self.end;
+	}
+
+	/// The span starting at `self` and ending at the end of `other`.
+	pub fn to(&self, other: &Span) -> Span {
+		if self.is_dummy() {
+			return *other;
+		}
+		if other.is_dummy() {
+			return *self;
+		}
+		return Span { end: other.end.max(self.start), ..*self };
+	}
+
+	/// The span of the bytes `from..to` of `text`, where `text` is the source code covered by
+	/// `self`.
+	pub fn sub_span(&self, text: &str, from: usize, to: usize) -> Span {
+		let before = &text[..from];
+		let Some(newline) = before.rfind('\n') else {
+			let column = self.column + before.chars().count();
+			return Span::new(self.file_id, self.start + from, self.start + to, self.line, column);
+		};
+		let line = self.line + before.matches('\n').count();
+		let column = before[newline + 1..].chars().count() + 1;
+		return Span::new(self.file_id, self.start + from, self.start + to, line, column);
+	}
+}
+
+impl Display for Span {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}:{}", self.line, self.column)
+	}
+}
@@ -117,7 +117,7 @@ fn define_export_for_constructor(
 				#NativeClassMemberFunctionContext::new(env, &this_val),
 				#(#param_names_list),*
 			)?;
-			#native_wrap(this_obj, #NATIVE_BOX_WRAP_NAME.into(), new_obj);
+			#native_wrap(this_obj, #NATIVE_BOX_WRAP_NAME.into(), new_obj)?;
 			return #Ok_(#PrimitiveValue::Null);
 		}
 		// #(#rest_exports)*
@@ -293,7 +293,12 @@ pub(crate) struct RootAttributes {
 pub(crate) struct ExportAttribute {
 	#[darling(default, rename = "_as")]
 	export_as: Option<String>,
+	#[darling(default)]
 	raw: bool,
+	/// Exports a member function taking no arguments as the getter of a property, which is called
+	/// when the property is read.
+	#[darling(default)]
+	getter: bool,
 	#[darling(skip)]
 	attribute: Option<Attribute>,
 }
@@ -312,6 +317,7 @@ impl TryParseAttribute for ExportAttribute {
 			export_as: Some(expr_as_string(expr)),
 			attribute: None,
 			raw: false,
+			getter: false,
 		}
 	}
 
@@ -421,6 +427,7 @@ impl RootData {
 			GcPtrToObject,
 			PrimitiveValue,
 			concat_str,
+			GETTER_PREFIX,
 			INativeClass,
 			INativeClass_BuildClass,
 			Ok_,
@@ -452,8 +459,13 @@ impl RootData {
 		let export_tuples_for_functions = self.functions.iter().flat_map(|func| {
 			func.exports.iter().map(|export| {
 				let name = &export.export_ident;
+				let key = if export.attribute.getter {
+					quote!(#concat_str!(#GETTER_PREFIX, ::std::stringify!(#name)))
+				} else {
+					quote!(::std::stringify!(#name))
+				};
 				quote_spanned!(export.attribute.get_span()=>
-					(::std::stringify!(#name).into(), #gc_ptr_cell_from(#PrimitiveValue::new_native_function(#Self_exports::#name)))
+					(#key.into(), #gc_ptr_cell_from(#PrimitiveValue::new_native_function(#Self_exports::#name)))
 				)
 			})
 		});
@@ -465,6 +477,11 @@ impl RootData {
 			if let Some(FnArg::Receiver(_)) = func.signature.inputs.first() {
 				return define_export_for_member_function(&imports, &ExpT, &NATIVE_BOX_WRAP_NAME, func);
 			}
+			if func.exports.iter().any(|v| v.attribute.getter) {
+				return quote_spanned! {func.signature.span() =>
+					compile_error!("Only member functions can be exported as getters");
+				};
+			}
 			{
 				let (others_iter, ctors_iter) = func.exports
 					.iter()
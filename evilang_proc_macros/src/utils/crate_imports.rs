@@ -18,6 +18,7 @@ pub struct CrateImports {
 	pub FunctionReturnValue: TokenStream,
 	pub FunctionParameters: TokenStream,
 	pub concat_str: TokenStream,
+	pub GETTER_PREFIX: TokenStream,
 	pub INativeClass: TokenStream,
 	pub INativeClass_BuildClass: TokenStream,
 	pub from_option_of_primitive_value: TokenStream,
@@ -52,6 +53,7 @@ impl CrateImports {
 			FunctionReturnValue: quote! { #module::interpreter::runtime_values::functions::types::FunctionReturnValue },
 			FunctionParameters: quote! { #module::interpreter::runtime_values::functions::types::FunctionParameters },
 			concat_str: quote! { #module::types::consts::concat_str },
+			GETTER_PREFIX: quote! { #module::types::consts::GETTER_PREFIX },
 			INativeClass: quote! { #module::interpreter::runtime_values::i_native_struct::INativeClass },
 			INativeClass_BuildClass: quote! { #module::interpreter::runtime_values::i_native_struct::INativeClass_BuildClass },
 			INativeClass_IsStructWrapper: quote! { #module::interpreter::runtime_values::i_native_struct::INativeClass_IsStructWrapper },
@@ -1,12 +1,23 @@
-use clap::Parser;
+use clap::{ Parser, ValueEnum };
 
 use evilang_lib::types::string::StringT;
 
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq)]
+pub enum ErrorFormat {
+	/// Source snippets with the error underlined
+	#[default]
+	Human,
+	/// One JSON object per error, for editors and CI
+	Json,
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct CliArguments {
 	#[arg(long, short, help = "File to execute", value_name = "FILE")]
 	pub file: Option<StringT>,
+	#[arg(long, value_enum, default_value_t, help = "How errors are reported")]
+	pub error_format: ErrorFormat,
 	#[arg(long = "src-debug-dnu", help = "Source Debug (Do Not Use)", action, hide = true)]
 	pub src_debug: bool,
 }
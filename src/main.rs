@@ -1,16 +1,20 @@
 #![allow(dead_code)]
 
-use std::error::Error;
+use std::fs;
 use std::ops::Deref;
+use std::process::ExitCode;
 
 use clap::Parser;
 
 use evilang_lib::ast::statement::StatementList;
+use evilang_lib::diagnostics::Diagnostic;
+use evilang_lib::errors::EvilangError;
 use evilang_lib::interpreter::environment::Environment;
 use evilang_lib::interpreter::environment::resolver::DefaultResolver;
-use evilang_lib::parser::parse;
+use evilang_lib::parser::{ parse, parse_file_with_recovery };
+use evilang_lib::types::string::StringT;
 
-use crate::cli::CliArguments;
+use crate::cli::{ CliArguments, ErrorFormat };
 
 pub mod cli;
 
@@ -35,14 +39,48 @@ fn ensure_program(input: &str, expected: StatementList) -> TestRes {
 	}
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+/// Parses the file with error recovery, to report all of its syntax errors at once rather than
+/// only the first one.
+fn check_syntax(file: &StringT) -> Vec<EvilangError> {
+	let Ok(path) = DefaultResolver::resolve_file_path(None, file.clone()) else {
+		return vec![];
+	};
+	let Ok(contents) = fs::read_to_string(&path) else {
+		return vec![];
+	};
+	parse_file_with_recovery(path.to_string_lossy().into(), contents).errors
+}
+
+fn report_errors(errors: &[EvilangError], format: ErrorFormat) {
+	for (i, err) in errors.iter().enumerate() {
+		let diagnostic = Diagnostic::new(err);
+		match format {
+			ErrorFormat::Human if i == 0 => eprintln!("{}", diagnostic.render()),
+			ErrorFormat::Human => eprintln!("\n{}", diagnostic.render()),
+			ErrorFormat::Json => eprintln!("{}", diagnostic.to_json()),
+		}
+	}
+}
+
+fn main() -> ExitCode {
 	let args = CliArguments::parse();
 	let Some(file) = args.file else {
-		return Ok(());
+		return ExitCode::SUCCESS;
+	};
+	let syntax_errors = check_syntax(&file);
+	if !syntax_errors.is_empty() {
+		report_errors(&syntax_errors, args.error_format);
+		return ExitCode::FAILURE;
+	}
+	let env = match Environment::execute_file(file, DefaultResolver::new_box()) {
+		Ok(env) => env,
+		Err(err) => {
+			report_errors(&[err], args.error_format);
+			return ExitCode::FAILURE;
+		}
 	};
-	let env = Environment::execute_file(file, DefaultResolver::new_box())?;
 	if args.src_debug {
 		dbg!(&env.global_scope.borrow().res_stack);
 	}
-	Ok(())
+	ExitCode::SUCCESS
 }
@@ -0,0 +1,51 @@
+use evilang_lib::ast::statement::StatementKind::{ BlockStatement, ClassDeclarationStatement };
+use evilang_lib::ast::structs::{
+	ClassDeclaration,
+	FunctionDeclaration,
+	FunctionParameterDeclaration,
+};
+use evilang_lib::errors::{ ErrorT, RuntimeError };
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_program,
+	run_asserts_in_file,
+	TestRes,
+};
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	let empty_fn = |name: &str, params: Vec<FunctionParameterDeclaration>| {
+		FunctionDeclaration::new(name.into(), params, BlockStatement(vec![]).into())
+	};
+	ensure_program(
+		"class K { get a() {} set a(v) {} fn get() {} }",
+		vec![
+			ClassDeclarationStatement(
+				ClassDeclaration::new("K".into(), None, vec![empty_fn("get", vec![])]).with_accessors(
+					vec![empty_fn("a", vec![])],
+					vec![empty_fn("a", vec![FunctionParameterDeclaration::new("v".into())])]
+				)
+			).into()
+		]
+	);
+	ensure_parsing_fails("class K { a() {} }", None);
+	ensure_parsing_fails("class K { get fn a() {} }", None);
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/accessors_test/main.evil".into());
+}
+
+#[test]
+fn getters_without_setters_cant_be_assigned_to() -> TestRes {
+	ensure_execution_fails(
+		"class K { get a() { return 1; } } let k = new K(); k.a = 2;".into(),
+		Some(ErrorT::UnexpectedRuntimeError(RuntimeError::PropertyWithoutSetter("a".into())))
+	);
+	ensure_execution_fails("let v = [1]; v.length += 1;".into(), None);
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use evilang_lib::ast::expression::{ BoxExpression, Expression };
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	Identifier,
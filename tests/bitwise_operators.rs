@@ -0,0 +1,167 @@
+use evilang_lib::ast::expression::{ BoxExpression, Expression };
+use evilang_lib::ast::expression::ExpressionKind::{ BinaryExpression, Identifier, UnaryExpression };
+use evilang_lib::ast::operator::Operator;
+use evilang_lib::ast::operator::Operator::{
+	BitwiseAnd,
+	BitwiseNot,
+	BitwiseOr,
+	BitwiseXor,
+	Equals,
+	Exponentiation,
+	FloorDivision,
+	LeftShift,
+	Minus,
+	Plus,
+};
+use evilang_lib::errors::{ Descriptor, ErrorT, RuntimeError };
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_res_stack_matches,
+	test_expression_and_assignment,
+	TestRes,
+};
+
+mod common;
+
+fn binary(operator: Operator, left: Expression, right: Expression) -> Expression {
+	BinaryExpression {
+		operator,
+		left: BoxExpression::from(left),
+		right: BoxExpression::from(right),
+	}.into()
+}
+
+fn identifier(name: &str) -> Expression {
+	Identifier(name.into()).into()
+}
+
+#[test]
+fn precedence() -> TestRes {
+	test_expression_and_assignment(
+		"a | b ^ c & d << e + f == g;",
+		binary(
+			Equals,
+			binary(
+				BitwiseOr,
+				identifier("a"),
+				binary(
+					BitwiseXor,
+					identifier("b"),
+					binary(
+						BitwiseAnd,
+						identifier("c"),
+						binary(LeftShift, identifier("d"), binary(Plus, identifier("e"), identifier("f")))
+					)
+				)
+			),
+			identifier("g")
+		)
+	)
+}
+
+#[test]
+fn exponentiation_is_right_associative_and_binds_tighter_than_unary() -> TestRes {
+	test_expression_and_assignment(
+		"-a ** ~b ** c ~/ d;",
+		binary(
+			FloorDivision,
+			UnaryExpression {
+				operator: Minus,
+				argument: binary(
+					Exponentiation,
+					identifier("a"),
+					UnaryExpression {
+						operator: BitwiseNot,
+						argument: binary(Exponentiation, identifier("b"), identifier("c")).into(),
+					}.into()
+				).into(),
+			}.into(),
+			identifier("d")
+		)
+	)
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	ensure_res_stack_matches(
+		"push_res_stack(6 & 3, 6 | 3, 6 ^ 3, ~5, 1 << 4, -16 >> 2, 1 | 2 == 3);\
+		push_res_stack(2 ** 10, -2 ** 2, 2 ** 3 ** 2, 2 ** -1, 2.0 ** 2);\
+		push_res_stack(7 ~/ 2, -7 ~/ 2, 7 ~/ -2, 7.5 ~/ 2, 7 / 2);",
+		vec![
+			PrimitiveValue::integer(2),
+			PrimitiveValue::integer(7),
+			PrimitiveValue::integer(5),
+			PrimitiveValue::integer(-6),
+			PrimitiveValue::integer(16),
+			PrimitiveValue::integer(-4),
+			PrimitiveValue::Boolean(true),
+			PrimitiveValue::integer(1024),
+			PrimitiveValue::integer(-4),
+			PrimitiveValue::integer(512),
+			PrimitiveValue::float(0.5),
+			PrimitiveValue::float(4.0),
+			PrimitiveValue::integer(3),
+			PrimitiveValue::integer(-4),
+			PrimitiveValue::integer(-4),
+			PrimitiveValue::float(3.0),
+			PrimitiveValue::float(3.5),
+		]
+	)
+}
+
+#[test]
+fn compound_assignments() -> TestRes {
+	ensure_res_stack_matches(
+		"let a = 5; a <<= 2; push_res_stack(a); a |= 1; push_res_stack(a); a ^= 3; push_res_stack(a);\
+		a &= 14; push_res_stack(a); a **= 2; push_res_stack(a); a ~/= 5; push_res_stack(a); a >>= 1;\
+		push_res_stack(a);",
+		vec![
+			PrimitiveValue::integer(20),
+			PrimitiveValue::integer(21),
+			PrimitiveValue::integer(22),
+			PrimitiveValue::integer(6),
+			PrimitiveValue::integer(36),
+			PrimitiveValue::integer(7),
+			PrimitiveValue::integer(3),
+		]
+	)
+}
+
+#[test]
+fn bitwise_operators_on_floats() -> TestRes {
+	ensure_execution_fails(
+		"1.5 & 1;".into(),
+		Some(
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::ExpectedInteger(Descriptor::ExpressionAndValue {
+					value: PrimitiveValue::float(1.5),
+					expression: Expression::float_literal(1.5),
+				})
+			)
+		)
+	);
+	ensure_execution_fails("~0.5;".into(), None);
+	ensure_execution_fails("1 << 2.0;".into(), None);
+}
+
+#[test]
+fn invalid_arithmetic() -> TestRes {
+	ensure_execution_fails(
+		"1 ~/ 0;".into(),
+		Some(
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::InvalidArithmetic(
+					Descriptor::Expression(
+						binary(FloorDivision, Expression::integer_literal(1), Expression::integer_literal(0))
+					),
+					"Division by zero".into()
+				)
+			)
+		)
+	);
+	ensure_execution_fails("2 ** 200;".into(), None);
+	ensure_execution_fails("1 << 128;".into(), None);
+	ensure_execution_fails("1 >> -1;".into(), None);
+}
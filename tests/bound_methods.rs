@@ -0,0 +1,23 @@
+use evilang_lib::errors::{ Descriptor, ErrorT, RuntimeError };
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{ ensure_execution_fails, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/bound_methods_test/main.evil".into());
+}
+
+#[test]
+fn bind_expects_a_function() -> TestRes {
+	ensure_execution_fails(
+		"bind(1, 2);".into(),
+		Some(
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::ExpectedFunction(Descriptor::Value(PrimitiveValue::integer(1)))
+			)
+		)
+	);
+}
@@ -0,0 +1,220 @@
+use evilang_lib::ast::expression::{ Expression, ExpressionKind::Identifier };
+use evilang_lib::ast::operator::Operator::Multiplication;
+use evilang_lib::ast::statement::StatementKind::{ BlockStatement, FunctionDeclarationStatement };
+use evilang_lib::ast::structs::{ CaptureDeclaration, FunctionDeclaration };
+use evilang_lib::errors::ErrorT;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+use evilang_lib::tokenizer::{ Token, TokenType };
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_program,
+	ensure_res_stack_matches,
+	TestRes,
+};
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"fn f() captures (a, &b, c = a * 2) {}",
+		vec![
+			FunctionDeclarationStatement(
+				FunctionDeclaration::new("f".into(), vec![], BlockStatement(vec![]).into()).with_captures(
+					Some(
+						vec![
+							CaptureDeclaration::ByValue("a".into()),
+							CaptureDeclaration::ByReference("b".into()),
+							CaptureDeclaration::Initialized(
+								"c".into(),
+								Expression::binary_expression(
+									Multiplication,
+									Identifier("a".into()).into(),
+									Expression::integer_literal(2).into()
+								)
+							)
+						]
+					)
+				)
+			).into()
+		]
+	);
+	ensure_program(
+		"fn f() captures () {}",
+		vec![
+			FunctionDeclarationStatement(
+				FunctionDeclaration::new("f".into(), vec![], BlockStatement(vec![]).into()).with_captures(
+					Some(vec![])
+				)
+			).into()
+		]
+	);
+	ensure_parsing_fails(
+		"fn f() captures (1) {}",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Number, "1".into())))
+	);
+	ensure_parsing_fails("fn f() captures (a += 1) {}", Some(ErrorT::ExpectedSimpleAssignmentOperator));
+	ensure_parsing_fails(
+		"fn f() captures a {}",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Identifier, "a".into())))
+	);
+}
+
+#[test]
+fn snapshots() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+fn make() {
+	let count = 1;
+	let get = fn () captures (count) { return count; };
+	count = 2;
+	return get;
+}
+push_res_stack(make()());
+
+let a = 1;
+fn get_a() captures (a) {
+	return a;
+}
+a = 2;
+push_res_stack(get_a());
+
+let functions = [];
+let i = 0;
+while (i < 3) {
+	functions.push(fn () captures (i, doubled = i * 2) { return i + doubled; });
+	i += 1;
+}
+push_res_stack(functions.map(f => f()).equals([0, 3, 6]));
+"#,
+		vec![PrimitiveValue::integer(1), PrimitiveValue::integer(1), PrimitiveValue::Boolean(true)]
+	);
+}
+
+#[test]
+fn references() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+fn counter() {
+	let count = 0;
+	let increment = fn () captures (&count) {
+		count += 1;
+		return count;
+	};
+	increment();
+	increment();
+	push_res_stack(count);
+	count = 10;
+	push_res_stack(increment());
+}
+counter();
+
+fn outer() {
+	fn factorial(n) captures (&factorial) {
+		if (n <= 1) {
+			return 1;
+		}
+		return n * factorial(n - 1);
+	}
+	return factorial(5);
+}
+push_res_stack(outer());
+"#,
+		vec![PrimitiveValue::integer(2), PrimitiveValue::integer(11), PrimitiveValue::integer(120)]
+	);
+}
+
+#[test]
+fn builtins_are_accessible() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+let global = 10;
+fn outer() {
+	let f = fn (x) captures (global) {
+		let local = x + global;
+		push_res_stack(local, Vector::from(local).len());
+	};
+	f(1);
+}
+outer();
+"#,
+		vec![PrimitiveValue::integer(11), PrimitiveValue::integer(1)]
+	);
+}
+
+#[test]
+fn uncaptured_variables() -> TestRes {
+	ensure_execution_fails(
+		r#"
+fn outer() {
+	let a = 1;
+	let b = 2;
+	let f = fn () captures (a) { return a + b; };
+	return f();
+}
+outer();
+"#.into(),
+		Some(ErrorT::UncapturedVariable("b".into()))
+	);
+	ensure_execution_fails(
+		r#"
+fn outer() {
+	let f = fn () captures () { undeclared = 1; };
+	f();
+}
+outer();
+"#.into(),
+		Some(ErrorT::UncapturedVariable("undeclared".into()))
+	);
+	ensure_execution_fails(
+		r#"
+fn outer() {
+	let a = 1;
+	let f = fn () captures () {
+		let g = () => a;
+		return g();
+	};
+	return f();
+}
+outer();
+"#.into(),
+		Some(ErrorT::UncapturedVariable("a".into()))
+	);
+	ensure_execution_fails(
+		"let secret = 42; fn f() captures () { println(secret); } f();".into(),
+		Some(ErrorT::UncapturedVariable("secret".into()))
+	);
+	ensure_execution_fails(
+		"let secret = 42; fn f() captures () { secret = 1; } f();".into(),
+		Some(ErrorT::UncapturedVariable("secret".into()))
+	);
+}
+
+#[test]
+fn unknown_captures() -> TestRes {
+	let fails_with = |input: &str, name: &str| {
+		ensure_execution_fails(input.into(), Some(ErrorT::UnknownCapturedVariable(name.into())));
+	};
+	fails_with("let counter = 0; fn f() captures (&countr) { countr += 1; } f();", "countr");
+	fails_with("let counter = 0; let f = fn () captures (countr) { return countr; };", "countr");
+}
+
+#[test]
+fn declared_when_reached() -> TestRes {
+	ensure_execution_fails(
+		r#"
+f();
+fn f() captures () {}
+"#.into(),
+		Some(ErrorT::CantAccessHoistedVariable("f".into()))
+	);
+	ensure_execution_fails(
+		r#"
+fn f() captures (a) {}
+let a = 1;
+"#.into(),
+		Some(ErrorT::CantAccessHoistedVariable("a".into()))
+	);
+}
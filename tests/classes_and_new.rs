@@ -1,11 +1,11 @@
 use evilang_lib::ast::expression::{
-	Expression::{ AssignmentExpression, BinaryExpression, Identifier, MemberAccess },
 	Expression,
+	ExpressionKind::{ AssignmentExpression, BinaryExpression, Identifier, MemberAccess },
 };
 use evilang_lib::ast::expression::MemberIndexer::PropertyName;
 use evilang_lib::ast::operator::Operator::{ Assignment, Plus };
 use evilang_lib::ast::statement::Statement;
-use evilang_lib::ast::statement::Statement::{
+use evilang_lib::ast::statement::StatementKind::{
 	BlockStatement,
 	ReturnStatement,
 	VariableDeclarations,
@@ -64,15 +64,9 @@ push_res_stack(p3.calc());
 						FunctionDeclaration::new(
 							"constructor".into(),
 							[
-								FunctionParameterDeclaration {
-									identifier: "this".into(),
-								},
-								FunctionParameterDeclaration {
-									identifier: "x".into(),
-								},
-								FunctionParameterDeclaration {
-									identifier: "y".into(),
-								},
+								FunctionParameterDeclaration::new("this".into()),
+								FunctionParameterDeclaration::new("x".into()),
+								FunctionParameterDeclaration::new("y".into()),
 							].into(),
 							BlockStatement(
 								[
@@ -98,9 +92,7 @@ push_res_stack(p3.calc());
 						FunctionDeclaration::new(
 							"calc".into(),
 							[
-								FunctionParameterDeclaration {
-									identifier: "this".into(),
-								},
+								FunctionParameterDeclaration::new("this".into()),
 							].into(),
 							BlockStatement(
 								[
@@ -115,8 +107,8 @@ push_res_stack(p3.calc());
 												object: Identifier("this".into()).into(),
 												member: PropertyName("y".into()),
 											}).into(),
-										})
-									),
+										}.into())
+									).into(),
 								].into()
 							).into()
 						),
@@ -126,23 +118,15 @@ push_res_stack(p3.calc());
 				// region ...Point3D
 				Statement::class_declaration(
 					"Point3D".into(),
-					Some(Identifier("Point".into())),
+					Some(Identifier("Point".into()).into()),
 					[
 						FunctionDeclaration::new(
 							"constructor".into(),
 							[
-								FunctionParameterDeclaration {
-									identifier: "this".into(),
-								},
-								FunctionParameterDeclaration {
-									identifier: "x".into(),
-								},
-								FunctionParameterDeclaration {
-									identifier: "y".into(),
-								},
-								FunctionParameterDeclaration {
-									identifier: "z".into(),
-								},
+								FunctionParameterDeclaration::new("this".into()),
+								FunctionParameterDeclaration::new("x".into()),
+								FunctionParameterDeclaration::new("y".into()),
+								FunctionParameterDeclaration::new("z".into()),
 							].into(),
 							BlockStatement(
 								[
@@ -152,9 +136,9 @@ push_res_stack(p3.calc());
 											"constructor".into()
 										).into(),
 										[
-											Identifier("this".into()),
-											Identifier("x".into()),
-											Identifier("y".into()),
+											Identifier("this".into()).into(),
+											Identifier("x".into()).into(),
+											Identifier("y".into()).into(),
 										].into()
 									).consume_as_statement(),
 									(AssignmentExpression {
@@ -171,9 +155,7 @@ push_res_stack(p3.calc());
 						FunctionDeclaration::new(
 							"calc".into(),
 							[
-								FunctionParameterDeclaration {
-									identifier: "this".into(),
-								},
+								FunctionParameterDeclaration::new("this".into()),
 							].into(),
 							BlockStatement(
 								[
@@ -185,14 +167,14 @@ push_res_stack(p3.calc());
 													object: Identifier("super".into()).into(),
 													member: PropertyName("calc".into()),
 												}).into(),
-												[Identifier("this".into())].into()
+												[Identifier("this".into()).into()].into()
 											).into(),
 											right: (MemberAccess {
 												object: Identifier("this".into()).into(),
 												member: PropertyName("z".into()),
 											}).into(),
-										})
-									),
+										}.into())
+									).into(),
 								].into()
 							).into()
 						),
@@ -201,22 +183,22 @@ push_res_stack(p3.calc());
 				// endregion Point3D
 				VariableDeclarations(
 					[
-						VariableDeclaration {
-							identifier: "p".into(),
-							initializer: Some(
+						VariableDeclaration::new(
+							"p".into(),
+							Some(
 								Expression::new_object_expression(
 									Identifier("Point".into()).into(),
 									[Expression::integer_literal(10), Expression::integer_literal(12)].into()
 								)
-							),
-						},
+							)
+						),
 					].into()
-				),
+				).into(),
 				VariableDeclarations(
 					[
-						VariableDeclaration {
-							identifier: "p3".into(),
-							initializer: Some(
+						VariableDeclaration::new(
+							"p3".into(),
+							Some(
 								Expression::new_object_expression(
 									Identifier("Point3D".into()).into(),
 									[
@@ -225,10 +207,10 @@ push_res_stack(p3.calc());
 										Expression::integer_literal(30),
 									].into()
 								)
-							),
-						},
+							)
+						),
 					].into()
-				),
+				).into(),
 				push_res_stack_stmt(
 					Expression::function_call(
 						Expression::member_property_access(Identifier("p".into()).into(), "calc".into()).into(),
@@ -0,0 +1,92 @@
+use evilang_lib::ast::expression::{ Expression, MemberIndexer };
+use evilang_lib::ast::expression::ExpressionKind::{
+	ArrayLiteral,
+	AssignmentExpression,
+	Identifier,
+	ObjectLiteral,
+	StringLiteral,
+};
+use evilang_lib::ast::operator::Operator::Assignment;
+use evilang_lib::ast::statement::StatementKind::BlockStatement;
+use evilang_lib::errors::ErrorT;
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+use evilang_lib::tokenizer::{ Token, TokenType };
+
+use crate::common::{ ensure_parsing_fails, ensure_program, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"a = [1, \"b\", [c]];",
+		vec![
+			(AssignmentExpression {
+				operator: Assignment,
+				left: Identifier("a".into()).into(),
+				right: ArrayLiteral(
+					vec![
+						Expression::integer_literal(1),
+						StringLiteral("b".into()).into(),
+						ArrayLiteral(vec![Identifier("c".into()).into()]).into()
+					]
+				).into(),
+			}).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"a = { x: 1, \"y z\": [], [k]: {} };",
+		vec![
+			(AssignmentExpression {
+				operator: Assignment,
+				left: Identifier("a".into()).into(),
+				right: ObjectLiteral(
+					vec![
+						(MemberIndexer::PropertyName("x".into()), Expression::integer_literal(1)),
+						(MemberIndexer::PropertyName("y z".into()), ArrayLiteral(vec![]).into()),
+						(
+							MemberIndexer::SubscriptExpression(Identifier("k".into()).into()),
+							ObjectLiteral(vec![]).into(),
+						)
+					]
+				).into(),
+			}).consume_as_statement()
+		]
+	);
+}
+
+#[test]
+fn block_or_object() -> TestRes {
+	ensure_program("{}", vec![BlockStatement(vec![]).into()]);
+	ensure_program("({});", vec![ObjectLiteral(vec![]).consume_as_parenthesized().consume_as_statement()]);
+	ensure_parsing_fails(
+		"{ x: 1 };",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Colon, ":".into())))
+	);
+	ensure_parsing_fails(
+		"a = { x 1 };",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Number, "1".into())))
+	);
+	ensure_parsing_fails(
+		"a = { 1: 1 };",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Number, "1".into())))
+	);
+}
+
+#[test]
+fn object_literals_inherit_from_object() -> TestRes {
+	let mut env = Environment::new().unwrap();
+	env.eval_program_string("push_res_stack({ x: 1 });".into()).unwrap();
+	let res_stack = env.global_scope.borrow().res_stack.clone();
+	let [PrimitiveValue::Object(object)] = res_stack.as_slice() else {
+		panic!("Expected an object, got {:#?}", res_stack);
+	};
+	let parent = object.parent.clone().expect("Expected the object to have a parent");
+	assert_eq!(parent.name, "Object");
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/collection_literals_test/main.evil".into());
+}
@@ -4,10 +4,10 @@
 use std::ops::Deref;
 
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{ AssignmentExpression, Identifier };
+use evilang_lib::ast::expression::ExpressionKind::{ AssignmentExpression, Identifier };
 use evilang_lib::ast::operator::Operator::Assignment;
 use evilang_lib::ast::statement::{ Statement, StatementList };
-use evilang_lib::ast::statement::Statement::ExpressionStatement;
+use evilang_lib::ast::statement::StatementKind::ExpressionStatement;
 use evilang_lib::errors::ErrorT;
 use evilang_lib::interpreter::environment::Environment;
 use evilang_lib::interpreter::environment::resolver::DefaultResolver;
@@ -117,7 +117,7 @@ impl TestData {
 		};
 		env.setup_scope(&parsed).unwrap();
 		for (stmt, expected_val) in parsed.iter().zip(results.iter()) {
-			if let ExpressionStatement(expr) = stmt {
+			if let ExpressionStatement(expr) = &stmt.kind {
 				let value = env.eval(expr).unwrap();
 				let borrow = value.borrow();
 				let got_val = borrow.deref();
@@ -211,17 +211,18 @@ pub fn ensure_res_stack_matches_with_env(
 	assert_eq!(env.global_scope.borrow().res_stack, results, "Expected result values to match");
 }
 
-pub fn test_expression_and_assignment(input: &str, expr: Expression) -> TestRes {
+pub fn test_expression_and_assignment(input: &str, expr: impl Into<Expression>) -> TestRes {
+	let expr = expr.into();
 	ensure_program(input, vec![expr.clone().consume_as_statement()]);
 	let new_input = "y = ".to_string() + input;
 	ensure_program(
 		new_input.as_str(),
 		vec![
-			ExpressionStatement(AssignmentExpression {
+			(AssignmentExpression {
 				operator: Assignment,
 				left: Identifier("y".to_string()).into(),
 				right: expr.into(),
-			})
+			}).consume_as_statement()
 		]
 	);
 }
@@ -231,9 +232,10 @@ pub fn identifier_stmt(iden: &str) -> Statement {
 }
 
 pub fn push_res_stack_stmt(val: Expression) -> Statement {
-	ExpressionStatement(
-		Expression::function_call(Identifier("push_res_stack".to_string()).into(), vec![val])
-	)
+	Expression::function_call(
+		Identifier("push_res_stack".to_string()).into(),
+		vec![val]
+	).consume_as_statement()
 }
 
 pub fn run_asserts_in_file(file: CowStringT) {
@@ -0,0 +1,115 @@
+use evilang_lib::ast::expression::{ Expression, MemberIndexer };
+use evilang_lib::ast::expression::ExpressionKind::Identifier;
+use evilang_lib::ast::operator::Operator::{
+	LogicalAndAssignment,
+	LogicalOr,
+	LogicalOrAssignment,
+	NullishCoalescing,
+	NullishCoalescingAssignment,
+};
+use evilang_lib::ast::statement::StatementKind::ExpressionStatement;
+use evilang_lib::errors::ErrorT;
+use evilang_lib::parser::parse;
+use evilang_lib::tokenizer::{ Token, TokenType };
+
+use crate::common::{ ensure_parsing_fails, ensure_program, run_asserts_in_file, TestRes };
+
+mod common;
+
+fn iden(name: &str) -> Box<Expression> {
+	Identifier(name.into()).into()
+}
+
+#[test]
+fn conditional_parsing() -> TestRes {
+	ensure_program(
+		"a ? b : c ? d : e;",
+		vec![
+			Expression::conditional_expression(
+				iden("a"),
+				iden("b"),
+				Expression::conditional_expression(iden("c"), iden("d"), iden("e")).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"a || b ?? c;",
+		vec![
+			Expression::binary_expression(
+				NullishCoalescing,
+				Expression::binary_expression(LogicalOr, iden("a"), iden("b")).into(),
+				iden("c")
+			).consume_as_statement()
+		]
+	);
+	ensure_parsing_fails(
+		"a ? b;",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::Semicolon, ";".into())))
+	);
+	ensure_parsing_fails("(a ? b : c) = d;", Some(ErrorT::ExpectedLhsExpression));
+}
+
+#[test]
+fn logical_assignment_parsing() -> TestRes {
+	for (input, operator) in [
+		("a ??= b;", NullishCoalescingAssignment),
+		("a ||= b;", LogicalOrAssignment),
+		("a &&= b;", LogicalAndAssignment),
+	] {
+		ensure_program(
+			input,
+			vec![Expression::assignment_expression(operator, iden("a"), iden("b")).consume_as_statement()]
+		);
+	}
+}
+
+#[test]
+fn optional_chain_parsing() -> TestRes {
+	ensure_program(
+		"a?.b.c;",
+		vec![
+			Expression::optional_chain(
+				Expression::member_property_access(
+					Expression::optional_member_access(
+						iden("a"),
+						MemberIndexer::PropertyName("b".into())
+					).into(),
+					"c".into()
+				).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"a?.[b](c);",
+		vec![
+			Expression::optional_chain(
+				Expression::function_call(
+					Expression::optional_member_access(
+						iden("a"),
+						MemberIndexer::SubscriptExpression(iden("b"))
+					).into(),
+					vec![*iden("c")]
+				).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_parsing_fails("a?.b = 1;", Some(ErrorT::ExpectedLhsExpression));
+	ensure_parsing_fails(
+		"a?.(b);",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::OpenParen, "(".into())))
+	);
+}
+
+#[test]
+fn display() -> TestRes {
+	let parsed = parse("x = a?.b.c(d?.[0]) ?? (e ? f : g);".into()).unwrap();
+	let ExpressionStatement(expr) = &parsed[0].kind else {
+		panic!("Expected an expression statement, got {:#?}", parsed[0]);
+	};
+	assert_eq!(expr.to_string(), "x = a?.b.c(d?.[0]) ?? (e ? f : g)");
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/conditional_operators_test/main.evil".into());
+}
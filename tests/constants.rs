@@ -0,0 +1,91 @@
+use evilang_lib::ast::expression::{ Expression, ExpressionKind::Identifier };
+use evilang_lib::ast::pattern::{ Pattern, PatternElement };
+use evilang_lib::ast::statement::StatementKind::{
+	BlockStatement,
+	ClassDeclarationStatement,
+	ConstantDeclarations,
+	FunctionDeclarationStatement,
+};
+use evilang_lib::ast::structs::{ ClassDeclaration, FunctionDeclaration, VariableDeclaration };
+use evilang_lib::errors::ErrorT;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_program,
+	ensure_res_stack_matches,
+	TestRes,
+};
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"const a = 1, [b] = c;",
+		vec![
+			ConstantDeclarations(vec![
+				VariableDeclaration::new("a".into(), Some(Expression::integer_literal(1))),
+				VariableDeclaration::new_destructuring(
+					Pattern::Vector {
+						elements: vec![PatternElement::new(Pattern::Identifier("b".into()), None)],
+						rest: None,
+					},
+					Identifier("c".into()).into()
+				),
+			]).into()
+		]
+	);
+	ensure_program(
+		"const fn f() {} const class K {}",
+		vec![
+			FunctionDeclarationStatement(
+				FunctionDeclaration::new("f".into(), vec![], BlockStatement(vec![]).into()).as_constant()
+			).into(),
+			ClassDeclarationStatement(ClassDeclaration::new("K".into(), None, vec![]).as_constant()).into()
+		]
+	);
+	ensure_parsing_fails("const a;", None);
+	ensure_parsing_fails("const;", Some(ErrorT::ExpectedVariableDeclaration));
+}
+
+#[test]
+fn constants_can_be_read_and_shadowed() -> TestRes {
+	ensure_res_stack_matches(
+		"push_res_stack(f()); const a = 1, { b } = { b: 2 }; const fn f() { return 3; } \
+		{ let a = 4; a += 1; push_res_stack(a); } push_res_stack(a, b); \
+		const class K { fn constructor() { this.v = 6; } } push_res_stack(new K().v);",
+		vec![
+			PrimitiveValue::integer(3),
+			PrimitiveValue::integer(5),
+			PrimitiveValue::integer(1),
+			PrimitiveValue::integer(2),
+			PrimitiveValue::integer(6),
+		]
+	);
+}
+
+#[test]
+fn assignment_to_constant() -> TestRes {
+	let fails_with = |input: &str, name: &str| {
+		ensure_execution_fails(input.into(), Some(ErrorT::AssignmentToConstant(name.into())));
+	};
+	fails_with("const a = 1; a = 2;", "a");
+	fails_with("const a = 1; a += 2;", "a");
+	fails_with("const a = 1; a++;", "a");
+	fails_with("const [a, b] = [1, 2]; [b, a] = [a, b];", "b");
+	fails_with("const fn f() {} f = null;", "f");
+	fails_with("const class K {} K = null;", "K");
+	fails_with("namespace ns { const x = 1; } ns.x = 2;", "x");
+	fails_with("println = null;", "println");
+	fails_with("Vector = null;", "Vector");
+	fails_with("const K = 1; fn f() captures (&K) { K = 2; } f();", "K");
+	fails_with("fn f() captures (&println) { println = null; } f();", "println");
+	ensure_execution_fails("const a = 1; let a = 2;".into(), Some(ErrorT::CantRedeclareVariable("a".into())));
+	ensure_execution_fails(
+		"let println = fn(x) {};".into(),
+		Some(ErrorT::CantRedeclareVariable("println".into()))
+	);
+	ensure_execution_fails("const Vector = 1;".into(), Some(ErrorT::CantRedeclareVariable("Vector".into())));
+}
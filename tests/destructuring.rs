@@ -0,0 +1,190 @@
+use evilang_lib::ast::expression::{
+	Expression,
+	ExpressionKind,
+	ExpressionKind::{ ArrayLiteral, DestructuringPattern, Identifier, MemberAccess },
+	MemberIndexer,
+};
+use evilang_lib::ast::operator::Operator::{ Assignment, Equals };
+use evilang_lib::ast::pattern::{ Pattern, PatternElement, PropertyPattern };
+use evilang_lib::ast::statement::StatementKind::{
+	BlockStatement,
+	ExpressionStatement,
+	VariableDeclarations,
+};
+use evilang_lib::ast::statement::Statement;
+use evilang_lib::ast::structs::{ FunctionParameterDeclaration, VariableDeclaration };
+use evilang_lib::errors::{ ErrorT, RuntimeError };
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::parser::parse;
+
+use crate::common::{ ensure_parsing_fails, ensure_program, run_asserts_in_file, TestRes };
+
+mod common;
+
+fn element(name: &str) -> PatternElement {
+	PatternElement::new(Pattern::Identifier(name.into()), None)
+}
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"let [x, y = 1, ...rest] = v;",
+		vec![
+			VariableDeclarations(
+				vec![
+					VariableDeclaration::new_destructuring(
+						Pattern::Vector {
+							elements: vec![
+								element("x"),
+								PatternElement::new(
+									Pattern::Identifier("y".into()),
+									Some(Expression::integer_literal(1))
+								)
+							],
+							rest: Some(Pattern::Identifier("rest".into()).into()),
+						},
+						Identifier("v".into()).into()
+					)
+				]
+			).into()
+		]
+	);
+	ensure_program(
+		"let { name, age: [years] = v } = obj;",
+		vec![
+			VariableDeclarations(
+				vec![
+					VariableDeclaration::new_destructuring(
+						Pattern::Object(
+							vec![
+								PropertyPattern::new_shorthand("name".into(), None),
+								PropertyPattern::new(
+									MemberIndexer::PropertyName("age".into()),
+									PatternElement::new(
+										Pattern::Vector { elements: vec![element("years")], rest: None },
+										Some(Identifier("v".into()).into())
+									)
+								)
+							]
+						),
+						Identifier("obj".into()).into()
+					)
+				]
+			).into()
+		]
+	);
+	ensure_program(
+		"[a, b[c]] = v;",
+		vec![
+			Expression::assignment_expression(
+				Assignment,
+				DestructuringPattern(
+					Pattern::Vector {
+						elements: vec![
+							element("a"),
+							PatternElement::new(
+								Pattern::Target(
+									MemberAccess {
+										object: Identifier("b".into()).into(),
+										member: MemberIndexer::SubscriptExpression(Identifier("c".into()).into()),
+									}.into()
+								),
+								None
+							)
+						],
+						rest: None,
+					}
+				).into(),
+				Identifier("v".into()).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"fn f({ x }, [y] = v) {}",
+		vec![
+			Statement::function_declaration(
+				"f".into(),
+				vec![
+					FunctionParameterDeclaration::new_destructuring(
+						Pattern::Object(vec![PropertyPattern::new_shorthand("x".into(), None)]),
+						None
+					),
+					FunctionParameterDeclaration::new_destructuring(
+						Pattern::Vector { elements: vec![element("y")], rest: None },
+						Some(Identifier("v".into()).into())
+					)
+				],
+				BlockStatement(vec![]).into()
+			)
+		]
+	);
+	// Literals followed by anything but an assignment operator are still literals
+	ensure_program(
+		"[a, b] == v;",
+		vec![
+			Expression::binary_expression(
+				Equals,
+				ArrayLiteral(vec![Identifier("a".into()).into(), Identifier("b".into()).into()]).into(),
+				Identifier("v".into()).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_parsing_fails("let [a, b];", None);
+	ensure_parsing_fails("let [a, ...b, c] = v;", None);
+	ensure_parsing_fails("let [a.b] = v;", None);
+	ensure_parsing_fails("[a + 1] = v;", Some(ErrorT::ExpectedLhsExpression));
+	ensure_parsing_fails("[a, b] += v;", Some(ErrorT::ExpectedSimpleAssignmentOperator));
+	ensure_parsing_fails("fn f(...[a, b]) {}", None);
+}
+
+fn ensure_pattern_display(input: &str, expected: &str) {
+	let parsed = parse(input.into()).unwrap();
+	let Some(ExpressionStatement(expr)) = parsed.first().map(|v| &v.kind) else {
+		panic!("Expected an expression statement, got {:#?}", parsed);
+	};
+	let expr = match &expr.kind {
+		ExpressionKind::ParenthesizedExpression(inner) => inner,
+		_ => expr,
+	};
+	let ExpressionKind::AssignmentExpression { left, .. } = &expr.kind else {
+		panic!("Expected an assignment, got {:#?}", expr);
+	};
+	assert_eq!(left.to_string(), expected);
+}
+
+#[test]
+fn display() -> TestRes {
+	ensure_pattern_display("[a, b[c] = 1, ...d] = v;", "[a, b[c] = 1, ...d]");
+	ensure_pattern_display("({ x, \"y z\": [y] = v, [k]: { w } } = v);", "{ x, \"y z\": [y] = v, [k]: { w } }");
+	ensure_pattern_display("({ x: [], y: {} } = v);", "{ x: [], y: {} }");
+}
+
+fn ensure_destructuring_fails(input: &str) {
+	let mut env = Environment::new().unwrap();
+	let err = env.eval_program_string(input.into()).unwrap_err();
+	assert!(
+		matches!(
+			&err.typ,
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::ExpectedClassObject(..) | RuntimeError::ExpectedNativeObject(..)
+			)
+		),
+		"Expected {} to fail to destructure, got {:#?}",
+		input,
+		err
+	);
+}
+
+#[test]
+fn invalid_values() -> TestRes {
+	ensure_destructuring_fails("let [a] = 1;");
+	ensure_destructuring_fails("let [a] = { a: 1 };");
+	ensure_destructuring_fails("let { a } = null;");
+	ensure_destructuring_fails("let [[a]] = [1];");
+	ensure_destructuring_fails("fn f({ a }) {} f(2);");
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/destructuring_test/main.evil".into());
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::process::Command;
+
+use evilang_lib::diagnostics::Diagnostic;
+use evilang_lib::errors::{ Descriptor, ErrorT, EvilangError, RuntimeError };
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::parser::parse_file;
+use evilang_lib::types::source_map::SourceFile;
+
+use crate::common::TestRes;
+
+mod common;
+
+#[test]
+fn stable_error_codes() -> TestRes {
+	assert_eq!(ErrorT::EndOfTokenStream.code(), "E0002");
+	assert_eq!(ErrorT::CantRedeclareVariable("x".into()).code(), "E0017");
+	assert_eq!(RuntimeError::ExpectedFunction(Descriptor::None).code(), "E1005");
+	let error: EvilangError = RuntimeError::ExpectedObject(Descriptor::None).into();
+	assert_eq!(error.code(), "E1008", "Runtime errors should keep their own code");
+}
+
+#[test]
+fn render_runtime_error() -> TestRes {
+	let input = "let a = \"str\";\nfn f() {\n\treturn a(1, 2);\n}\nf();";
+	let mut env = Environment::new().unwrap();
+	let error = env.eval_program_string(input.into()).unwrap_err();
+	let source = SourceFile { name: "main.evil".into(), contents: input.into() };
+	let diagnostic = Diagnostic::new_with_source(&error, Some(&source));
+	assert_eq!(
+		diagnostic.render(),
+		[
+			"error[E1005]: Expected `a` (which is \"str\") to be a function",
+			"  --> main.evil:3:9",
+			"  |",
+			"3 | \treturn a(1, 2);",
+			"  | \t       ^^^^^^^",
+		].join("\n")
+	);
+}
+
+#[test]
+fn render_parse_error_from_source_map() -> TestRes {
+	let error = parse_file("parse_error.evil".into(), "let x = 1;\nlet y = x +;".into()).unwrap_err();
+	let diagnostic = Diagnostic::new(&error);
+	assert_eq!(diagnostic.code, "E0003");
+	assert_eq!(diagnostic.file_name.as_deref(), Some("parse_error.evil"));
+	assert_eq!(diagnostic.source_line.as_deref(), Some("let y = x +;"));
+	assert_eq!(diagnostic.highlight, Some((11, 12)));
+}
+
+#[test]
+fn render_without_span() -> TestRes {
+	let error: EvilangError = RuntimeError::GenericError("Something went wrong".into()).into();
+	assert_eq!(Diagnostic::new(&error).render(), "error[E1000]: Something went wrong");
+}
+
+#[test]
+fn json_output() -> TestRes {
+	let error = parse_file("json \"error\".evil".into(), "\tlet 1;".into()).unwrap_err();
+	assert_eq!(
+		Diagnostic::new(&error).to_json(),
+		concat!(
+			r#"{"code":"E0003","message":"Unexpected token: `1`","file":"json \"error\".evil","#,
+			r#""line":1,"column":6,"start":5,"end":6,"source_line":"\tlet 1;"}"#
+		)
+	);
+}
+
+#[test]
+fn cli_error_formats() -> TestRes {
+	let dir = std::env::temp_dir().join(format!("evilang_diagnostics_{}", std::process::id()));
+	fs::create_dir_all(&dir).unwrap();
+	let file = dir.join("undefined_function.evil");
+	fs::write(&file, "let x = 1;\nx();\n").unwrap();
+	let run = |format: &str| {
+		let output = Command::new(env!("CARGO_BIN_EXE_evilang_main"))
+			.args(["--file", file.to_str().unwrap(), "--error-format", format])
+			.output()
+			.unwrap();
+		assert!(!output.status.success(), "Expected the program to fail");
+		String::from_utf8(output.stderr).unwrap()
+	};
+	let human = run("human");
+	assert!(human.starts_with("error[E1005]: Expected `x` (which is `1`) to be a function\n"));
+	assert!(human.ends_with("2 | x();\n  | ^^^\n"));
+	let json = run("json");
+	assert!(json.starts_with("{\"code\":\"E1005\","));
+	assert!(json.contains("\"line\":2,\"column\":1,"));
+	fs::remove_dir_all(&dir).unwrap();
+}
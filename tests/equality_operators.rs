@@ -1,5 +1,5 @@
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	BinaryExpression,
 	BooleanLiteral,
 	Identifier,
@@ -0,0 +1,110 @@
+use evilang_lib::ast::statement::StatementKind::{
+	BlockStatement,
+	ErrorStatement,
+	FunctionDeclarationStatement,
+	IfStatement,
+	VariableDeclarations,
+};
+use evilang_lib::errors::ErrorT;
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::parser::{ parse, parse_with_recovery };
+use evilang_lib::tokenizer::TokenType;
+
+use crate::common::{ ensure_parsing_fails, TestRes };
+
+mod common;
+
+fn error_positions(input: &str) -> Vec<(usize, usize)> {
+	parse_with_recovery(input.to_string())
+		.errors
+		.iter()
+		.map(|err| err.span.map(|span| (span.line, span.column)).unwrap())
+		.collect()
+}
+
+#[test]
+fn reports_every_error() -> TestRes {
+	let input = r#"
+let a = ;
+let b = 1;
+fn f(x y) {
+	return x;
+}
+b = * 3;
+let c = 2;
+"#;
+	assert_eq!(error_positions(input), vec![(2, 9), (4, 8), (7, 5)]);
+	let result = parse_with_recovery(input.to_string());
+	let kinds = result.statements
+		.iter()
+		.map(|v| &v.kind)
+		.collect::<Vec<_>>();
+	assert_eq!(kinds.len(), 5);
+	assert_eq!(kinds[0], &ErrorStatement);
+	assert!(matches!(kinds[1], VariableDeclarations(..)));
+	assert_eq!(kinds[2], &ErrorStatement);
+	assert_eq!(kinds[3], &ErrorStatement);
+	assert!(matches!(kinds[4], VariableDeclarations(..)));
+	assert_eq!(result.statements[2].span.line, 4, "Expected the placeholder to span the function");
+}
+
+#[test]
+fn recovers_inside_blocks() -> TestRes {
+	let input = r#"
+fn f() {
+	let a = 1 +;
+	return a;
+}
+if (true) {
+	a = ) 3;
+	f();
+}
+"#;
+	assert_eq!(error_positions(input), vec![(3, 13), (7, 6)]);
+	let statements = parse_with_recovery(input.to_string()).statements;
+	let FunctionDeclarationStatement(fdecl) = &statements[0].kind else {
+		panic!("Expected a function declaration, got {:#?}", statements[0]);
+	};
+	let BlockStatement(body) = &fdecl.body.kind else {
+		panic!("Expected a block, got {:#?}", fdecl.body);
+	};
+	assert_eq!(body.len(), 2);
+	assert_eq!(body[0].kind, ErrorStatement);
+	let IfStatement { if_branch, .. } = &statements[1].kind else {
+		panic!("Expected an if statement, got {:#?}", statements[1]);
+	};
+	let BlockStatement(if_body) = &if_branch.kind else {
+		panic!("Expected a block, got {:#?}", if_branch);
+	};
+	assert_eq!(if_body.len(), 2);
+	assert_eq!(if_body[0].kind, ErrorStatement);
+}
+
+#[test]
+fn recovers_from_stray_tokens() -> TestRes {
+	assert_eq!(error_positions("}\nlet a = 1;\n) let b = 2;\nlet c = 1 @ 2;\nlet d = 3;"), vec![
+		(1, 1),
+		(3, 1),
+		(4, 11),
+	]);
+	assert_eq!(error_positions("let a = 1;\nfn f() {\n\tlet b = 2;\n"), vec![(4, 1)]);
+}
+
+#[test]
+fn parse_returns_first_error() -> TestRes {
+	ensure_parsing_fails("let a = ;\nlet b = ;", None);
+	let err = parse("let a = 1;\nlet b = ;\nlet c = ;".to_string()).unwrap_err();
+	let ErrorT::InvalidTokenType(token) = &err.typ else {
+		panic!("Expected an invalid token error, got {:#?}", err);
+	};
+	assert_eq!(token.typ, TokenType::Semicolon);
+	assert_eq!(err.span.map(|v| v.line), Some(2));
+}
+
+#[test]
+fn error_statements_are_not_executable() -> TestRes {
+	let statements = parse_with_recovery("let a = 1;\nlet b = ;".to_string()).statements;
+	let mut env = Environment::new().unwrap();
+	let err = env.setup_and_eval_statements(&statements).unwrap_err();
+	assert_eq!(err.typ, ErrorT::ExecutingErrorStatement);
+}
@@ -0,0 +1,100 @@
+use evilang_lib::ast::expression::{ Expression, ExpressionKind::Identifier };
+use evilang_lib::ast::pattern::Pattern;
+use evilang_lib::ast::statement::{ Statement, StatementKind::{ BlockStatement, ThrowStatement } };
+use evilang_lib::ast::structs::CatchClause;
+use evilang_lib::errors::{ Descriptor, ErrorT, RuntimeError };
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{ ensure_parsing_fails, ensure_program, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"throw e;",
+		vec![ThrowStatement(Identifier("e".into()).into()).into()]
+	);
+	ensure_program(
+		"try {} catch (e) {} finally {}",
+		vec![
+			Statement::try_statement(
+				BlockStatement(vec![]).into(),
+				Some(
+					CatchClause::new(Some(Pattern::Identifier("e".into())), BlockStatement(vec![]).into())
+				),
+				Some(BlockStatement(vec![]).into())
+			)
+		]
+	);
+	ensure_program(
+		"try {} catch { throw 1; }",
+		vec![
+			Statement::try_statement(
+				BlockStatement(vec![]).into(),
+				Some(
+					CatchClause::new(
+						None,
+						BlockStatement(vec![ThrowStatement(Expression::integer_literal(1)).into()]).into()
+					)
+				),
+				None
+			)
+		]
+	);
+	ensure_program(
+		"try {} finally {}",
+		vec![
+			Statement::try_statement(
+				BlockStatement(vec![]).into(),
+				None,
+				Some(BlockStatement(vec![]).into())
+			)
+		]
+	);
+	ensure_parsing_fails("try {}", Some(ErrorT::ExpectedCatchOrFinally));
+	ensure_parsing_fails("try {} catch () {}", None);
+	ensure_parsing_fails("try a; catch {}", None);
+	ensure_parsing_fails("throw;", None);
+}
+
+#[test]
+fn uncaught_exceptions() -> TestRes {
+	let mut env = Environment::new().unwrap();
+	let err = env
+		.eval_program_string("fn f() {\n\tthrow 1;\n}\nfn g() {\n\tf();\n}\ng();".into())
+		.unwrap_err();
+	assert_eq!(
+		err.typ,
+		ErrorT::UnexpectedRuntimeError(
+			RuntimeError::UncaughtException(Descriptor::Value(PrimitiveValue::integer(1)))
+		)
+	);
+	assert_eq!(err.span.map(|v| (v.line, v.column)), Some((2, 2)));
+	let stack = err.stack
+		.iter()
+		.map(|v| (v.function.as_str(), v.span.map(|v| (v.line, v.column))))
+		.collect::<Vec<_>>();
+	assert_eq!(stack, vec![("f", Some((2, 2))), ("g", Some((5, 2)))]);
+	assert_eq!(err.span_in_frame.map(|v| (v.line, v.column)), Some((7, 1)));
+}
+
+#[test]
+fn uncaught_runtime_errors_keep_their_type() -> TestRes {
+	let mut env = Environment::new().unwrap();
+	let err = env
+		.eval_program_string("try { 1 + null; } finally { push_res_stack(1); }".into())
+		.unwrap_err();
+	assert!(
+		matches!(err.typ, ErrorT::UnimplementedBinaryOperatorForValues(..)),
+		"Expected the error to unroll past the finally block, got {:#?}",
+		err
+	);
+	assert_eq!(env.global_scope.borrow().res_stack, vec![PrimitiveValue::integer(1)]);
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/exceptions_test/main.evil".into());
+}
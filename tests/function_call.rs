@@ -1,7 +1,8 @@
 use evilang_lib::ast::expression::{
 	DottedIdentifiers,
 	Expression,
-	Expression::{ AssignmentExpression, BinaryExpression, Identifier, MemberAccess, StringLiteral },
+	ExpressionKind,
+	ExpressionKind::{ AssignmentExpression, BinaryExpression, Identifier, MemberAccess, StringLiteral },
 };
 use evilang_lib::ast::expression::MemberIndexer::{ PropertyName, SubscriptExpression };
 use evilang_lib::ast::operator::Operator::{ Assignment, ModulusAssignment, Plus };
@@ -17,7 +18,7 @@ fn function_call() -> TestRes {
 		r#"foo(x,y);"#,
 		Expression::function_call(
 			Identifier("foo".to_string()).into(),
-			vec![Identifier("x".to_string()), Identifier("y".to_string())]
+			vec![Identifier("x".to_string()).into(), Identifier("y".to_string()).into()]
 		)
 	);
 }
@@ -28,7 +29,7 @@ fn chained_function_call() -> TestRes {
 		r#"foo()(x,y);"#,
 		Expression::function_call(
 			Expression::function_call(Identifier("foo".to_string()).into(), vec![]).into(),
-			vec![Identifier("x".to_string()), Identifier("y".to_string())]
+			vec![Identifier("x".to_string()).into(), Identifier("y".to_string()).into()]
 		)
 	);
 }
@@ -39,11 +40,11 @@ fn console_log() -> TestRes {
 		r#"console.log("values");"#,
 		vec![
 			Expression::function_call(
-				Expression::DottedIdentifiers(DottedIdentifiers {
+				Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 					identifiers: ["console".to_string(), "log".to_string()].into(),
-					delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-				}).into(),
-				vec![StringLiteral("values".to_string())]
+					delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+				})).into(),
+				vec![StringLiteral("values".to_string()).into()]
 			).into()
 		]
 	);
@@ -58,10 +59,10 @@ fn member_complex_assignment() -> TestRes {
 			left: (MemberAccess {
 				object: Expression::function_call(
 					(MemberAccess {
-						object: Expression::DottedIdentifiers(DottedIdentifiers {
+						object: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 							identifiers: ["a".to_string(), "b".to_string()].into(),
-							delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-						}).into(),
+							delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+						})).into(),
 						member: SubscriptExpression(
 							(BinaryExpression {
 								operator: Plus,
@@ -70,7 +71,7 @@ fn member_complex_assignment() -> TestRes {
 							}).into()
 						),
 					}).into(),
-					vec![Identifier("p1".to_string()), Identifier("$".to_string())]
+					vec![Identifier("p1".to_string()).into(), Identifier("$".to_string()).into()]
 				).into(),
 				member: PropertyName("d".to_string()),
 			}).into(),
@@ -80,18 +81,18 @@ fn member_complex_assignment() -> TestRes {
 					operator: Plus,
 					left: Expression::integer_literal(1).into(),
 					right: Expression::function_call(
-						Expression::DottedIdentifiers(DottedIdentifiers {
+						Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 							identifiers: ["$".to_string(), "left".to_string()].into(),
-							delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-						}).into(),
+							delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+						})).into(),
 						vec![AssignmentExpression {
 							operator: Assignment,
-							left: Expression::DottedIdentifiers(DottedIdentifiers {
+							left: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 								identifiers: ["$".to_string(), "right".to_string()].into(),
-								delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-							}).into(),
+								delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+							})).into(),
 							right: Expression::integer_literal(1).into(),
-						}]
+						}.into()]
 					).into(),
 				}).into(),
 				right: Expression::integer_literal(4).into(),
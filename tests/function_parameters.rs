@@ -0,0 +1,103 @@
+use evilang_lib::ast::expression::{ Expression, ExpressionKind::{ Identifier, SpreadExpression } };
+use evilang_lib::ast::statement::{ Statement, StatementKind::BlockStatement };
+use evilang_lib::ast::structs::FunctionParameterDeclaration;
+use evilang_lib::errors::{ ErrorT, RuntimeError };
+use evilang_lib::interpreter::environment::Environment;
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_program,
+	run_asserts_in_file,
+	TestRes,
+};
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"fn f(a, b = 10, ...rest) {}",
+		vec![
+			Statement::function_declaration(
+				"f".into(),
+				vec![
+					FunctionParameterDeclaration::new("a".into()),
+					FunctionParameterDeclaration::new_with_default(
+						"b".into(),
+						Expression::integer_literal(10)
+					),
+					FunctionParameterDeclaration::new_rest("rest".into())
+				],
+				BlockStatement(vec![]).into()
+			)
+		]
+	);
+	ensure_program(
+		"f(a, ...b);",
+		vec![
+			Expression::function_call(
+				Identifier("f".into()).into(),
+				vec![Identifier("a".into()).into(), SpreadExpression(Identifier("b".into()).into()).into()]
+			).consume_as_statement()
+		]
+	);
+	ensure_parsing_fails("fn f(...rest, a) {}", Some(ErrorT::RestParameterNotLast("rest".into())));
+	ensure_parsing_fails("fn f(a += 1) {}", Some(ErrorT::ExpectedSimpleAssignmentOperator));
+	ensure_parsing_fails("fn f(...rest = 1) {}", None);
+	ensure_parsing_fails("let a = ...b;", None);
+}
+
+fn ensure_wrong_number_of_arguments(input: &str, got: usize, expected: &str, func: &str) {
+	ensure_execution_fails(
+		input.into(),
+		Some(
+			(RuntimeError::InvalidNumberArgumentsToFunction {
+				got,
+				expected: Some(expected.into()),
+				func: func.into(),
+			}).into()
+		)
+	);
+}
+
+#[test]
+fn arity_checks() -> TestRes {
+	ensure_wrong_number_of_arguments("fn f(a, b) {} f(1);", 1, "2", "f");
+	ensure_wrong_number_of_arguments("fn f(a, b = 1) {} f();", 0, "1 to 2", "f");
+	ensure_wrong_number_of_arguments("fn f(a, ...rest) {} f();", 0, "at least 1", "f");
+	ensure_wrong_number_of_arguments("fn f(a = 1, b) {} f(1);", 1, "2", "f");
+	ensure_wrong_number_of_arguments("let f = (a, b) => a; f(...[1]);", 1, "2", "lambda");
+	ensure_wrong_number_of_arguments(
+		"class A { fn m(this, a) {} } new A().m();",
+		1,
+		"2",
+		"m"
+	);
+}
+
+#[test]
+fn extra_arguments_are_rejected() -> TestRes {
+	ensure_wrong_number_of_arguments("fn f(a) {} f(1, 2);", 2, "1", "f");
+	ensure_wrong_number_of_arguments("fn f(a, b = 1) {} f(1, 2, 3);", 3, "1 to 2", "f");
+	ensure_wrong_number_of_arguments("let f = () => 1; f(...[1]);", 1, "0", "lambda");
+	ensure_wrong_number_of_arguments("class A { fn m(a) {} } new A().m(1, 2);", 2, "1", "m");
+	ensure_wrong_number_of_arguments("class A { fn m(this, a) {} } new A().m(1, 2);", 3, "2", "m");
+}
+
+#[test]
+fn spreading_non_vectors() -> TestRes {
+	let mut env = Environment::new().unwrap();
+	let err = env.eval_program_string("fn f() {}\nf(1, ...2);".into()).unwrap_err();
+	assert!(
+		matches!(&err.typ, ErrorT::UnexpectedRuntimeError(RuntimeError::ExpectedClassObject(..))),
+		"Expected only vectors to be spreadable, got {:#?}",
+		err
+	);
+	assert_eq!(err.span.map(|v| (v.line, v.column)), Some((2, 6)));
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/function_parameters_test/main.evil".into());
+}
@@ -1,12 +1,12 @@
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	Identifier,
 };
 use evilang_lib::ast::operator::Operator::{ MultiplicationAssignment, NotEquals, PlusAssignment };
 use evilang_lib::ast::statement::Statement;
-use evilang_lib::ast::statement::Statement::{
+use evilang_lib::ast::statement::StatementKind::{
 	BlockStatement,
 	ExpressionStatement,
 	IfStatement,
@@ -51,12 +51,8 @@ fn function_with_params_and_no_body() -> TestRes {
 			Statement::function_declaration(
 				"func_name".to_string(),
 				vec![
-					FunctionParameterDeclaration {
-						identifier: "param1".to_string(),
-					},
-					FunctionParameterDeclaration {
-						identifier: "val2".to_string(),
-					}
+					FunctionParameterDeclaration::new("param1".to_string()),
+					FunctionParameterDeclaration::new("val2".to_string())
 				],
 				BlockStatement(vec![]).into()
 			)
@@ -76,7 +72,7 @@ fn function_with_no_params() -> TestRes {
 			Statement::function_declaration(
 				"func_name".to_string(),
 				vec![],
-				BlockStatement(vec![ReturnStatement(None)]).into()
+				BlockStatement(vec![ReturnStatement(None).into()]).into()
 			)
 		]
 	);
@@ -99,12 +95,8 @@ fn function_with_params_and_return() -> TestRes {
 			Statement::function_declaration(
 				"func_name".to_string(),
 				vec![
-					FunctionParameterDeclaration {
-						identifier: "param1".to_string(),
-					},
-					FunctionParameterDeclaration {
-						identifier: "val2".to_string(),
-					}
+					FunctionParameterDeclaration::new("param1".to_string()),
+					FunctionParameterDeclaration::new("val2".to_string())
 				],
 				BlockStatement(
 					vec![
@@ -112,13 +104,13 @@ fn function_with_params_and_return() -> TestRes {
 							operator: PlusAssignment,
 							left: Identifier("param1".to_string()).into(),
 							right: Identifier("val2".to_string()).into(),
-						}),
+						}.into()).into(),
 						IfStatement {
 							condition: BinaryExpression {
 								operator: NotEquals,
 								left: Identifier("param1".to_string()).into(),
 								right: Expression::integer_literal(0).into(),
-							},
+							}.into(),
 							if_branch: BlockStatement(
 								vec![
 									ReturnStatement(
@@ -126,12 +118,12 @@ fn function_with_params_and_return() -> TestRes {
 											operator: MultiplicationAssignment,
 											left: Identifier("val2".to_string()).into(),
 											right: Identifier("param1".to_string()).into(),
-										})
-									)
+										}.into())
+									).into()
 								]
 							).into(),
-							else_branch: Some(BlockStatement(vec![ReturnStatement(None)]).into()),
-						}
+							else_branch: Some(BlockStatement(vec![ReturnStatement(None).into()]).into()),
+						}.into()
 					]
 				).into()
 			)
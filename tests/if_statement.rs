@@ -1,5 +1,5 @@
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	Identifier,
@@ -15,7 +15,7 @@ use evilang_lib::ast::operator::Operator::{
 	ModulusAssignment,
 	MultiplicationAssignment,
 };
-use evilang_lib::ast::statement::Statement::{ BlockStatement, ExpressionStatement, IfStatement };
+use evilang_lib::ast::statement::StatementKind::{ BlockStatement, ExpressionStatement, IfStatement };
 
 use crate::common::{ ensure_program, TestRes };
 
@@ -34,18 +34,18 @@ fn if_branch() -> TestRes {
 				operator: GreaterThanOrEqualTo,
 				left: Identifier("x".parse().unwrap()).into(),
 				right: Expression::integer_literal(32).into(),
-			},
+			}.into(),
 			if_branch: BlockStatement(
 				[
 					ExpressionStatement(AssignmentExpression {
 						operator: MinusAssignment,
 						left: Identifier("stuff".parse().unwrap()).into(),
 						right: Expression::integer_literal(1).into(),
-					}),
+					}.into()).into(),
 				].into()
 			).into(),
 			else_branch: None,
-		}]
+		}.into()]
 	);
 }
 
@@ -64,14 +64,14 @@ fn if_else_branch() -> TestRes {
 				operator: LessThan,
 				left: Identifier("x".parse().unwrap()).into(),
 				right: Expression::integer_literal(12).into(),
-			},
+			}.into(),
 			if_branch: BlockStatement(
 				[
 					ExpressionStatement(AssignmentExpression {
 						operator: Assignment,
 						left: Identifier("stuff".parse().unwrap()).into(),
 						right: Expression::integer_literal(1).into(),
-					}),
+					}.into()).into(),
 				].into()
 			).into(),
 			else_branch: Some(
@@ -81,11 +81,11 @@ fn if_else_branch() -> TestRes {
 							operator: MultiplicationAssignment,
 							left: Identifier("ot1_h".parse().unwrap()).into(),
 							right: Expression::integer_literal(4).into(),
-						}),
+						}.into()).into(),
 					].into()
 				).into()
 			),
-		}]
+		}.into()]
 	);
 }
 
@@ -102,28 +102,28 @@ fn if_if_else_branch() -> TestRes {
 				operator: LessThan,
 				left: Identifier("x".parse().unwrap()).into(),
 				right: Expression::integer_literal(12).into(),
-			},
+			}.into(),
 			if_branch: (IfStatement {
 				condition: BinaryExpression {
 					operator: LessThanOrEqualTo,
 					left: Identifier("y".parse().unwrap()).into(),
 					right: Expression::integer_literal(13).into(),
-				},
+				}.into(),
 				if_branch: ExpressionStatement(AssignmentExpression {
 					operator: Assignment,
 					left: Identifier("stuff".parse().unwrap()).into(),
 					right: Expression::integer_literal(1).into(),
-				}).into(),
+				}.into()).into(),
 				else_branch: Some(
 					ExpressionStatement(AssignmentExpression {
 						operator: MultiplicationAssignment,
 						left: Identifier("ot1_h".parse().unwrap()).into(),
 						right: Expression::integer_literal(4).into(),
-					}).into()
+					}.into()).into()
 				),
 			}).into(),
 			else_branch: None,
-		}]
+		}.into()]
 	);
 }
 
@@ -145,14 +145,14 @@ fn if_elseif_else_ladder() -> TestRes {
 				operator: LessThan,
 				left: Identifier("x".parse().unwrap()).into(),
 				right: Expression::integer_literal(12).into(),
-			},
+			}.into(),
 			if_branch: BlockStatement(
 				[
 					ExpressionStatement(AssignmentExpression {
 						operator: Assignment,
 						left: Identifier("stuff".parse().unwrap()).into(),
 						right: Expression::integer_literal(1).into(),
-					}),
+					}.into()).into(),
 				].into()
 			).into(),
 			else_branch: Some(
@@ -161,14 +161,14 @@ fn if_elseif_else_ladder() -> TestRes {
 						operator: GreaterThan,
 						left: Identifier("Zyx".parse().unwrap()).into(),
 						right: Expression::integer_literal(12).into(),
-					},
+					}.into(),
 					if_branch: BlockStatement(
 						[
 							ExpressionStatement(AssignmentExpression {
 								operator: DivisionAssignment,
 								left: Identifier("stuff".parse().unwrap()).into(),
 								right: Expression::integer_literal(12).into(),
-							}),
+							}.into()).into(),
 						].into()
 					).into(),
 					else_branch: Some(
@@ -178,18 +178,18 @@ fn if_elseif_else_ladder() -> TestRes {
 									operator: MultiplicationAssignment,
 									left: Identifier("val23".parse().unwrap()).into(),
 									right: Expression::integer_literal(4).into(),
-								}),
+								}.into()).into(),
 								ExpressionStatement(AssignmentExpression {
 									operator: ModulusAssignment,
 									left: Identifier("$data".parse().unwrap()).into(),
 									right: Expression::integer_literal(13).into(),
-								}),
+								}.into()).into(),
 							].into()
 						).into()
 					),
 				}).into()
 			),
-		}]
+		}.into()]
 	);
 }
 
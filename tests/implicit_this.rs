@@ -0,0 +1,19 @@
+use evilang_lib::errors::ErrorT;
+
+use crate::common::{ ensure_execution_fails, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/implicit_this_test/main.evil".into());
+}
+
+#[test]
+fn implicit_this_is_constant() -> TestRes {
+	ensure_execution_fails(
+		"class K { fn f() { this = null; } } new K().f();".into(),
+		Some(ErrorT::AssignmentToConstant("this".into()))
+	);
+	ensure_execution_fails("class K { fn constructor(x) {} } new K();".into(), None);
+}
@@ -0,0 +1,126 @@
+use evilang_lib::ast::expression::{ Expression, ExpressionKind::{ Identifier, LambdaExpression } };
+use evilang_lib::ast::operator::Operator::{ Assignment, Plus };
+use evilang_lib::ast::statement::{ Statement, StatementKind::{ BlockStatement, ExpressionStatement, ReturnStatement } };
+use evilang_lib::ast::structs::{ FunctionDeclaration, FunctionParameterDeclaration };
+use evilang_lib::errors::ErrorT;
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+use evilang_lib::interpreter::variables_containers::map::IVariablesMapConstMembers;
+use evilang_lib::parser::parse;
+use evilang_lib::tokenizer::{ Token, TokenType };
+
+use crate::common::{ ensure_parsing_fails, ensure_program, ensure_res_stack_matches, TestRes };
+
+mod common;
+
+fn lambda(params: &[&str], body: Statement) -> Expression {
+	LambdaExpression(
+		FunctionDeclaration::new(
+			"lambda".into(),
+			params.iter().map(|v| FunctionParameterDeclaration::new(v.to_string())).collect(),
+			body.into()
+		)
+	).into()
+}
+
+fn return_stmt(expr: Expression) -> Statement {
+	ReturnStatement(Some(expr)).into()
+}
+
+fn iden(name: &str) -> Box<Expression> {
+	Identifier(name.into()).into()
+}
+
+#[test]
+fn parsing() -> TestRes {
+	let sum = Expression::binary_expression(Plus, iden("a"), iden("b"));
+	ensure_program("(a, b) => a + b;", vec![lambda(&["a", "b"], return_stmt(sum.clone())).consume_as_statement()]);
+	ensure_program("x => x;", vec![lambda(&["x"], return_stmt(*iden("x"))).consume_as_statement()]);
+	ensure_program("() => {};", vec![lambda(&[], BlockStatement(vec![]).into()).consume_as_statement()]);
+	ensure_program(
+		"f = fn (a, b) { return a + b; };",
+		vec![
+			Expression::assignment_expression(
+				Assignment,
+				iden("f"),
+				lambda(&["a", "b"], BlockStatement(vec![return_stmt(sum)]).into()).into()
+			).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"f(x => y => x);",
+		vec![
+			Expression::function_call(
+				iden("f"),
+				vec![lambda(&["x"], return_stmt(lambda(&["y"], return_stmt(*iden("x")))))]
+			).consume_as_statement()
+		]
+	);
+	ensure_program("(a);", vec![Identifier("a".into()).consume_as_parenthesized().consume_as_statement()]);
+	ensure_parsing_fails(
+		"(a + 1) => a;",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::AdditiveOperator, "+".into())))
+	);
+	ensure_parsing_fails(
+		"fn (a) { return a; }",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::OpenParen, "(".into())))
+	);
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+let add = (a, b) => a + b;
+push_res_stack(add(1, 2));
+let square = x => { return x * x; };
+push_res_stack(square(3));
+let negate = fn (x) { return -x; };
+push_res_stack(negate(4));
+push_res_stack((() => "called")());
+let make_adder = x => y => x + y;
+push_res_stack(make_adder(10)(5));
+"#,
+		vec![
+			PrimitiveValue::integer(3),
+			PrimitiveValue::integer(9),
+			PrimitiveValue::integer(-4),
+			PrimitiveValue::String("called".into()),
+			PrimitiveValue::integer(15),
+		]
+	);
+}
+
+#[test]
+fn vector_callbacks() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+let v = [1, 2, 3];
+push_res_stack(v.map(x => x * 2).equals([2, 4, 6]));
+push_res_stack(v.reduce(0, (acc, x) => acc + x));
+let total = 0;
+v.for_each(fn (x, i) {
+	total += x * i;
+});
+push_res_stack(total);
+"#,
+		vec![PrimitiveValue::Boolean(true), PrimitiveValue::integer(6), PrimitiveValue::integer(8)]
+	);
+}
+
+#[test]
+fn lambdas_are_not_declared() -> TestRes {
+	let mut env = Environment::new().unwrap();
+	env.eval_program_string("let f = x => x; let g = fn (y) { return y; }; f(g(1));".into()).unwrap();
+	assert!(env.get_actual("lambda".into()).is_none(), "Expected lambdas to not declare a name");
+	assert!(env.get_actual("x".into()).is_none(), "Expected parameters to not leak");
+}
+
+#[test]
+fn display() -> TestRes {
+	let parsed = parse("v.map((a, b) => a + b);".into()).unwrap();
+	let ExpressionStatement(expr) = &parsed[0].kind else {
+		panic!("Expected an expression statement, got {:#?}", parsed[0]);
+	};
+	assert_eq!(expr.to_string(), "v.map((a, b) => { ... })");
+}
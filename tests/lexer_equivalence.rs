@@ -0,0 +1,206 @@
+use std::fs;
+
+use evilang_lib::tokenizer::{ Keyword, TokenStream, TokenType };
+
+use crate::common::TestRes;
+
+mod common;
+
+/// A lexed token as its type, contents and byte range, or the byte range of the characters that
+/// could not be lexed.
+type Lexed = Result<(TokenType, String, usize, usize), (usize, usize)>;
+
+/// The regex based tokenizer that the hand-written lexer replaced, kept as it was to check that
+/// both lex the syntax it knew the same way. Syntax added since is covered by the tests of each
+/// feature instead.
+mod reference {
+	use regex::Regex;
+
+	use evilang_lib::tokenizer::{ Keyword, TokenType };
+
+	use crate::Lexed;
+
+	type Matcher = Box<dyn Fn(&str) -> Option<&str>>;
+
+	fn is_identifier_char(ch: char) -> bool {
+		ch.is_alphanumeric() || matches!(ch, '_' | '$')
+	}
+
+	fn regex_matcher(regex_str: &str) -> Matcher {
+		let reg = Regex::new(regex_str).unwrap();
+		Box::new(move |s: &str| Some(reg.find(s)?.as_str()))
+	}
+
+	fn one_of_many<const COUNT: usize>(starters: [&'static str; COUNT]) -> Matcher {
+		Box::new(move |s: &str| starters.iter().find(|v| s.starts_with(**v)).copied())
+	}
+
+	fn keyword_matcher(start: &'static str) -> Matcher {
+		let start_code_points_len = start.chars().count();
+		Box::new(move |s: &str| {
+			let is_keyword = s.starts_with(start) &&
+				s
+					.chars()
+					.nth(start_code_points_len)
+					.is_some_and(|v| !is_identifier_char(v));
+			is_keyword.then_some(start)
+		})
+	}
+
+	fn get_token_matchers() -> Vec<(Matcher, Option<TokenType>)> {
+		let keywords = [
+			("let", Keyword::Let),
+			("if", Keyword::If),
+			("else", Keyword::Else),
+			("true", Keyword::True),
+			("false", Keyword::False),
+			("null", Keyword::Null),
+			("while", Keyword::While),
+			("do", Keyword::Do),
+			("for", Keyword::For),
+			("fn", Keyword::Fn),
+			("captures", Keyword::Captures),
+			("return", Keyword::Return),
+			("class", Keyword::Class),
+			("extends", Keyword::Extends),
+			("new", Keyword::New),
+			("break", Keyword::Break),
+			("continue", Keyword::Continue),
+			("namespace", Keyword::Namespace),
+			("import", Keyword::Import),
+			("as", Keyword::As),
+		];
+		let mut res: Vec<(Matcher, Option<TokenType>)> = vec![
+			(regex_matcher(r"^[\s\r\n]+"), None),
+			(regex_matcher(r#"^//.*"#), None),
+			(regex_matcher(r#"^/\*[^*]*\*+(?:[^/*][^*]*\*+)*/"#), None),
+			(one_of_many([";"]), Some(TokenType::Semicolon)),
+			(one_of_many(["{"]), Some(TokenType::OpenBlock)),
+			(one_of_many(["}"]), Some(TokenType::CloseBlock)),
+			(one_of_many(["("]), Some(TokenType::OpenParen)),
+			(one_of_many([")"]), Some(TokenType::CloseParen)),
+			(one_of_many(["["]), Some(TokenType::OpenSquareBracket)),
+			(one_of_many(["]"]), Some(TokenType::CloseSquareBracket)),
+			(one_of_many([","]), Some(TokenType::Comma)),
+			(one_of_many(["."]), Some(TokenType::Dot)),
+			(one_of_many(["::"]), Some(TokenType::DoubleColon)),
+			(one_of_many(["==", "!="]), Some(TokenType::EqualityOperator)),
+			(one_of_many(["&&"]), Some(TokenType::LogicalAndOperator)),
+			(one_of_many(["||"]), Some(TokenType::LogicalOrOperator)),
+			(one_of_many(["!"]), Some(TokenType::LogicalNotOperator)),
+			(one_of_many(["=", "+=", "-=", "*=", "/=", "%="]), Some(TokenType::AssignmentOperator)),
+			(one_of_many(["*", "/", "%"]), Some(TokenType::MultiplicativeOperator)),
+			(one_of_many(["+", "-"]), Some(TokenType::AdditiveOperator)),
+			(one_of_many(["<=", ">="]), Some(TokenType::RelationalOperator)),
+			(one_of_many(["<", ">"]), Some(TokenType::RelationalOperator)),
+			(regex_matcher(r"^[-+]?[0-9]*\.?[0-9]+([eE][-+]?[0-9]+)?"), Some(TokenType::Number)),
+			(regex_matcher(r#"^("[^"\\]*(?:\\.[^"\\]*)*")"#), Some(TokenType::String)),
+		];
+		for (word, keyword) in keywords {
+			res.push((keyword_matcher(word), Some(TokenType::Keyword(keyword))));
+		}
+		res.push((regex_matcher(r#"^[a-zA-Z_$][a-zA-Z0-9_$]*"#), Some(TokenType::Identifier)));
+		res
+	}
+
+	pub fn lex(input: &str) -> Vec<Lexed> {
+		let matchers = get_token_matchers();
+		let mut res = vec![];
+		let mut position = 0;
+		while position < input.len() {
+			let from = &input[position..];
+			let matched = matchers
+				.iter()
+				.find_map(|(matcher, typ)| matcher(from).map(|s| (s.len(), *typ)));
+			let Some((len, typ)) = matched else {
+				let len = from.chars().next().unwrap().len_utf8();
+				res.push(Err((position, position + len)));
+				position += len;
+				continue;
+			};
+			if let Some(typ) = typ {
+				res.push(Ok((typ, from[..len].to_string(), position, position + len)));
+			}
+			position += len;
+		}
+		res
+	}
+}
+
+fn lex(input: &str) -> Vec<Lexed> {
+	TokenStream::new(input.to_string())
+		.map(|v| match v {
+			Ok(token) => Ok((token.typ, token.data, token.span.start, token.span.end)),
+			Err(err) => {
+				let span = err.span.unwrap();
+				Err((span.start, span.end))
+			}
+		})
+		.filter(|v| !matches!(v, Ok((TokenType::_EOFDummy, ..))))
+		.collect()
+}
+
+fn ensure_same_tokens(input: &str, origin: &str) {
+	assert_eq!(lex(input), reference::lex(input), "Mismatched tokens for {}", origin);
+}
+
+#[test]
+fn edge_cases() -> TestRes {
+	let corpus = [
+		"let a = 1;",
+		"a.b.c = x::y::z(1, 2)[3];",
+		"1 1.5 .5 1. 1.2.3 12e3 1E-4 1.e5 2.5e3.1 007",
+		"+1 -2.5 a-1 a+-+-1",
+		"== != = += -= *= /= %= ! !! !== <= >= < > && ||",
+		"* / % + - a - -1 a+ +b",
+		":: ; , . ..",
+		"\"\" \"a\" \"a\\\"b\" \"a\\\\\" \"multi\nline\" \"tab\\t\" \"unicode é ✓\"",
+		"\"unterminated\nlet a = 1;",
+		"\"escape at end\\",
+		"// comment\nlet a; // trailing comment\n//",
+		"/* block */ a /* multi\nline */ b /***/ c /** doc **/ d /* * / */ e",
+		"/* unterminated\nlet a = 1;",
+		"/*/ a */ b /",
+		"let letter = iff + if_ + _if + $if + if$ + if1 + fn() + fnord;",
+		"if(a){}else{}while(true)do{}for(;;)",
+		"classy class extends extendsx new newer breakable break continue as ask",
+		"namespace import null nullable true truee false_ captures return returns",
+		"leté été ✓ x @ # ` '",
+		"a\r\nb\t\tc\u{a0}d\u{2003}e",
+		"   \n\n\t  ",
+		"",
+		"$ _ $a _b a$b a_b A9",
+	];
+	for (i, input) in corpus.iter().enumerate() {
+		ensure_same_tokens(input, &format!("case {}: {:?}", i, input));
+	}
+}
+
+#[test]
+fn source_files() -> TestRes {
+	// The scripts written before the lexer replaced the old tokenizer
+	let files = [
+		"resources/for_loop.evil",
+		"resources/functions.evil",
+		"resources/tests/common/index.evil",
+		"resources/tests/import_test/main.evil",
+		"resources/tests/import_test/main_adj.evil",
+		"resources/tests/import_test/sub/point.evil",
+		"resources/tests/import_test/sub/sub_2/sub_2_file_1.evil",
+		"resources/tests/import_test/sub/sub_file_1.evil",
+		"resources/tests/vector_test/main.evil",
+		"src/evilang_files/main.evil",
+	];
+	for file in files {
+		let input = fs::read_to_string(file).unwrap();
+		ensure_same_tokens(&input, file);
+	}
+}
+
+#[test]
+fn keywords_at_end_of_input() -> TestRes {
+	// The only intended difference from the old tokenizer, which lexed these as identifiers.
+	let tokens = lex("a; break");
+	assert_eq!(tokens.last(), Some(&Ok((TokenType::Keyword(Keyword::Break), "break".into(), 3, 8))));
+	assert_eq!(lex("breakage").last().map(|v| v.clone().unwrap().0), Some(TokenType::Identifier));
+}
@@ -1,7 +1,9 @@
-use evilang_lib::ast::expression::Expression;
+use evilang_lib::ast::expression::{ Expression, ExpressionKind };
+use evilang_lib::errors::ErrorT;
 use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+use evilang_lib::parser::parse;
 
-use crate::common::{ ensure_program_statement_results, TestRes };
+use crate::common::{ ensure_parsing_fails, ensure_program_statement_results, TestRes };
 
 mod common;
 
@@ -14,14 +16,55 @@ fn integer_literal() -> TestRes {
 	)
 }
 
+fn ensure_number(input: &str, expected: Expression, value: PrimitiveValue) -> TestRes {
+	ensure_program_statement_results(
+		&(input.to_string() + ";"),
+		vec![expected.consume_as_statement()],
+		vec![value]
+	)
+}
+
+#[test]
+fn radix_and_separated_literals() -> TestRes {
+	ensure_number("0xff", Expression::integer_literal(255), PrimitiveValue::integer(255));
+	ensure_number("0xDead_Beef", Expression::integer_literal(0xdead_beef), PrimitiveValue::integer(0xdead_beef));
+	ensure_number("0o17", Expression::integer_literal(15), PrimitiveValue::integer(15));
+	ensure_number("0b1010_0101", Expression::integer_literal(165), PrimitiveValue::integer(165));
+	ensure_number("1_000_000", Expression::integer_literal(1_000_000), PrimitiveValue::integer(1_000_000));
+	ensure_number("1_000.000_5", Expression::float_literal(1000.0005), PrimitiveValue::float(1000.0005));
+	ensure_number("1e1_0", Expression::float_literal(1e10), PrimitiveValue::float(1e10));
+}
+
+#[test]
+fn suffixed_literals() -> TestRes {
+	ensure_number("1f", Expression::float_literal(1.0), PrimitiveValue::float(1.0));
+	ensure_number("2i", Expression::integer_literal(2), PrimitiveValue::integer(2));
+	ensure_number("1_000f", Expression::float_literal(1000.0), PrimitiveValue::float(1000.0));
+	ensure_number("2.5f", Expression::float_literal(2.5), PrimitiveValue::float(2.5));
+	ensure_number("1e3f", Expression::float_literal(1000.0), PrimitiveValue::float(1000.0));
+}
+
+#[test]
+fn invalid_numeric_literals() -> TestRes {
+	let cases = [
+		"0x", "0xg", "0b102", "0o8", "0x_1", "0b1_", "1__0", "1_", "1_.5", "1e", "1e_5", "2.5i",
+		"1e3i", "1u", "1fi", "0xffi", "9abc", "0X10",
+		"0x1_0000_0000_0000_0000_0000_0000_0000_0000",
+	];
+	for literal in cases {
+		let input = literal.to_string() + ";";
+		ensure_parsing_fails(&input, Some(ErrorT::InvalidNumericLiteral(literal.into())));
+	}
+}
+
 #[test]
 fn string_literal() -> TestRes {
 	ensure_program_statement_results(
 		r#""This is a string and this is a double quote: \"";"#,
 		vec![
-			Expression::StringLiteral(
+			Expression::from(ExpressionKind::StringLiteral(
 				"This is a string and this is a double quote: \"".parse().unwrap()
-			).consume_as_statement()
+			)).consume_as_statement()
 		],
 		vec![PrimitiveValue::String("This is a string and this is a double quote: \"".parse().unwrap())]
 	)
@@ -39,7 +82,7 @@ fn comments_and_white_space() -> TestRes {
 //*/
 ;
 "#,
-		vec![Expression::StringLiteral("someCode();".parse().unwrap()).consume_as_statement()],
+		vec![Expression::from(ExpressionKind::StringLiteral("someCode();".parse().unwrap())).consume_as_statement()],
 		vec![PrimitiveValue::String("someCode();".parse().unwrap())]
 	);
 	ensure_program_statement_results(
@@ -52,8 +95,56 @@ fn comments_and_white_space() -> TestRes {
 //*/
 ;
 "#,
-		vec![Expression::StringLiteral("someOtherCode();".parse().unwrap()).consume_as_statement()],
+		vec![Expression::from(ExpressionKind::StringLiteral("someOtherCode();".parse().unwrap())).consume_as_statement()],
 		vec![PrimitiveValue::String("someOtherCode();".parse().unwrap())]
 	);
 }
 
+
+fn ensure_string(input: &str, expected: &str) -> TestRes {
+	ensure_program_statement_results(
+		&(input.to_string() + ";"),
+		vec![Expression::from(ExpressionKind::StringLiteral(expected.into())).consume_as_statement()],
+		vec![PrimitiveValue::String(expected.into())]
+	)
+}
+
+#[test]
+fn string_escapes() -> TestRes {
+	ensure_string(r#""a\tb\nc\rd\0""#, "a\tb\nc\rd\0");
+	ensure_string(r#""back\\slash \"quote\" \'single\'""#, "back\\slash \"quote\" 'single'");
+	ensure_string(r#""\u{41}\u{e9}\u{1F600}""#, "A\u{e9}\u{1F600}");
+	ensure_string(r#"'single "quoted" \'string\'\n'"#, "single \"quoted\" 'string'\n");
+	ensure_string(r#"''"#, "");
+}
+
+#[test]
+fn raw_and_multi_line_strings() -> TestRes {
+	ensure_string(r#"r"C:\new\table\u{41}""#, "C:\\new\\table\\u{41}");
+	ensure_string(r#"r"""#, "");
+	ensure_string("\"\"\"first\n\t\"second\" line\\n\"\"\"", "first\n\t\"second\" line\n");
+	ensure_string(r#""""escaped \""" quotes""""#, "escaped \"\"\" quotes");
+	ensure_string("\"plain\nmulti-line\"", "plain\nmulti-line");
+}
+
+#[test]
+fn invalid_escapes() -> TestRes {
+	let cases = [
+		(r"\q", r"\q"),
+		(r"\u41", r"\u"),
+		(r"\u{}", r"\u{}"),
+		(r"\u{110000}", r"\u{110000}"),
+		(r"\u{d800}", r"\u{d800}"),
+		(r"\u{1234567}", r"\u{1234567}"),
+		(r"\u{12x", r"\u{12"),
+		("\\\n", "\\\n"),
+	];
+	for (escape, reported) in cases {
+		let input = format!("\"a{}b\";", escape);
+		ensure_parsing_fails(&input, Some(ErrorT::InvalidEscapeSequence(reported.into())));
+	}
+	let err = parse("let a = 1;\nlet b = \"\"\"line\n\tand \\x\"\"\";".into()).unwrap_err();
+	assert_eq!(err.typ, ErrorT::InvalidEscapeSequence(r"\x".into()));
+	let span = err.span.unwrap();
+	assert_eq!((span.line, span.column, span.end - span.start), (3, 6, 2));
+}
@@ -1,5 +1,5 @@
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	BooleanLiteral,
@@ -13,8 +13,9 @@ use evilang_lib::ast::operator::Operator::{
 	MultiplicationAssignment,
 	PlusAssignment,
 };
+use evilang_lib::ast::pattern::{ Pattern, PatternElement };
 use evilang_lib::ast::statement::Statement;
-use evilang_lib::ast::statement::Statement::{
+use evilang_lib::ast::statement::StatementKind::{
 	BlockStatement,
 	BreakStatement,
 	ContinueStatement,
@@ -27,9 +28,11 @@ use evilang_lib::ast::statement::Statement::{
 	WhileLoop,
 };
 use evilang_lib::ast::structs::VariableDeclaration;
+use evilang_lib::errors::{ ErrorT, RuntimeError };
 use evilang_lib::interpreter::runtime_values::PrimitiveValue;
 
 use crate::common::{
+	ensure_execution_fails,
 	ensure_parsing_fails,
 	ensure_program,
 	ensure_program_statement_results,
@@ -43,10 +46,10 @@ mod common;
 
 fn get_parts() -> (Statement, Expression, Statement) {
 	let initialization = VariableDeclarations(
-		vec![VariableDeclaration {
-			identifier: "i".to_string(),
-			initializer: Some(Expression::integer_literal(1)),
-		}]
+		vec![VariableDeclaration::new(
+			"i".to_string(),
+			Some(Expression::integer_literal(1))
+		)]
 	);
 	let condition = BinaryExpression {
 		operator: LessThanOrEqualTo,
@@ -57,8 +60,8 @@ fn get_parts() -> (Statement, Expression, Statement) {
 		operator: PlusAssignment,
 		left: Identifier("i".to_string()).into(),
 		right: Expression::integer_literal(1).into(),
-	});
-	(initialization, condition, increment)
+	}.into());
+	(initialization.into(), condition.into(), increment.into())
 }
 
 fn primitive_values_integer_range() -> Vec<PrimitiveValue> {
@@ -79,9 +82,9 @@ fn while_loop() -> TestRes {
 			vec![initialization.clone(), WhileLoop {
 				condition: condition.clone(),
 				body: BlockStatement(
-					[push_res_stack_stmt(Identifier("i".into())), increment.clone()].into()
+					[push_res_stack_stmt(Identifier("i".into()).into()), increment.clone()].into()
 				).into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(primitive_values_integer_range())
 		.check();
@@ -96,7 +99,7 @@ fn while_loop() -> TestRes {
 			WhileLoop {
 				condition,
 				body: increment.into(),
-			},
+			}.into(),
 			identifier_stmt("i")
 		],
 		vec![PrimitiveValue::Null, PrimitiveValue::Null, PrimitiveValue::integer(11)]
@@ -122,9 +125,9 @@ fn do_while_loop() -> TestRes {
 			vec![initialization, DoWhileLoop {
 				condition,
 				body: BlockStatement(
-					[push_res_stack_stmt(Identifier("i".into())), increment].into()
+					[push_res_stack_stmt(Identifier("i".into()).into()), increment].into()
 				).into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(primitive_values_integer_range())
 		.check();
@@ -135,11 +138,11 @@ fn do_while_loop() -> TestRes {
 "#.to_string())
 		.expect_statements(
 			vec![DoWhileLoop {
-				condition: BooleanLiteral(false),
+				condition: BooleanLiteral(false).into(),
 				body: BlockStatement(
-					[push_res_stack_stmt(StringLiteral("atleast_once".to_string()))].into()
+					[push_res_stack_stmt(StringLiteral("atleast_once".to_string()).into())].into()
 				).into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(vec![PrimitiveValue::String("atleast_once".to_string())])
 		.check();
@@ -148,7 +151,7 @@ fn do_while_loop() -> TestRes {
 #[test]
 fn for_loop() -> TestRes {
 	let (initialization, condition, increment) = get_parts();
-	let for_body = push_res_stack_stmt(Identifier("i".to_string()));
+	let for_body = push_res_stack_stmt(Identifier("i".to_string()).into());
 	TestData::new(r#"
 	for(let i = 1; i <= 10; i += 1){
 		push_res_stack(i);
@@ -160,7 +163,7 @@ fn for_loop() -> TestRes {
 				condition: condition.clone(),
 				increment: increment.clone().into(),
 				body: BlockStatement(vec![for_body.clone()]).into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(primitive_values_integer_range())
 		.check();
@@ -174,7 +177,7 @@ fn for_loop() -> TestRes {
 				condition,
 				increment: increment.into(),
 				body: for_body.into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(primitive_values_integer_range())
 		.check();
@@ -188,10 +191,10 @@ fn empty_for_loop() -> TestRes {
 "#,
 		vec![ForLoop {
 			initialization: EmptyStatement.into(),
-			condition: BooleanLiteral(true),
+			condition: BooleanLiteral(true).into(),
 			increment: EmptyStatement.into(),
 			body: EmptyStatement.into(),
-		}]
+		}.into()]
 	);
 }
 
@@ -223,11 +226,11 @@ fn exotic_for_loop() -> TestRes {
 					vec![
 						initialization,
 						VariableDeclarations(
-							vec![VariableDeclaration {
-								identifier: "j".to_string(),
-								initializer: Some(Expression::integer_literal(2)),
-							}]
-						),
+							vec![VariableDeclaration::new(
+								"j".to_string(),
+								Some(Expression::integer_literal(2))
+							)]
+						).into(),
 						(AssignmentExpression {
 							operator: PlusAssignment,
 							left: i.clone().into(),
@@ -248,11 +251,11 @@ fn exotic_for_loop() -> TestRes {
 				).into(),
 				body: BlockStatement(
 					vec![
-						push_res_stack_stmt(Identifier("i".to_string())),
-						push_res_stack_stmt(Identifier("j".to_string()))
+						push_res_stack_stmt(Identifier("i".to_string()).into()),
+						push_res_stack_stmt(Identifier("j".to_string()).into())
 					]
 				).into(),
-			}]
+			}.into()]
 		)
 		.expect_stack(
 			vec![
@@ -300,22 +303,22 @@ fn break_and_continue() -> TestRes {
 			[
 				VariableDeclarations(
 					[
-						VariableDeclaration {
-							identifier: "sum".into(),
-							initializer: Some(Expression::integer_literal(0)),
-						},
-						VariableDeclaration {
-							identifier: "i".into(),
-							initializer: Some(Expression::integer_literal(1)),
-						},
+						VariableDeclaration::new(
+							"sum".into(),
+							Some(Expression::integer_literal(0))
+						),
+						VariableDeclaration::new(
+							"i".into(),
+							Some(Expression::integer_literal(1))
+						),
 					].into()
-				),
+				).into(),
 				WhileLoop {
 					condition: BinaryExpression {
 						operator: LessThanOrEqualTo,
 						left: Identifier("i".into()).into(),
 						right: Expression::integer_literal(10).into(),
-					},
+					}.into(),
 					body: BlockStatement(
 						[
 							IfStatement {
@@ -327,7 +330,7 @@ fn break_and_continue() -> TestRes {
 										right: Expression::integer_literal(3).into(),
 									}).into(),
 									right: Expression::integer_literal(0).into(),
-								},
+								}.into(),
 								if_branch: BlockStatement(
 									[
 										(AssignmentExpression {
@@ -335,25 +338,25 @@ fn break_and_continue() -> TestRes {
 											left: Identifier("i".into()).into(),
 											right: Expression::integer_literal(1).into(),
 										}).consume_as_statement(),
-										ContinueStatement(1),
+										ContinueStatement(1).into(),
 									].into()
 								).into(),
 								else_branch: None,
-							},
+							}.into(),
 							IfStatement {
 								condition: BinaryExpression {
 									operator: Equals,
 									left: Identifier("i".into()).into(),
 									right: Expression::integer_literal(8).into(),
-								},
-								if_branch: BlockStatement([BreakStatement(1)].into()).into(),
+								}.into(),
+								if_branch: BlockStatement([BreakStatement(1).into()].into()).into(),
 								else_branch: None,
-							},
+							}.into(),
 							ExpressionStatement(AssignmentExpression {
 								operator: PlusAssignment,
 								left: Identifier("sum".into()).into(),
 								right: Identifier("i".into()).into(),
-							}),
+							}.into()).into(),
 							(AssignmentExpression {
 								operator: PlusAssignment,
 								left: Identifier("i".into()).into(),
@@ -361,10 +364,10 @@ fn break_and_continue() -> TestRes {
 							}).consume_as_statement(),
 						].into()
 					).into(),
-				},
+				}.into(),
 				Expression::function_call(
 					Identifier("push_res_stack".into()).into(),
-					[Identifier("sum".into())].into()
+					[Identifier("sum".into()).into()].into()
 				).consume_as_statement(),
 			].into()
 		)
@@ -414,26 +417,26 @@ fn break_and_continue() -> TestRes {
 			[
 				VariableDeclarations(
 					[
-						VariableDeclaration {
-							identifier: "sum".into(),
-							initializer: Some(Expression::integer_literal(0)),
-						},
+						VariableDeclaration::new(
+							"sum".into(),
+							Some(Expression::integer_literal(0))
+						),
 					].into()
-				),
+				).into(),
 				ForLoop {
 					initialization: VariableDeclarations(
 						[
-							VariableDeclaration {
-								identifier: "i".into(),
-								initializer: Some(Expression::integer_literal(1)),
-							},
+							VariableDeclaration::new(
+								"i".into(),
+								Some(Expression::integer_literal(1))
+							),
 						].into()
 					).into(),
 					condition: BinaryExpression {
 						operator: LessThanOrEqualTo,
 						left: Identifier("i".into()).into(),
 						right: Expression::integer_literal(10).into(),
-					},
+					}.into(),
 					increment: (AssignmentExpression {
 						operator: PlusAssignment,
 						left: Identifier("i".into()).into(),
@@ -452,44 +455,44 @@ fn break_and_continue() -> TestRes {
 										right: Expression::integer_literal(3).into(),
 									}).into(),
 									right: Expression::integer_literal(0).into(),
-								},
+								}.into(),
 								if_branch: BlockStatement(
 									[
 										WhileLoop {
-											condition: BooleanLiteral(true),
-											body: BlockStatement([ContinueStatement(2)].into()).into(),
-										},
+											condition: BooleanLiteral(true).into(),
+											body: BlockStatement([ContinueStatement(2).into()].into()).into(),
+										}.into(),
 									].into()
 								).into(),
 								else_branch: None,
-							},
+							}.into(),
 							IfStatement {
 								condition: BinaryExpression {
 									operator: Equals,
 									left: Identifier("i".into()).into(),
 									right: Expression::integer_literal(8).into(),
-								},
+								}.into(),
 								if_branch: BlockStatement(
 									[
 										DoWhileLoop {
-											condition: BooleanLiteral(true),
-											body: BlockStatement([BreakStatement(2)].into()).into(),
-										},
+											condition: BooleanLiteral(true).into(),
+											body: BlockStatement([BreakStatement(2).into()].into()).into(),
+										}.into(),
 									].into()
 								).into(),
 								else_branch: None,
-							},
+							}.into(),
 							ExpressionStatement(AssignmentExpression {
 								operator: PlusAssignment,
 								left: Identifier("sum".into()).into(),
 								right: Identifier("i".into()).into(),
-							}),
+							}.into()).into(),
 						].into()
 					).into(),
-				},
+				}.into(),
 				Expression::function_call(
 					Identifier("push_res_stack".into()).into(),
-					[Identifier("sum".into())].into()
+					[Identifier("sum".into()).into()].into()
 				).consume_as_statement(),
 			].into()
 		)
@@ -497,3 +500,218 @@ fn break_and_continue() -> TestRes {
 		.check()
 }
 
+
+#[test]
+fn for_of_and_for_in_loop_parsing() -> TestRes {
+	ensure_program(
+		"for (let x of v) push_res_stack(x);",
+		vec![
+			Statement::for_of_loop(
+				Pattern::Identifier("x".into()),
+				Identifier("v".into()).into(),
+				push_res_stack_stmt(Identifier("x".into()).into()).into()
+			)
+		]
+	);
+	ensure_program(
+		"for (let [k, v] in o) {}",
+		vec![
+			Statement::for_in_loop(
+				Pattern::Vector {
+					elements: vec![
+						PatternElement::new(Pattern::Identifier("k".into()), None),
+						PatternElement::new(Pattern::Identifier("v".into()), None),
+					],
+					rest: None,
+				},
+				Identifier("o".into()).into(),
+				BlockStatement(vec![]).into()
+			)
+		]
+	);
+	ensure_parsing_fails("for (let x of) {}", None);
+	ensure_parsing_fails("for (x of v) {}", None);
+	ensure_parsing_fails("for (let x, y of v) {}", None);
+}
+
+#[test]
+fn for_of_loop() -> TestRes {
+	TestData::new(
+		r#"
+	for (let x of [1, 2, 3]) {
+		push_res_stack(x * 10);
+	}
+	for (let [a, b] of [[1, 2], [3, 4]]) push_res_stack(a + b);
+	class Range {
+		fn constructor(this, end) {
+			this.end = end;
+		}
+		fn iter(this) {
+			let iterator = { current: 0, end: this.end };
+			iterator.next = fn(self) {
+				if (self.current >= self.end) {
+					return { done: true };
+				}
+				self.current += 1;
+				return { value: self.current, done: false };
+			};
+			return iterator;
+		}
+	}
+	for (let i of new Range(3)) push_res_stack(i);
+	let countdown = {
+		iter: fn(self) {
+			let left = 2;
+			return { next: fn(_) { left -= 1; return { value: left, done: left < 0 }; } };
+		}
+	};
+	for (let i of countdown) push_res_stack(i);
+"#.into()
+	)
+		.expect_stack(
+			[10, 20, 30, 3, 7, 1, 2, 3, 1, 0]
+				.into_iter()
+				.map(PrimitiveValue::integer)
+				.collect()
+		)
+		.check();
+	ensure_execution_fails("for (let x of 1) {}".into(), None);
+	// Methods of the iterator protocol get the object they are called on as their first argument
+	ensure_execution_fails(
+		"for (let x of { iter: fn() { return null; } }) {}".into(),
+		Some(
+			(RuntimeError::InvalidNumberArgumentsToFunction {
+				got: 1,
+				expected: Some("0".into()),
+				func: "lambda".into(),
+			}).into()
+		)
+	)
+}
+
+#[test]
+fn for_in_loop() -> TestRes {
+	TestData::new(
+		r#"
+	let o = { b: 2, a: 1, c: 3 };
+	for (let k in o) {
+		push_res_stack(k);
+	}
+	let v = [1, 2];
+	v.name = "v";
+	for (let k in v) push_res_stack(k);
+"#.into()
+	)
+		.expect_stack(
+			["a", "b", "c", "name"]
+				.into_iter()
+				.map(|v| PrimitiveValue::String(v.into()))
+				.collect()
+		)
+		.check()
+}
+
+#[test]
+fn break_and_continue_in_for_of_and_for_in_loops() -> TestRes {
+	TestData::new(
+		r#"
+	let sum = 0;
+	for (let i of [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]) {
+		if (i % 3 == 0) {
+			while (true) {
+				continue 2;
+			}
+		}
+		if (i == 8) {
+			break;
+		}
+		sum += i;
+	}
+	push_res_stack(sum);
+	for (let k in { a: 1, b: 2, c: 3 }) {
+		for (let x of [1, 2]) {
+			if (k == "b") {
+				break 2;
+			}
+			push_res_stack(k);
+		}
+	}
+"#.into()
+	)
+		.expect_stack(vec![
+			PrimitiveValue::integer(19),
+			PrimitiveValue::String("a".into()),
+			PrimitiveValue::String("a".into()),
+		])
+		.check()
+}
+
+#[test]
+fn labeled_loops() -> TestRes {
+	ensure_program(
+		"outer: while (true) { for (;;) { break outer; } continue outer; }",
+		vec![
+			WhileLoop {
+				condition: BooleanLiteral(true).into(),
+				body: BlockStatement(vec![
+					ForLoop {
+						initialization: EmptyStatement.into(),
+						condition: BooleanLiteral(true).into(),
+						increment: EmptyStatement.into(),
+						body: BlockStatement(vec![BreakStatement(2).into()]).into(),
+					}.into(),
+					ContinueStatement(1).into(),
+				]).into(),
+			}.into()
+		]
+	);
+	ensure_parsing_fails("while (true) { break outer; }", Some(ErrorT::UnknownLoopLabel("outer".into())));
+	ensure_parsing_fails(
+		"outer: while (true) { fn f() { while (true) { break outer; } } }",
+		Some(ErrorT::UnknownLoopLabel("outer".into()))
+	);
+	ensure_parsing_fails(
+		"outer: while (true) {} while (true) { continue outer; }",
+		Some(ErrorT::UnknownLoopLabel("outer".into()))
+	);
+	ensure_parsing_fails("outer: if (true) {}", None);
+	for depth in ["0", "0x1", "1_0", "1.5", "99999999999999999999"] {
+		ensure_parsing_fails(
+			&format!("while (true) {{ break {}; }}", depth),
+			Some(ErrorT::InvalidLoopDepth(depth.into()))
+		);
+	}
+	ensure_parsing_fails("while (true) { continue 0b1; }", Some(ErrorT::InvalidLoopDepth("0b1".into())));
+	TestData::new(
+		r#"
+	rows: for (let row of [1, 2, 3]) {
+		let col = 0;
+		cols: do {
+			col += 1;
+			if (col == row) {
+				continue rows;
+			}
+			for (let i of [0]) {
+				if (row == 3) {
+					break rows;
+				}
+			}
+			push_res_stack(row * 10 + col);
+		} while (true);
+	}
+	inner: for (let i = 0; i < 2; i += 1) {
+		inner: for (let j = 0; j < 2; j += 1) {
+			push_res_stack(j);
+			break inner;
+		}
+	}
+"#.into()
+	)
+		.expect_stack(
+			[21, 0, 0]
+				.into_iter()
+				.map(PrimitiveValue::integer)
+				.collect()
+		)
+		.check()
+}
@@ -0,0 +1,139 @@
+use evilang_lib::ast::expression::{
+	Expression,
+	ExpressionKind::{ BooleanLiteral, Identifier, NullLiteral, StringLiteral },
+	MemberIndexer,
+};
+use evilang_lib::ast::operator::Operator;
+use evilang_lib::ast::pattern::{ Pattern, PatternElement, PropertyPattern };
+use evilang_lib::ast::statement::{ Statement, StatementKind::{ BlockStatement, VariableDeclarations } };
+use evilang_lib::ast::structs::{ MatchArm, VariableDeclaration };
+use evilang_lib::errors::{ Descriptor, ErrorT, RuntimeError };
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_program,
+	ensure_res_stack_matches,
+	run_asserts_in_file,
+	TestRes,
+};
+
+mod common;
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program(
+		"match (a) { 1 | -2 => {} [x, ...rest] if x => x, { kind: \"circle\", r } => r, _ => null }",
+		vec![
+			Statement::match_statement(
+				Identifier("a".into()).into(),
+				vec![
+					MatchArm::new(
+						Pattern::Alternatives(vec![
+							Pattern::Literal(Expression::integer_literal(1).into()),
+							Pattern::Literal(
+								Expression::unary_expression(
+									Operator::Minus,
+									Expression::integer_literal(2).into()
+								).into()
+							),
+						]),
+						None,
+						BlockStatement(vec![]).into()
+					),
+					MatchArm::new(
+						Pattern::Vector {
+							elements: vec![PatternElement::new(Pattern::Identifier("x".into()), None)],
+							rest: Some(Pattern::Identifier("rest".into()).into()),
+						},
+						Some(Identifier("x".into()).into()),
+						Identifier("x".into()).consume_as_statement().into()
+					),
+					MatchArm::new(
+						Pattern::Object(vec![
+							PropertyPattern::new(
+								MemberIndexer::PropertyName("kind".into()),
+								PatternElement::new(
+									Pattern::Literal(StringLiteral("circle".into()).into()),
+									None
+								)
+							),
+							PropertyPattern::new_shorthand("r".into(), None),
+						]),
+						None,
+						Identifier("r".into()).consume_as_statement().into()
+					),
+					MatchArm::new(
+						Pattern::Wildcard,
+						None,
+						NullLiteral.consume_as_statement().into()
+					)
+				]
+			)
+		]
+	);
+	ensure_program(
+		"let v = match (a) { true => 1, x => x };",
+		vec![
+			VariableDeclarations(vec![
+				VariableDeclaration::new(
+					"v".into(),
+					Some(
+						Expression::match_expression(
+							Identifier("a".into()).into(),
+							vec![
+								MatchArm::new(
+									Pattern::Literal(BooleanLiteral(true).into()),
+									None,
+									Expression::integer_literal(1)
+								),
+								MatchArm::new(
+									Pattern::Identifier("x".into()),
+									None,
+									Identifier("x".into()).into()
+								)
+							]
+						)
+					)
+				),
+			]).into()
+		]
+	);
+	ensure_parsing_fails("match (a) { 1 => x 2 => y }", None);
+	ensure_parsing_fails("match (a) { a + 1 => x }", None);
+	ensure_parsing_fails("match a { _ => x }", None);
+	ensure_parsing_fails("let v = match (a) { 1 => {} 2 => {} };", None);
+}
+
+#[test]
+fn statements_and_expressions() -> TestRes {
+	ensure_res_stack_matches(
+		"for (let i = 0; i < 5; i += 1) { match (i) { 0 | 1 => push_res_stack(\"small\"), \
+		x if x > 3 => { push_res_stack(x); } } } \
+		push_res_stack(match (\"b\") { \"a\" => 1, \"b\" => 2, _ => 3 });",
+		vec![
+			PrimitiveValue::String("small".into()),
+			PrimitiveValue::String("small".into()),
+			PrimitiveValue::integer(4),
+			PrimitiveValue::integer(2),
+		]
+	);
+}
+
+#[test]
+fn no_matching_arm() -> TestRes {
+	ensure_execution_fails(
+		"let v = match (3) { 1 => 1, 2 => 2 };".into(),
+		Some(
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::NoMatchingArm(Descriptor::Value(PrimitiveValue::integer(3)))
+			)
+		)
+	);
+}
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/match_test/main.evil".into());
+}
@@ -1,5 +1,5 @@
-use evilang_lib::ast::expression::{ DottedIdentifiers, Expression };
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::{ DottedIdentifiers, Expression, ExpressionKind };
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	Identifier,
@@ -18,10 +18,10 @@ mod common;
 fn member_access() -> TestRes {
 	test_expression_and_assignment(r#"a.b["c"].d;"#, MemberAccess {
 		object: (MemberAccess {
-			object: Expression::DottedIdentifiers(DottedIdentifiers {
+			object: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 				identifiers: ["a".to_string(), "b".to_string()].into(),
-				delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-			}).into(),
+				delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+			})).into(),
 			member: SubscriptExpression(StringLiteral("c".to_string()).into()),
 		}).into(),
 		member: PropertyName("d".to_string()),
@@ -36,10 +36,10 @@ fn member_complex_assignment() -> TestRes {
 			operator: ModulusAssignment,
 			left: (MemberAccess {
 				object: (MemberAccess {
-					object: Expression::DottedIdentifiers(DottedIdentifiers {
+					object: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 						identifiers: ["a".to_string(), "b".to_string()].into(),
-						delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-					}).into(),
+						delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+					})).into(),
 					member: SubscriptExpression(
 						(BinaryExpression {
 							operator: Plus,
@@ -57,16 +57,16 @@ fn member_complex_assignment() -> TestRes {
 					left: Expression::integer_literal(1).into(),
 					right: (BinaryExpression {
 						operator: Multiplication,
-						left: Expression::DottedIdentifiers(DottedIdentifiers {
+						left: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 							identifiers: ["$".to_string(), "left".to_string()].into(),
-							delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-						}).into(),
+							delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+						})).into(),
 						right: (AssignmentExpression {
 							operator: Assignment,
-							left: Expression::DottedIdentifiers(DottedIdentifiers {
+							left: Expression::from(ExpressionKind::DottedIdentifiers(DottedIdentifiers {
 								identifiers: ["$".to_string(), "right".to_string()].into(),
-								delimiters: [Token { typ: TokenType::Dot, data: ".".to_string() }].into(),
-							}).into(),
+								delimiters: [Token::new(TokenType::Dot, ".".to_string())].into(),
+							})).into(),
 							right: Expression::integer_literal(1).into(),
 						})
 							.consume_as_parenthesized()
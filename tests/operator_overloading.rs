@@ -0,0 +1,31 @@
+use evilang_lib::ast::expression::ExpressionKind::Identifier;
+use evilang_lib::ast::operator::Operator;
+use evilang_lib::errors::ErrorT;
+
+use crate::common::{ ensure_execution_fails, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/operator_overloading_test/main.evil".into());
+}
+
+#[test]
+fn missing_overloads() -> TestRes {
+	ensure_execution_fails(
+		"class K {} let a = new K(), b = new K(); a + b;".into(),
+		Some(
+			ErrorT::UnimplementedBinaryOperatorForValues(
+				Operator::Plus,
+				Identifier("a".into()).into(),
+				Identifier("b".into()).into()
+			)
+		)
+	);
+	ensure_execution_fails(
+		"class K {} let a = new K(); -a;".into(),
+		Some(ErrorT::UnimplementedUnaryOperatorForValues(Operator::Minus, Identifier("a".into()).into()))
+	);
+	ensure_execution_fails("class K { fn __add__(this, other) { return 1; } } 1 + new K();".into(), None);
+}
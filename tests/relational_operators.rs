@@ -1,5 +1,5 @@
 use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{ BinaryExpression, Identifier };
+use evilang_lib::ast::expression::ExpressionKind::{ BinaryExpression, Identifier };
 use evilang_lib::ast::operator::Operator::{
 	Division,
 	GreaterThan,
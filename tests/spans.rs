@@ -0,0 +1,65 @@
+use evilang_lib::ast::expression::ExpressionKind::BinaryExpression;
+use evilang_lib::ast::statement::StatementKind::ExpressionStatement;
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::parser::parse;
+use evilang_lib::tokenizer::{ TokenStream, TokenType };
+use evilang_lib::types::span::Span;
+
+use crate::common::TestRes;
+
+mod common;
+
+#[test]
+fn token_spans() -> TestRes {
+	let tokens = TokenStream::new_with_file_id("let x =\n\t\"é\" + 12;".to_string(), 3)
+		.map(|v| v.unwrap())
+		.collect::<Vec<_>>();
+	let spans = tokens
+		.iter()
+		.filter(|v| v.typ != TokenType::_EOFDummy)
+		.map(|v| (v.data.as_str(), v.span))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		spans,
+		vec![
+			("let", Span::new(3, 0, 3, 1, 1)),
+			("x", Span::new(3, 4, 5, 1, 5)),
+			("=", Span::new(3, 6, 7, 1, 7)),
+			("\"é\"", Span::new(3, 9, 13, 2, 2)),
+			("+", Span::new(3, 14, 15, 2, 6)),
+			("12", Span::new(3, 16, 18, 2, 8)),
+			(";", Span::new(3, 18, 19, 2, 10)),
+		],
+		"Expected token spans to match"
+	);
+}
+
+#[test]
+fn statement_and_expression_spans() -> TestRes {
+	let parsed = parse("let a = 1;\n  a * 2 + 3;\nfn f(x) {\n\treturn x;\n}".to_string()).unwrap();
+	assert_eq!(parsed[0].span, Span::new(0, 0, 10, 1, 1));
+	assert_eq!(parsed[1].span, Span::new(0, 13, 23, 2, 3));
+	let ExpressionStatement(expr) = &parsed[1].kind else {
+		panic!("Expected an expression statement, got {:#?}", parsed[1]);
+	};
+	assert_eq!(expr.span, Span::new(0, 13, 22, 2, 3));
+	let BinaryExpression { left, right, .. } = &expr.kind else {
+		panic!("Expected a binary expression, got {:#?}", expr);
+	};
+	assert_eq!(left.span, Span::new(0, 13, 18, 2, 3));
+	assert_eq!(right.span, Span::new(0, 21, 22, 2, 11));
+	assert_eq!(parsed[2].span, Span::new(0, 24, 46, 3, 1));
+}
+
+#[test]
+fn errors_carry_spans() -> TestRes {
+	let parse_error = parse("let a = 1;\nlet b = ;".to_string()).unwrap_err();
+	assert_eq!(parse_error.span.map(|v| (v.line, v.column)), Some((2, 9)));
+
+	let mut env = Environment::new().unwrap();
+	let runtime_error = env
+		.eval_program_string("let a = 1;\nif (a) {\n\ta = b + 1;\n}".to_string())
+		.unwrap_err();
+	assert_eq!(runtime_error.span.map(|v| (v.line, v.column)), Some((3, 6)));
+	assert!(runtime_error.to_string().starts_with("[3:6] "));
+}
@@ -1,4 +1,4 @@
-use evilang_lib::ast::{ expression::Expression, statement::Statement };
+use evilang_lib::ast::{ expression::{ Expression, ExpressionKind }, statement::{ Statement, StatementKind } };
 
 use crate::common::{ ensure_program, TestRes };
 
@@ -11,11 +11,11 @@ fn multiple_expressions() -> TestRes {
 42;
 "More stuff";"#,
 		vec![
-			Expression::StringLiteral(
+			Expression::from(ExpressionKind::StringLiteral(
 				"This is a string and this is a double quote: \"".parse().unwrap()
-			).consume_as_statement(),
+			)).consume_as_statement(),
 			Expression::integer_literal(42).consume_as_statement(),
-			Expression::StringLiteral("More stuff".parse().unwrap()).consume_as_statement()
+			Expression::from(ExpressionKind::StringLiteral("More stuff".parse().unwrap())).consume_as_statement()
 		]
 	);
 }
@@ -29,15 +29,15 @@ fn block_expression() -> TestRes {
 }
 "More stuff";"#,
 		vec![
-			Statement::BlockStatement(
+			Statement::from(StatementKind::BlockStatement(
 				vec![
-					Expression::StringLiteral(
+					Expression::from(ExpressionKind::StringLiteral(
 						"This is a string and this is a double quote: \"".parse().unwrap()
-					).consume_as_statement(),
+					)).consume_as_statement(),
 					Expression::integer_literal(42).consume_as_statement()
 				]
-			),
-			Expression::StringLiteral("More stuff".parse().unwrap()).consume_as_statement()
+			)),
+			Expression::from(ExpressionKind::StringLiteral("More stuff".parse().unwrap())).consume_as_statement()
 		]
 	);
 }
@@ -48,8 +48,8 @@ fn empty_expression() -> TestRes {
 		r#"42;;"data";"#,
 		vec![
 			Expression::integer_literal(42).consume_as_statement(),
-			Statement::EmptyStatement,
-			Expression::StringLiteral("data".to_string()).consume_as_statement()
+			Statement::from(StatementKind::EmptyStatement),
+			Expression::from(ExpressionKind::StringLiteral("data".to_string())).consume_as_statement()
 		]
 	);
 }
@@ -0,0 +1,26 @@
+use evilang_lib::errors::{ Descriptor, ErrorT, RuntimeError };
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+
+use crate::common::{ ensure_execution_fails, run_asserts_in_file, TestRes };
+
+mod common;
+
+#[test]
+fn evaluation() -> TestRes {
+	run_asserts_in_file("/resources/tests/stringification_test/main.evil".into());
+}
+
+#[test]
+fn to_string_method_has_to_return_a_string() -> TestRes {
+	ensure_execution_fails(
+		"class K { fn to_string(this) { return 1; } } println(new K());".into(),
+		Some(
+			ErrorT::UnexpectedRuntimeError(
+				RuntimeError::ExpectedString(Descriptor::NameAndValue {
+					name: "to_string".into(),
+					value: PrimitiveValue::integer(1),
+				})
+			)
+		)
+	);
+}
@@ -0,0 +1,135 @@
+use evilang_lib::ast::expression::Expression;
+use evilang_lib::ast::expression::ExpressionKind::{ Identifier, TemplateLiteral };
+use evilang_lib::ast::operator::Operator::Plus;
+use evilang_lib::ast::statement::StatementKind::ExpressionStatement;
+use evilang_lib::errors::{ ErrorT, RuntimeError };
+use evilang_lib::interpreter::environment::Environment;
+use evilang_lib::interpreter::runtime_values::PrimitiveValue;
+use evilang_lib::parser::parse;
+use evilang_lib::tokenizer::{ Token, TokenType };
+
+use crate::common::{ ensure_parsing_fails, ensure_program, ensure_res_stack_matches, TestRes };
+
+mod common;
+
+fn template(strings: &[&str], expressions: Vec<Expression>) -> Expression {
+	TemplateLiteral {
+		strings: strings.iter().map(|v| v.to_string()).collect(),
+		expressions,
+	}.into()
+}
+
+fn string(str: &str) -> PrimitiveValue {
+	PrimitiveValue::String(str.into())
+}
+
+#[test]
+fn parsing() -> TestRes {
+	ensure_program("`plain text`;", vec![template(&["plain text"], vec![]).consume_as_statement()]);
+	ensure_program(
+		"`Point(${x}, ${1 + 2})`;",
+		vec![
+			template(
+				&["Point(", ", ", ")"],
+				vec![
+					Identifier("x".into()).into(),
+					Expression::binary_expression(
+						Plus,
+						Expression::integer_literal(1).into(),
+						Expression::integer_literal(2).into()
+					)
+				]
+			).consume_as_statement()
+		]
+	);
+	ensure_program(
+		"`${a}${b}`;",
+		vec![
+			template(
+				&["", "", ""],
+				vec![Identifier("a".into()).into(), Identifier("b".into()).into()]
+			).consume_as_statement()
+		]
+	);
+}
+
+#[test]
+fn interpolation() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+class Point {
+	fn constructor(this, x, y) {
+		this.x = x;
+		this.y = y;
+	}
+}
+let p = new Point(1, 2.5);
+push_res_stack(`Point(${p.x}, ${p.y})`);
+push_res_stack(`${null} ${true} ${"str"} ${2i}${2f}`);
+push_res_stack(`${p.x + p.y}${p.x}`);
+"#,
+		vec![string("Point(1, 2.5)"), string("null true str 22"), string("3.51")]
+	);
+}
+
+#[test]
+fn nesting_and_escapes() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+let a = 1;
+push_res_stack(`outer ${`inner ${a}`} ${"}"} ${'`'}`);
+push_res_stack(`\${a} \` $a $ {a} \t\u{41}`);
+push_res_stack(`multi
+line ${a +
+	1}`);
+fn f() {
+	return `${a + 1}`;
+}
+push_res_stack(`${f()}`);
+"#,
+		vec![
+			string("outer inner 1 } `"),
+			string("${a} ` $a $ {a} \tA"),
+			string("multi\nline 2"),
+			string("2"),
+		]
+	);
+}
+
+#[test]
+fn errors() -> TestRes {
+	ensure_parsing_fails("`unterminated;", Some(ErrorT::TokenCannotBeParsed));
+	ensure_parsing_fails("`${a`;", Some(ErrorT::TokenCannotBeParsed));
+	ensure_parsing_fails(
+		"`${}`;",
+		Some(ErrorT::InvalidTokenType(Token::new(TokenType::_EOFDummy, "".into())))
+	);
+	ensure_parsing_fails("`\\q`;", Some(ErrorT::InvalidEscapeSequence("\\q".into())));
+
+	let err = parse("let a = 1;\nlet b = `x\n  ${a b}`;".into()).unwrap_err();
+	assert_eq!(err.typ, ErrorT::InvalidTokenType(Token::new(TokenType::Identifier, "b".into())));
+	let span = err.span.unwrap();
+	assert_eq!((span.line, span.column, span.start), (3, 7, 28));
+
+	let mut env = Environment::new().unwrap();
+	let err = env
+		.eval_program_string(
+			"class K { fn to_string(this) { return 1; } }\nlet s = `a ${new K()} b`;".into()
+		)
+		.unwrap_err();
+	assert!(
+		matches!(&err.typ, ErrorT::UnexpectedRuntimeError(RuntimeError::ExpectedString(..))),
+		"Expected to_string methods to have to return strings, got {:#?}",
+		err
+	);
+	assert_eq!(err.span.map(|v| (v.line, v.column)), Some((2, 14)));
+}
+
+#[test]
+fn display() -> TestRes {
+	let parsed = parse("`a\\`b ${x + 1} \\${c}\\n`;".into()).unwrap();
+	let ExpressionStatement(expr) = &parsed[0].kind else {
+		panic!("Expected an expression statement, got {:#?}", parsed[0]);
+	};
+	assert_eq!(expr.to_string(), "`a\\`b ${x + 1} \\${c}\\n`");
+}
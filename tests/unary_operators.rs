@@ -1,21 +1,33 @@
-use evilang_lib::ast::expression::Expression;
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::{ Expression, MemberIndexer };
+use evilang_lib::ast::expression::ExpressionKind::{
 	BinaryExpression,
 	BooleanLiteral,
 	Identifier,
+	MemberAccess,
 	UnaryExpression,
+	UpdateExpression,
 };
 use evilang_lib::ast::operator::Operator::{
+	Decrement,
 	Equals,
 	GreaterThan,
+	Increment,
 	LogicalNot,
 	Minus,
 	Multiplication,
 	Plus,
 };
+use evilang_lib::errors::ErrorT;
 use evilang_lib::interpreter::runtime_values::PrimitiveValue;
 
-use crate::common::{ test_expression_and_assignment, TestData, TestRes };
+use crate::common::{
+	ensure_execution_fails,
+	ensure_parsing_fails,
+	ensure_res_stack_matches,
+	test_expression_and_assignment,
+	TestData,
+	TestRes,
+};
 
 mod common;
 
@@ -118,3 +130,57 @@ fn interpretation_basic() -> TestRes {
 		.check();
 }
 
+
+#[test]
+fn increment_and_decrement() -> TestRes {
+	test_expression_and_assignment("++x;", UpdateExpression {
+		operator: Increment,
+		prefix: true,
+		argument: Identifier("x".parse().unwrap()).into(),
+	});
+	test_expression_and_assignment("-x[0]--;", UnaryExpression {
+		operator: Minus,
+		argument: (UpdateExpression {
+			operator: Decrement,
+			prefix: false,
+			argument: (MemberAccess {
+				object: Identifier("x".parse().unwrap()).into(),
+				member: MemberIndexer::SubscriptExpression(Expression::integer_literal(0).into()),
+			}).into(),
+		}).into(),
+	});
+	ensure_parsing_fails("1++;", Some(ErrorT::ExpectedLhsExpression));
+	ensure_parsing_fails("++(x);", Some(ErrorT::ExpectedLhsExpression));
+	ensure_parsing_fails("x++ = 1;", Some(ErrorT::ExpectedLhsExpression));
+}
+
+#[test]
+fn interpretation_increment_and_decrement() -> TestRes {
+	ensure_res_stack_matches(
+		r#"
+	let i = 5;
+	push_res_stack(i++, i, ++i, i--, --i, i);
+	let o = { n: 1.5 };
+	push_res_stack(o.n++, ++o["n"], o.n);
+	namespace ns { let x = 1; }
+	ns.x++;
+	let total = 0;
+	for (let j = 0; j < 3; j++) { total += j; }
+	push_res_stack(ns.x, total);
+"#,
+		vec![
+			PrimitiveValue::integer(5),
+			PrimitiveValue::integer(6),
+			PrimitiveValue::integer(7),
+			PrimitiveValue::integer(7),
+			PrimitiveValue::integer(5),
+			PrimitiveValue::integer(5),
+			PrimitiveValue::float(1.5),
+			PrimitiveValue::float(3.5),
+			PrimitiveValue::float(3.5),
+			PrimitiveValue::integer(2),
+			PrimitiveValue::integer(3),
+		]
+	);
+	ensure_execution_fails("let s = \"a\"; s++;".into(), None);
+}
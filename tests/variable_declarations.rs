@@ -1,11 +1,11 @@
 use evilang_lib::ast::expression::{ BoxExpression, Expression };
-use evilang_lib::ast::expression::Expression::{
+use evilang_lib::ast::expression::ExpressionKind::{
 	AssignmentExpression,
 	BinaryExpression,
 	Identifier,
 };
 use evilang_lib::ast::operator::Operator::{ Plus, PlusAssignment };
-use evilang_lib::ast::statement::Statement::VariableDeclarations;
+use evilang_lib::ast::statement::StatementKind::VariableDeclarations;
 use evilang_lib::ast::structs::VariableDeclaration;
 use evilang_lib::interpreter::runtime_values::PrimitiveValue;
 
@@ -20,16 +20,16 @@ fn basic_declaration() -> TestRes {
 		vec![
 			VariableDeclarations(
 				Vec::from([
-					VariableDeclaration {
-						identifier: "x".parse().unwrap(),
-						initializer: Some(BinaryExpression {
+					VariableDeclaration::new(
+						"x".parse().unwrap(),
+						Some(BinaryExpression {
 							operator: Plus,
 							left: BoxExpression::from(Expression::integer_literal(1)),
 							right: BoxExpression::from(Expression::integer_literal(2)),
-						}),
-					},
+						}.into())
+					),
 				])
-			),
+			).into(),
 			identifier_stmt("x")
 		],
 		vec![PrimitiveValue::Null, PrimitiveValue::integer(3)]
@@ -48,28 +48,28 @@ baz;
 		vec![
 			VariableDeclarations(
 				Vec::from([
-					VariableDeclaration {
-						identifier: "$foo".parse().unwrap(),
-						initializer: Some(BinaryExpression {
+					VariableDeclaration::new(
+						"$foo".parse().unwrap(),
+						Some(BinaryExpression {
 							operator: Plus,
 							left: BoxExpression::from(Expression::integer_literal(1)),
 							right: BoxExpression::from(Expression::integer_literal(2)),
-						}),
-					},
-					VariableDeclaration {
-						identifier: "bar1".parse().unwrap(),
-						initializer: None,
-					},
-					VariableDeclaration {
-						identifier: "baz".parse().unwrap(),
-						initializer: Some(AssignmentExpression {
+						}.into())
+					),
+					VariableDeclaration::new(
+						"bar1".parse().unwrap(),
+						None
+					),
+					VariableDeclaration::new(
+						"baz".parse().unwrap(),
+						Some(AssignmentExpression {
 							operator: PlusAssignment,
 							left: BoxExpression::from(Identifier("$foo".parse().unwrap())),
 							right: BoxExpression::from(Expression::integer_literal(4)),
-						}),
-					},
+						}.into())
+					),
 				])
-			),
+			).into(),
 			identifier_stmt("$foo"),
 			identifier_stmt("bar1"),
 			identifier_stmt("baz")